@@ -0,0 +1,35 @@
+use circuit::{build_schnorr_trace_with_height, SignatureWitness};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeCharacteristicRing;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use schnorr::SigningKey;
+
+fn bench_schnorr_trace(c: &mut Criterion) {
+    let mut group = c.benchmark_group("schnorr_trace");
+    group.sample_size(10);
+
+    let mut rng = StdRng::seed_from_u64(12345);
+    let signing_key = SigningKey::random(&mut rng);
+    let message = [
+        BabyBear::from_u32(1),
+        BabyBear::from_u32(2),
+        BabyBear::from_u32(3),
+    ];
+    let signature = signing_key.sign(&mut rng, &message).expect("sign");
+    let witness =
+        SignatureWitness::new(&signature, &signing_key.verifying_key(), &message).expect("witness");
+
+    for height in [256, 1024] {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(height),
+            &height,
+            |b, &height| b.iter(|| build_schnorr_trace_with_height(black_box(&witness), height)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_schnorr_trace);
+criterion_main!(benches);
@@ -0,0 +1,111 @@
+//! Example proving and verifying `k * G = P` with the standalone scalar-mul AIR.
+//!
+//! The public values are `G || P`: the base point coordinates
+//! (`PUBLIC_BASE_LIMBS`) followed by the claimed output (`PUBLIC_OUT_LIMBS`).
+
+use circuit::{
+    build_scalar_mul_trace, scalar_to_bits_trimmed, Affine, CircuitPoint, KoalaBear, ScalarField,
+    ScalarMulAir, PUBLIC_BASE_LIMBS, PUBLIC_OUT_LIMBS,
+};
+use curve::{Group, RandomField};
+use p3_challenger::{HashChallenger, SerializingChallenger32};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_fri::{FriParameters, TwoAdicFriPcs};
+use p3_keccak::{Keccak256Hash, KeccakF};
+use p3_matrix::Matrix;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher};
+use p3_uni_stark::{prove, verify, StarkConfig};
+use rand::rng;
+use std::time::Instant;
+
+fn encode_public(base: &CircuitPoint, out: &CircuitPoint) -> Vec<KoalaBear> {
+    let values = [base.x, base.y, out.x, out.y].concat();
+    assert_eq!(values.len(), PUBLIC_BASE_LIMBS + PUBLIC_OUT_LIMBS);
+    values
+}
+
+fn main() {
+    let mut rng = rng();
+    let scalar = ScalarField::random(&mut rng);
+    let generator = Affine::generator();
+    let base = CircuitPoint::from_affine(&generator);
+    let out = CircuitPoint::from_affine(&generator.scalar_mul(&scalar));
+
+    let trace = build_scalar_mul_trace(&base, &scalar_to_bits_trimmed(&scalar));
+    let height = trace.trace.height();
+    let air = ScalarMulAir::new(height);
+    let width = trace.trace.width();
+
+    type Val = KoalaBear;
+    type Challenge = BinomialExtensionField<Val, 4>;
+
+    type ByteHash = Keccak256Hash;
+    let byte_hash = ByteHash {};
+
+    type U64Hash = PaddingFreeSponge<KeccakF, 25, 17, 4>;
+    let u64_hash = U64Hash::new(KeccakF {});
+    type FieldHash = SerializingHasher<U64Hash>;
+    let field_hash = FieldHash::new(u64_hash);
+
+    type MyCompress = CompressionFunctionFromHasher<U64Hash, 2, 4>;
+    let compress = MyCompress::new(u64_hash);
+
+    type ValMmcs = MerkleTreeMmcs<
+        [Val; p3_keccak::VECTOR_LEN],
+        [u64; p3_keccak::VECTOR_LEN],
+        FieldHash,
+        MyCompress,
+        4,
+    >;
+    let val_mmcs = ValMmcs::new(field_hash, compress);
+
+    type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+
+    type Challenger = SerializingChallenger32<Val, HashChallenger<u8, ByteHash, 32>>;
+    let challenger = Challenger::from_hasher(vec![], byte_hash);
+
+    let fri_params = FriParameters {
+        log_blowup: 3,
+        log_final_poly_len: 0,
+        num_queries: 64,
+        commit_proof_of_work_bits: 1,
+        query_proof_of_work_bits: 1,
+        mmcs: challenge_mmcs,
+    };
+    type Dft = Radix2DitParallel<Val>;
+    type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+    let pcs = Pcs::new(Dft::default(), val_mmcs, fri_params);
+
+    type MyConfig = StarkConfig<Pcs, Challenge, Challenger>;
+    let config = MyConfig::new(pcs, challenger);
+
+    let public_values = encode_public(&base, &out);
+
+    println!("Trace rows: {}", height);
+    println!("Trace columns: {}", width);
+
+    let prove_start = Instant::now();
+    let proof = prove(&config, &air, trace.trace, &public_values);
+    let prove_time = prove_start.elapsed();
+
+    let verify_start = Instant::now();
+    verify(&config, &air, &proof, &public_values).expect("verify");
+    let verify_time = verify_start.elapsed();
+
+    println!("Proving time: {:?}", prove_time);
+    println!("Verification time: {:?}", verify_time);
+    println!("Scalar multiplication proof verified.");
+
+    // The same proof must not convince a verifier of a different output point
+    let wrong_out = CircuitPoint::from_affine(&generator.scalar_mul(&(scalar + ScalarField::ONE)));
+    let wrong_public_values = encode_public(&base, &wrong_out);
+    assert!(
+        verify(&config, &air, &proof, &wrong_public_values).is_err(),
+        "proof verified against a wrong output point"
+    );
+    println!("Proof rejected for a wrong output point.");
+}
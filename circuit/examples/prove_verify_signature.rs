@@ -1,6 +1,9 @@
 //! Example proving and verifying the Schnorr AIR over a full trace.
 
-use circuit::{build_schnorr_trace, CircuitPoint, KoalaBear, SchnorrAir, SignatureWitness};
+use circuit::{
+    build_schnorr_trace, challenge_hash_public_values, check_challenge_binding, KoalaBear,
+    SchnorrAir, SchnorrProofBundle, SignatureWitness,
+};
 use p3_baby_bear::BabyBear;
 use p3_challenger::{HashChallenger, SerializingChallenger32};
 use p3_commit::ExtensionMmcs;
@@ -13,20 +16,13 @@ use p3_matrix::Matrix;
 use p3_merkle_tree::MerkleTreeMmcs;
 use p3_symmetric::{CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher};
 use p3_uni_stark::{
-    prove_with_preprocessed, setup_preprocessed, verify_with_preprocessed, StarkConfig,
+    prove_with_preprocessed, setup_preprocessed, verify_with_preprocessed, Proof, StarkConfig,
 };
 use p3_util::log2_strict_usize;
 use rand::rng;
 use schnorr::SigningKey;
 use std::time::Instant;
 
-fn encode_point(point: &CircuitPoint) -> [KoalaBear; 16] {
-    let mut out = [KoalaBear::ZERO; 16];
-    out[..8].copy_from_slice(&point.x);
-    out[8..16].copy_from_slice(&point.y);
-    out
-}
-
 fn main() {
     let mut rng = rng();
     let signing_key = SigningKey::random(&mut rng);
@@ -91,9 +87,8 @@ fn main() {
     type MyConfig = StarkConfig<Pcs, Challenge, Challenger>;
     let config = MyConfig::new(pcs, challenger);
 
-    let mut public_values = Vec::with_capacity(32);
-    public_values.extend_from_slice(&encode_point(&witness.public_key));
-    public_values.extend_from_slice(&encode_point(&witness.r));
+    let public_values = witness.public_values().to_vec();
+    let hash_public_values = challenge_hash_public_values(&witness).expect("hash inputs");
 
     println!("Trace rows: {}", height);
     println!("Trace columns: {}", width);
@@ -110,18 +105,25 @@ fn main() {
         &public_values,
         Some(&preprocessed_prover_data),
     );
-    let proof_bytes = postcard::to_allocvec(&proof).expect("serialize proof");
+    let bundle = SchnorrProofBundle::new(proof, public_values, &air).expect("bundle");
+    let proof_bytes = bundle.to_bytes().expect("serialize proof");
     let prove_time = prove_start.elapsed();
 
     let verify_start = Instant::now();
+    let bundle =
+        SchnorrProofBundle::<Proof<MyConfig>>::from_bytes(&proof_bytes).expect("parse proof");
     verify_with_preprocessed(
         &config,
-        &air,
-        &proof,
-        &public_values,
+        &bundle.air(height),
+        &bundle.proof,
+        &bundle.public_values,
         Some(&preprocessed_vk),
     )
     .expect("verify");
+    assert!(
+        check_challenge_binding(&bundle.public_values, &hash_public_values),
+        "challenge not bound to R || pk || msg"
+    );
     let verify_time = verify_start.elapsed();
 
     println!("Proving time: {:?}", prove_time);
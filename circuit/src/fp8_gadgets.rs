@@ -0,0 +1,241 @@
+//! Constraint-level arithmetic over the Fp8 base field of the curve.
+//!
+//! An Fp8 element is carried as its [`COORD_LIMBS`] KoalaBear coefficients,
+//! `u^0` first, in the basis `u^8 = 3` that [`curve::to_coeffs`] uses. The
+//! helpers build expressions over any [`AirBuilder`] with `F = KoalaBear`, so
+//! an AIR over this curve can reuse them for its own point arithmetic:
+//! [`read_fp8`] lifts trace columns into expressions, the `fp8_*` functions
+//! combine them without adding constraints, and [`assert_fp8_eq`] and
+//! [`enforce_on_curve`] emit the constraints.
+//!
+//! Products are degree 2 in their inputs, so chaining [`fp8_mul`] raises the
+//! constraint degree; [`enforce_on_curve`] is degree 3.
+
+use p3_air::AirBuilder;
+use p3_field::PrimeCharacteristicRing;
+
+use crate::scalar_mul_air::COORD_LIMBS;
+use curve::KoalaBear;
+
+/// Reads the Fp8 element stored in columns `start..start + COORD_LIMBS` of `row`.
+pub fn read_fp8<AB: AirBuilder<F = KoalaBear>>(
+    row: &[AB::Var],
+    start: usize,
+) -> [AB::Expr; COORD_LIMBS] {
+    core::array::from_fn(|i| row[start + i].clone().into())
+}
+
+/// Constrains `lhs == rhs` coefficient by coefficient.
+pub fn assert_fp8_eq<AB: AirBuilder<F = KoalaBear>>(
+    builder: &mut AB,
+    lhs: &[AB::Expr; COORD_LIMBS],
+    rhs: &[AB::Expr; COORD_LIMBS],
+) {
+    for i in 0..COORD_LIMBS {
+        builder.assert_eq(lhs[i].clone(), rhs[i].clone());
+    }
+}
+
+/// The constant `1`.
+pub fn fp8_one<AB: AirBuilder<F = KoalaBear>>() -> [AB::Expr; COORD_LIMBS] {
+    let mut out = [AB::Expr::ZERO; COORD_LIMBS];
+    out[0] = AB::Expr::ONE;
+    out
+}
+
+/// The curve coefficient [`CURVE_A`](curve::CURVE_A).
+pub fn fp8_a<AB: AirBuilder<F = KoalaBear>>() -> [AB::Expr; COORD_LIMBS] {
+    curve::to_coeffs(curve::CURVE_A()).map(AB::Expr::from)
+}
+
+/// The curve coefficient [`CURVE_B`](curve::CURVE_B).
+pub fn fp8_b<AB: AirBuilder<F = KoalaBear>>() -> [AB::Expr; COORD_LIMBS] {
+    curve::to_coeffs(curve::CURVE_B()).map(AB::Expr::from)
+}
+
+/// `a + b`.
+pub fn fp8_add<AB: AirBuilder<F = KoalaBear>>(
+    a: &[AB::Expr; COORD_LIMBS],
+    b: &[AB::Expr; COORD_LIMBS],
+) -> [AB::Expr; COORD_LIMBS] {
+    core::array::from_fn(|i| a[i].clone() + b[i].clone())
+}
+
+/// `a - b`.
+pub fn fp8_sub<AB: AirBuilder<F = KoalaBear>>(
+    a: &[AB::Expr; COORD_LIMBS],
+    b: &[AB::Expr; COORD_LIMBS],
+) -> [AB::Expr; COORD_LIMBS] {
+    core::array::from_fn(|i| a[i].clone() - b[i].clone())
+}
+
+/// `a * scalar` for a KoalaBear constant, without raising the degree.
+pub fn fp8_mul_scalar<AB: AirBuilder<F = KoalaBear>>(
+    a: &[AB::Expr; COORD_LIMBS],
+    scalar: KoalaBear,
+) -> [AB::Expr; COORD_LIMBS] {
+    core::array::from_fn(|i| a[i].clone() * scalar)
+}
+
+/// `a * b`: the schoolbook product with the upper half folded back by `u^8 = 3`.
+pub fn fp8_mul<AB: AirBuilder<F = KoalaBear>>(
+    a: &[AB::Expr; COORD_LIMBS],
+    b: &[AB::Expr; COORD_LIMBS],
+) -> [AB::Expr; COORD_LIMBS] {
+    let mut t = vec![AB::Expr::ZERO; 2 * COORD_LIMBS - 1];
+    for (i, a_i) in a.iter().enumerate().take(COORD_LIMBS) {
+        for (j, b_j) in b.iter().enumerate().take(COORD_LIMBS) {
+            let idx = i + j;
+            t[idx] = t[idx].clone() + a_i.clone() * b_j.clone();
+        }
+    }
+
+    let w = KoalaBear::from_u32(3);
+    let mut out = [AB::Expr::ZERO; COORD_LIMBS];
+    for k in 0..COORD_LIMBS {
+        let mut acc = t[k].clone();
+        if k + COORD_LIMBS < t.len() {
+            acc += t[k + COORD_LIMBS].clone() * w;
+        }
+        out[k] = acc;
+    }
+    out
+}
+
+/// Constrains the point with `x` at `x_start` and `y` at `y_start` to satisfy
+/// `y^2 = x^3 + a*x + b`.
+pub fn enforce_on_curve<AB: AirBuilder<F = KoalaBear>>(
+    builder: &mut AB,
+    row: &[AB::Var],
+    x_start: usize,
+    y_start: usize,
+) {
+    let x = read_fp8::<AB>(row, x_start);
+    let y = read_fp8::<AB>(row, y_start);
+    let y2 = fp8_mul::<AB>(&y, &y);
+    let x2 = fp8_mul::<AB>(&x, &x);
+    let x3 = fp8_mul::<AB>(&x2, &x);
+    let ax = fp8_mul::<AB>(&x, &fp8_a::<AB>());
+    let rhs = fp8_add::<AB>(&fp8_add::<AB>(&x3, &ax), &fp8_b::<AB>());
+    assert_fp8_eq(builder, &y2, &rhs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use curve::{Affine, BaseField, Group, RandomField};
+    use p3_matrix::dense::RowMajorMatrix;
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    /// Evaluates constraints on concrete values, counting the nonzero ones.
+    struct MockBuilder {
+        main: RowMajorMatrix<KoalaBear>,
+        failures: usize,
+    }
+
+    impl MockBuilder {
+        fn new(row: Vec<KoalaBear>) -> Self {
+            let width = row.len();
+            Self {
+                main: RowMajorMatrix::new(row, width),
+                failures: 0,
+            }
+        }
+    }
+
+    impl AirBuilder for MockBuilder {
+        type F = KoalaBear;
+        type Expr = KoalaBear;
+        type Var = KoalaBear;
+        type M = RowMajorMatrix<KoalaBear>;
+
+        fn main(&self) -> Self::M {
+            self.main.clone()
+        }
+
+        fn is_first_row(&self) -> Self::Expr {
+            KoalaBear::ONE
+        }
+
+        fn is_last_row(&self) -> Self::Expr {
+            KoalaBear::ONE
+        }
+
+        fn is_transition_window(&self, _size: usize) -> Self::Expr {
+            KoalaBear::ZERO
+        }
+
+        fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+            if x.into() != KoalaBear::ZERO {
+                self.failures += 1;
+            }
+        }
+    }
+
+    type Fp8 = [KoalaBear; COORD_LIMBS];
+
+    fn coeffs(x: BaseField) -> Fp8 {
+        curve::to_coeffs(x)
+    }
+
+    fn native(x: Fp8) -> BaseField {
+        curve::from_coeffs(x)
+    }
+
+    #[test]
+    fn test_gadgets_match_native_arithmetic() {
+        let mut rng = StdRng::seed_from_u64(97);
+        for _ in 0..64 {
+            let a = BaseField::random(&mut rng);
+            let b = BaseField::random(&mut rng);
+            let k: KoalaBear = rng.random();
+            let (ca, cb) = (coeffs(a), coeffs(b));
+
+            assert_eq!(native(fp8_add::<MockBuilder>(&ca, &cb)), a + b);
+            assert_eq!(native(fp8_sub::<MockBuilder>(&ca, &cb)), a - b);
+            assert_eq!(native(fp8_mul::<MockBuilder>(&ca, &cb)), a * b);
+            assert_eq!(
+                native(fp8_mul_scalar::<MockBuilder>(&ca, k)),
+                a * BaseField::from(k)
+            );
+        }
+
+        assert_eq!(native(fp8_one::<MockBuilder>()), BaseField::ONE);
+        assert_eq!(native(fp8_a::<MockBuilder>()), curve::CURVE_A());
+        assert_eq!(native(fp8_b::<MockBuilder>()), curve::CURVE_B());
+    }
+
+    #[test]
+    fn test_assert_fp8_eq_counts_mismatches() {
+        let mut rng = StdRng::seed_from_u64(97);
+        let a = BaseField::random(&mut rng);
+        let row: Vec<KoalaBear> = [coeffs(a), coeffs(a + BaseField::ONE)].concat();
+        let mut builder = MockBuilder::new(row.clone());
+
+        let x = read_fp8::<MockBuilder>(&row, 0);
+        let y = read_fp8::<MockBuilder>(&row, COORD_LIMBS);
+        assert_eq!(native(x), a);
+        assert_fp8_eq(&mut builder, &x, &x);
+        assert_eq!(builder.failures, 0);
+
+        // Only the constant coefficient differs
+        assert_fp8_eq(&mut builder, &x, &y);
+        assert_eq!(builder.failures, 1);
+    }
+
+    #[test]
+    fn test_enforce_on_curve() {
+        let point = Affine::generator().mul_u64(97);
+        let row: Vec<KoalaBear> = [coeffs(point.x), coeffs(point.y)].concat();
+        let mut builder = MockBuilder::new(row.clone());
+        enforce_on_curve(&mut builder, &row, 0, COORD_LIMBS);
+        assert_eq!(builder.failures, 0);
+
+        let off_curve: Vec<KoalaBear> =
+            [coeffs(point.x), coeffs(point.y + BaseField::ONE)].concat();
+        let mut builder = MockBuilder::new(off_curve.clone());
+        enforce_on_curve(&mut builder, &off_curve, 0, COORD_LIMBS);
+        assert!(builder.failures > 0);
+    }
+}
@@ -70,7 +70,7 @@
 //! - `s`: Signature response scalar
 //! - `R`: Signature commitment point
 //! - `pk`: Public key (verifying key)
-//! - `e`: Fiat-Shamir challenge `e = Poseidon2(R || pk || msg)`
+//! - `e`: Fiat-Shamir challenge `e = Poseidon2(R || pk || len(msg) || msg)`
 //!
 //! This is computed as:
 //! 1. `P₁ = G × s` (fixed-base scalar multiplication, preprocessed)
@@ -114,6 +114,9 @@
 //! - `SCALAR_LIMBS = 9` - Number of limbs
 //! - `LIMB_BITS = 28` - Bits per limb
 //!
+//! **Range checks**: [`ScalarRangeAir`] decomposes each limb into 28 boolean
+//! columns in `scalar_to_bits` order, enforcing `limb < 2²⁸`.
+//!
 //! **Methods**:
 //! - `from_scalar_field(scalar)` - Convert from curve::ScalarField
 //!
@@ -124,9 +127,12 @@
 //! Complete Schnorr verification constraint system.
 //!
 //! **Trace Structure**:
-//! - Main trace: 49 columns (point coordinates, scalars, intermediate values)
+//! - Main trace: 159 columns (point coordinates, scalars, intermediate values)
 //! - Preprocessed trace: Generator multiplication table
-//! - Public inputs: 32 KoalaBear elements (pk: 16 + R: 16)
+//! - `SchnorrAir::with_window(height, k)` with `build_schnorr_trace_with_window`
+//!   consumes `k` bits per row: the height drops by `k` while the main and
+//!   preprocessed widths (`schnorr_columns(k)`, `gs_prep_columns(k)`) grow by `k`
+//! - Public inputs: 41 KoalaBear elements (pk: 16 + R: 16 + -e: 9)
 //!
 //! **Usage**:
 //! ```rust,ignore
@@ -168,9 +174,13 @@
 //! - `POSEIDON2_WIDTH = 16` - Sponge width
 //! - `POSEIDON2_RATE = 8` - Absorption rate
 //! - `POSEIDON2_OUT = 8` - Output size
+//! - `POSEIDON2_DIGEST_LEN = 16` - Public squeezed output (digest plus one extra permutation)
 //!
-//! **Function**:
-//! - `build_poseidon2_hash_trace(input, output)` - Generate hash constraint trace
+//! **Functions**:
+//! - `build_poseidon2_hash_trace(input)` - Generate the challenge hash trace
+//! - `build_poseidon2_hash_trace_with_perms::<N>(input)` - Any input length taking
+//!   `N = poseidon2_num_permutations(len)` permutations, proven with
+//!   `Poseidon2HashAir::<N>::with_input_len(len)`
 //!
 //! ## Circuit Architecture
 //!
@@ -206,17 +216,19 @@
 //!
 //! ## Trace Structure
 //!
-//! ### Main Trace (49 columns × height rows)
+//! ### Main Trace (159 columns × height rows)
 //!
-//! **Layout**:
-//! - Columns 0-15: Generator multiplication result (P₁ = G × s)
-//! - Columns 16-31: Public key multiplication result (P₂ = pk × e)
-//! - Columns 32-47: Point subtraction result (P₃ = P₁ - P₂)
-//! - Column 48: Control/selector flags
+//! Row `i` consumes bit `i` of `s` and of `-e` (Shamir's trick):
+//! - Accumulator and the current `2^i · pk`, plus the doubling of `pk`
+//! - `mid = acc + s_i · 2^i G`, with `2^i G` read from the preprocessed trace
+//! - `acc' = mid + e_i · 2^i pk`
+//! - Chord/tangent inverses and slopes for each of the three operations
+//! - Bit, infinity and add-activity flags, and the running `-e` limbs
 //!
 //! **Height**: Must be power of 2 (typically 256-4096)
 //! - Larger heights: More computation, better amortization
 //! - Smaller heights: Less memory, faster proving for simple cases
+//! - [`build_schnorr_trace_with_height`] pads with inert rows to a fixed height
 //!
 //! ### Preprocessed Trace
 //!
@@ -225,13 +237,28 @@
 //! - Precomputed multiples: [G, 2G, 4G, 8G, ...]
 //! - Reduces online computation during proving
 //!
-//! ### Public Inputs (32 elements)
+//! ### Public Inputs (41 elements)
 //!
 //! **Layout**:
 //! - Elements 0-15: Public key (pk.x: 8, pk.y: 8)
 //! - Elements 16-31: Commitment point (R.x: 8, R.y: 8)
+//! - Elements 32-40: Negated challenge `-e` (9 × 28-bit limbs)
+//!
+//! Verifier checks these match the claimed values. The challenge bits in the
+//! trace are constrained to the `-e` limbs, and `e = Poseidon2(R || pk || len(msg) || msg)`
+//! is proven by a separate [`Poseidon2HashAir`] proof over BabyBear. Use
+//! [`schnorr_public_values`] and [`challenge_hash_public_values`] to build the
+//! two public-value vectors, and [`check_challenge_binding`] to link them.
+//! [`SchnorrProofBundle`] stores a proof with its public values, window and
+//! public-challenge mode in a versioned byte format.
 //!
-//! Verifier checks these match the claimed values.
+//! ### Divergence from `schnorr`
+//!
+//! When the challenge digest reduces to zero, `schnorr::hash_challenge`
+//! re-hashes with a counter appended. The circuit does not implement this
+//! retry: [`Poseidon2HashAir`] proves only the first hash, and
+//! [`check_challenge_binding`] rejects a zero digest. Such a signature verifies
+//! natively but cannot be proven; a zero digest has probability about `2^-248`.
 //!
 //! Run benchmarks: `cargo bench -p circuit`
 //!
@@ -261,13 +288,14 @@
 //! 2. **Efficient Limb Width**: 28-bit limbs minimize range check overhead
 //! 3. **Native Field Ops**: Elliptic curve ops use native KoalaBear arithmetic
 //! 4. **Complete Formulas**: Unified addition avoiding edge-case branching
+//! 5. **Windowed Multiplication**: [`WindowedScalarMulAir`] consumes `w` bits per row
+//!    against an in-row table, cutting trace height by `w×`
 //!
 //! ### Future Optimizations
 //!
-//! 1. **Windowed Multiplication**: 4-bit windows reduce doublings
-//! 2. **Shamir's Trick**: Simultaneous double scalar multiplication
-//! 3. **Batch Verification**: Amortize constraints across multiple signatures
-//! 4. **Lookup Tables**: Replace range checks with table lookups
+//! 1. **Shamir's Trick**: Simultaneous double scalar multiplication
+//! 2. **Batch Verification**: Amortize constraints across multiple signatures
+//! 3. **Lookup Tables**: Replace range checks with table lookups
 //!
 //! **Production Use**: Conduct independent security review before production deployment.
 //!
@@ -336,28 +364,46 @@
 //! - Poseidon2: <https://eprint.iacr.org/2023/323>
 //! - Non-native field arithmetic in circuits: <https://eprint.iacr.org/2019/458>
 
+#[deny(missing_docs)]
+pub mod fp8_gadgets;
 #[deny(missing_docs)]
 mod point_ops;
 pub mod poseidon2_hash_air;
+mod proof_bundle;
 mod scalar_arithmetic;
 pub mod scalar_mul_air;
 pub mod schnorr_air;
 mod signature_witness;
+#[cfg(test)]
+mod test_utils;
 
-pub use point_ops::{scalar_to_bits, CircuitPoint};
+pub use point_ops::{scalar_to_bits, scalar_to_bits_trimmed, CircuitPoint};
 pub use poseidon2_hash_air::{
-    build_poseidon2_hash_trace, Poseidon2HashAir, Poseidon2HashTrace, POSEIDON2_INPUT_LEN,
-    POSEIDON2_NUM_PERMS, POSEIDON2_OUT, POSEIDON2_PACKED_LIMBS, POSEIDON2_RATE, POSEIDON2_WIDTH,
+    build_poseidon2_hash_trace, build_poseidon2_hash_trace_with_perms, poseidon2_num_permutations,
+    Poseidon2HashAir, Poseidon2HashTrace, POSEIDON2_DIGEST_LEN, POSEIDON2_INPUT_LEN,
+    POSEIDON2_NUM_PERMS, POSEIDON2_OUT, POSEIDON2_RATE, POSEIDON2_WIDTH,
+};
+pub use proof_bundle::{SchnorrProofBundle, PROOF_BUNDLE_VERSION};
+pub use scalar_arithmetic::{
+    build_scalar_range_trace, CircuitScalar, ScalarRangeAir, LIMB_BITS, SCALAR_BITS, SCALAR_LIMBS,
+    SCALAR_RANGE_COLUMNS,
 };
-pub use scalar_arithmetic::{CircuitScalar, LIMB_BITS, SCALAR_LIMBS};
 pub use signature_witness::SignatureWitness;
 
 // Re-export commonly used types
 pub use curve::{Affine, BaseField, KoalaBear, ScalarField};
 pub use scalar_mul_air::{
-    build_generator_mul_trace, build_scalar_mul_trace, ScalarMulAir, ScalarMulTrace, ACC_X_START,
-    ACC_Y_START, BASE_X_START, BASE_Y_START, NUM_COLUMNS as SCALAR_MUL_NUM_COLUMNS,
-    PUBLIC_BASE_LIMBS, PUBLIC_OUT_LIMBS,
+    build_generator_mul_trace, build_scalar_mul_trace, build_scalar_mul_trace_windowed,
+    windowed_num_columns, ScalarMulAir, ScalarMulTrace, WindowedScalarMulAir, ACC_X_START,
+    ACC_Y_START, BASE_X_START, BASE_Y_START, MAX_WINDOW_BITS,
+    NUM_COLUMNS as SCALAR_MUL_NUM_COLUMNS, PUBLIC_BASE_LIMBS, PUBLIC_OUT_LIMBS,
 };
 pub use schnorr::{Signature, SigningKey, VerifyingKey};
-pub use schnorr_air::{build_schnorr_trace, SchnorrAir, SchnorrTrace, SCHNORR_COLUMNS};
+pub use schnorr_air::{
+    build_challenge_hash_trace, build_schnorr_trace, build_schnorr_trace_with_height,
+    build_schnorr_trace_with_window, challenge_hash_public_values, check_challenge_binding,
+    column_layout, gs_prep_columns, schnorr_columns, schnorr_public_values,
+    schnorr_public_values_with_challenge, ColumnLayout, SchnorrAir, SchnorrTrace,
+    MAX_SCHNORR_WINDOW, SCHNORR_COLUMNS, SCHNORR_PUBLIC_VALUES,
+    SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE,
+};
@@ -3,7 +3,7 @@
 //! This module provides elliptic curve operations optimized for circuit use,
 //! where all operations are performed using native KoalaBear field arithmetic.
 
-use curve::{Affine, BaseField, KoalaBear};
+use curve::{from_coeffs, to_coeffs, Affine, BaseField, KoalaBear};
 use p3_field::PrimeCharacteristicRing;
 
 /// Elliptic curve point in circuit representation.
@@ -36,12 +36,9 @@ impl CircuitPoint {
             return Self::infinity();
         }
 
-        let x_coeffs: [KoalaBear; 8] = unsafe { core::mem::transmute(point.x) };
-        let y_coeffs: [KoalaBear; 8] = unsafe { core::mem::transmute(point.y) };
-
         Self {
-            x: x_coeffs,
-            y: y_coeffs,
+            x: to_coeffs(point.x),
+            y: to_coeffs(point.y),
             is_infinity: false,
         }
     }
@@ -52,20 +49,17 @@ impl CircuitPoint {
             return Affine::INFINITY;
         }
 
-        let x: BaseField = unsafe { core::mem::transmute(self.x) };
-        let y: BaseField = unsafe { core::mem::transmute(self.y) };
-
-        Affine::new(x, y)
+        Affine::new(self.x_as_basefield(), self.y_as_basefield())
     }
 
     /// Get the x-coordinate as a BaseField element
     pub fn x_as_basefield(&self) -> BaseField {
-        unsafe { core::mem::transmute(self.x) }
+        from_coeffs(self.x)
     }
 
     /// Get the y-coordinate as a BaseField element
     pub fn y_as_basefield(&self) -> BaseField {
-        unsafe { core::mem::transmute(self.y) }
+        from_coeffs(self.y)
     }
 
     /// Point addition in the circuit
@@ -126,16 +120,21 @@ impl CircuitPoint {
 }
 
 /// Convert a scalar field element to bit representation for scalar multiplication
+///
+/// Always returns 256 little-endian bits. Canonical scalars are below 2^248, so
+/// the top bits are zero padding; they keep row layouts aligned across scalars.
 pub fn scalar_to_bits(scalar: &curve::ScalarField) -> Vec<bool> {
-    let limbs = scalar.to_canonical_u64_vec();
-    let mut bits = Vec::with_capacity(256);
-
-    for limb in &limbs {
-        for i in 0..64 {
-            bits.push((*limb >> i) & 1 == 1);
-        }
-    }
+    scalar.to_bits_le()
+}
 
+/// Little-endian bits of `scalar` up to its top set bit (`num_bits()` bits).
+///
+/// The last bit is always set, and zero gives an empty vector. Use this where
+/// the trace height may depend on the scalar, to skip the padding rows of
+/// [`scalar_to_bits`].
+pub fn scalar_to_bits_trimmed(scalar: &curve::ScalarField) -> Vec<bool> {
+    let mut bits = scalar.to_bits_le();
+    bits.truncate(scalar.num_bits());
     bits
 }
 
@@ -195,4 +194,31 @@ mod tests {
 
         assert_eq!(result.to_affine(), expected);
     }
+
+    #[test]
+    fn test_scalar_to_bits_trimmed_reconstructs() {
+        let scalars = [
+            ScalarField::ONE,
+            ScalarField::from_canonical_u64(0x1234_5678),
+            ScalarField::from_canonical_limbs([0, 1, 0, 0]),
+            -ScalarField::ONE,
+        ];
+        for scalar in scalars {
+            let bits = scalar_to_bits_trimmed(&scalar);
+            assert_eq!(bits.len(), scalar.num_bits());
+            assert_eq!(bits.last(), Some(&true));
+            assert_eq!(bits[..], scalar_to_bits(&scalar)[..bits.len()]);
+
+            let rebuilt = bits.iter().rev().fold(ScalarField::ZERO, |acc, &bit| {
+                acc.double()
+                    + if bit {
+                        ScalarField::ONE
+                    } else {
+                        ScalarField::ZERO
+                    }
+            });
+            assert_eq!(rebuilt, scalar);
+        }
+        assert!(scalar_to_bits_trimmed(&ScalarField::ZERO).is_empty());
+    }
 }
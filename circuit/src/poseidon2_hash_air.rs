@@ -1,7 +1,46 @@
 //! Poseidon2 hash AIR for proving hash_challenge inputs in BabyBear.
 //!
-//! This module proves a Poseidon2 permutation trace and constrains the final
-//! digest (first OUT elements of the last permutation output) as public values.
+//! This module proves a Poseidon2 permutation trace and constrains the squeezed
+//! output as public values: the sponge digest (first OUT elements of the state
+//! after absorption) followed by the first OUT elements of one extra squeeze
+//! permutation. Reducing the resulting `POSEIDON2_DIGEST_LEN` elements into the
+//! scalar field is non-native arithmetic and is done by the verifier, see
+//! `check_challenge_binding`.
+//!
+//! # Input length
+//!
+//! The AIR places every permutation of one hash side by side in a single row, so
+//! the number of permutations is the const parameter `NUM_PERMS` and the input
+//! length is fixed per AIR instance. An input of `len` elements takes
+//! [`poseidon2_num_permutations`]`(len) = ceil(len / 8) + 1` permutations, and any
+//! length with that count can be proven with [`Poseidon2HashAir::with_input_len`].
+//! [`Poseidon2HashAir::new`] is the challenge configuration, `POSEIDON2_INPUT_LEN`
+//! elements in `POSEIDON2_NUM_PERMS` permutations.
+//!
+//! # Padding
+//!
+//! The sponge absorbs the input in blocks of `POSEIDON2_RATE = 8` elements, each
+//! written over lanes `0..8` of the state before a permutation. An input whose
+//! length is not a multiple of 8 is completed to a whole final block by the
+//! state itself:
+//!
+//! - lanes `0..len % 8` of the final permutation input are the last `len % 8`
+//!   input elements;
+//! - lanes `len % 8..16` are whatever the previous permutation output holds in
+//!   those lanes, or zero when the short block is also the first block.
+//!
+//! No padding element is appended, so the digest equals the `PaddingFreeSponge`
+//! digest that `schnorr` computes natively. On its own this rule does not
+//! separate lengths, since appending the inherited lanes to a short input gives
+//! the same state; the length is instead fixed by the AIR instance and by the
+//! number of public values, and the Schnorr challenge input also carries
+//! `len(msg)`.
+//!
+//! # Public values
+//!
+//! `input || digest || num_permutations`: the `input_len` input elements, before
+//! padding, then the `POSEIDON2_DIGEST_LEN` squeezed elements and the
+//! permutation count.
 
 use core::borrow::Borrow;
 
@@ -22,18 +61,28 @@ pub const POSEIDON2_SBOX_DEGREE: u64 = 7;
 pub const POSEIDON2_SBOX_REGISTERS: usize = 1;
 pub const POSEIDON2_HALF_FULL_ROUNDS: usize = 4;
 pub const POSEIDON2_PARTIAL_ROUNDS: usize = 13;
-pub const POSEIDON2_INPUT_LEN: usize = 35;
-pub const POSEIDON2_NUM_PERMS: usize = POSEIDON2_INPUT_LEN.div_ceil(POSEIDON2_RATE);
-pub const POSEIDON2_PACKED_LIMBS: usize = 3;
+/// Challenge input length: `R || pk` (32), the message length (1) and a
+/// 3-element message.
+pub const POSEIDON2_INPUT_LEN: usize = 36;
+/// Absorbing permutations plus one squeeze permutation.
+pub const POSEIDON2_NUM_PERMS: usize = poseidon2_num_permutations(POSEIDON2_INPUT_LEN);
+/// Squeezed output elements, matching `schnorr::CHALLENGE_DIGEST_LEN`.
+pub const POSEIDON2_DIGEST_LEN: usize = 2 * POSEIDON2_OUT;
+
+/// Permutations needed to hash `input_len` elements: one per started rate block
+/// plus the extra squeeze permutation.
+pub const fn poseidon2_num_permutations(input_len: usize) -> usize {
+    input_len.div_ceil(POSEIDON2_RATE) + 1
+}
 
 #[derive(Clone, Debug)]
 pub struct Poseidon2HashTrace {
     pub trace: RowMajorMatrix<BabyBear>,
-    pub digest: [BabyBear; POSEIDON2_OUT],
+    pub digest: [BabyBear; POSEIDON2_DIGEST_LEN],
     pub num_permutations: usize,
 }
 
-pub struct Poseidon2HashAir {
+pub struct Poseidon2HashAir<const NUM_PERMS: usize = POSEIDON2_NUM_PERMS> {
     inner: VectorizedPoseidon2Air<
         BabyBear,
         GenericPoseidon2LinearLayersBabyBear,
@@ -42,20 +91,39 @@ pub struct Poseidon2HashAir {
         POSEIDON2_SBOX_REGISTERS,
         POSEIDON2_HALF_FULL_ROUNDS,
         POSEIDON2_PARTIAL_ROUNDS,
-        POSEIDON2_NUM_PERMS,
+        NUM_PERMS,
     >,
+    input_len: usize,
 }
 
 impl Poseidon2HashAir {
+    /// AIR for the Schnorr challenge input of `POSEIDON2_INPUT_LEN` elements.
     pub fn new() -> Self {
+        Self::with_input_len(POSEIDON2_INPUT_LEN).expect("challenge input length")
+    }
+}
+
+impl<const NUM_PERMS: usize> Poseidon2HashAir<NUM_PERMS> {
+    /// AIR for hashing `input_len` elements.
+    ///
+    /// Errors unless `input_len` is non-zero and
+    /// `poseidon2_num_permutations(input_len) == NUM_PERMS`.
+    pub fn with_input_len(input_len: usize) -> Result<Self, String> {
+        check_input_len(input_len, NUM_PERMS)?;
         let constants = RoundConstants::new(
             BABYBEAR_RC16_EXTERNAL_INITIAL,
             BABYBEAR_RC16_INTERNAL,
             BABYBEAR_RC16_EXTERNAL_FINAL,
         );
-        Self {
+        Ok(Self {
             inner: VectorizedPoseidon2Air::new(constants),
-        }
+            input_len,
+        })
+    }
+
+    /// Number of input elements this AIR hashes.
+    pub fn input_len(&self) -> usize {
+        self.input_len
     }
 }
 
@@ -65,19 +133,19 @@ impl Default for Poseidon2HashAir {
     }
 }
 
-impl BaseAir<BabyBear> for Poseidon2HashAir {
+impl<const NUM_PERMS: usize> BaseAir<BabyBear> for Poseidon2HashAir<NUM_PERMS> {
     fn width(&self) -> usize {
         self.inner.width()
     }
 }
 
-impl BaseAirWithPublicValues<BabyBear> for Poseidon2HashAir {
+impl<const NUM_PERMS: usize> BaseAirWithPublicValues<BabyBear> for Poseidon2HashAir<NUM_PERMS> {
     fn num_public_values(&self) -> usize {
-        POSEIDON2_INPUT_LEN + POSEIDON2_OUT + POSEIDON2_PACKED_LIMBS
+        self.input_len + POSEIDON2_DIGEST_LEN + 1
     }
 }
 
-impl<AB> Air<AB> for Poseidon2HashAir
+impl<AB, const NUM_PERMS: usize> Air<AB> for Poseidon2HashAir<NUM_PERMS>
 where
     AB: AirBuilder<F = BabyBear> + AirBuilderWithPublicValues,
 {
@@ -86,11 +154,12 @@ where
 
         let main = builder.main();
         let local = main.row_slice(0).expect("Poseidon2 hash trace is empty");
-        // Constrain the digest (first OUT elements of final state) to public values on last row.
+        // Constrain the squeezed output of the last two permutations to public values on last row.
         let public = builder.public_values().to_vec();
-        let (public_inputs, rest) = public.split_at(POSEIDON2_INPUT_LEN);
-        let (public_digest, public_packed) = rest.split_at(POSEIDON2_OUT);
+        let (public_inputs, rest) = public.split_at(self.input_len);
+        let (public_digest, public_num_perms) = rest.split_at(POSEIDON2_DIGEST_LEN);
         let mut builder = builder.when_last_row();
+        builder.assert_eq(public_num_perms[0], BabyBear::from_usize(NUM_PERMS));
         let row = (*local).as_ref();
         let perm_width = num_cols::<
             POSEIDON2_WIDTH,
@@ -100,7 +169,7 @@ where
             POSEIDON2_PARTIAL_ROUNDS,
         >();
 
-        for perm_idx in 0..POSEIDON2_NUM_PERMS {
+        for perm_idx in 0..NUM_PERMS {
             let start = perm_idx * perm_width;
             let perm_slice = &row[start..start + perm_width];
             let perm_cols: &Poseidon2Cols<
@@ -113,11 +182,13 @@ where
             > = perm_slice.borrow();
             let inputs = &perm_cols.inputs;
             if perm_idx == 0 {
-                for i in 0..POSEIDON2_RATE {
-                    builder.assert_eq(inputs[i].clone(), public_inputs[i]);
-                }
-                for input in inputs.iter().take(POSEIDON2_WIDTH).skip(POSEIDON2_RATE) {
-                    builder.assert_eq(input.clone(), BabyBear::ZERO);
+                // Lanes past a short first block keep the zero initial state
+                for (i, input) in inputs.iter().enumerate() {
+                    if i < POSEIDON2_RATE && i < self.input_len {
+                        builder.assert_eq(input.clone(), public_inputs[i]);
+                    } else {
+                        builder.assert_eq(input.clone(), BabyBear::ZERO);
+                    }
                 }
                 continue;
             }
@@ -135,7 +206,7 @@ where
             let prev_output = &prev_cols.ending_full_rounds[POSEIDON2_HALF_FULL_ROUNDS - 1].post;
             for i in 0..POSEIDON2_WIDTH {
                 let msg_index = perm_idx * POSEIDON2_RATE + i;
-                if i < POSEIDON2_RATE && msg_index < POSEIDON2_INPUT_LEN {
+                if i < POSEIDON2_RATE && msg_index < self.input_len {
                     builder.assert_eq(inputs[i].clone(), public_inputs[msg_index]);
                 } else {
                     builder.assert_eq(inputs[i].clone(), prev_output[i].clone());
@@ -143,38 +214,28 @@ where
             }
         }
 
-        let output_start = (POSEIDON2_NUM_PERMS - 1) * perm_width;
-        let output_slice = &row[output_start..output_start + perm_width];
-        let output_cols: &Poseidon2Cols<
-            AB::Var,
-            POSEIDON2_WIDTH,
-            POSEIDON2_SBOX_DEGREE,
-            POSEIDON2_SBOX_REGISTERS,
-            POSEIDON2_HALF_FULL_ROUNDS,
-            POSEIDON2_PARTIAL_ROUNDS,
-        > = output_slice.borrow();
-        let output = &output_cols.ending_full_rounds[POSEIDON2_HALF_FULL_ROUNDS - 1].post;
-        for i in 0..POSEIDON2_OUT {
-            builder.assert_eq(output[i].clone(), public_digest[i]);
+        for (block, perm_idx) in [NUM_PERMS - 2, NUM_PERMS - 1].into_iter().enumerate() {
+            let output_start = perm_idx * perm_width;
+            let output_slice = &row[output_start..output_start + perm_width];
+            let output_cols: &Poseidon2Cols<
+                AB::Var,
+                POSEIDON2_WIDTH,
+                POSEIDON2_SBOX_DEGREE,
+                POSEIDON2_SBOX_REGISTERS,
+                POSEIDON2_HALF_FULL_ROUNDS,
+                POSEIDON2_PARTIAL_ROUNDS,
+            > = output_slice.borrow();
+            let output = &output_cols.ending_full_rounds[POSEIDON2_HALF_FULL_ROUNDS - 1].post;
+            for i in 0..POSEIDON2_OUT {
+                builder.assert_eq(output[i].clone(), public_digest[block * POSEIDON2_OUT + i]);
+            }
         }
-
-        let two_pow_31 = BabyBear::from_u32(1 << 31);
-        builder.assert_eq(
-            output[0].clone() + output[1].clone() * two_pow_31,
-            public_packed[0],
-        );
-        builder.assert_eq(
-            output[2].clone() + output[3].clone() * two_pow_31,
-            public_packed[1],
-        );
-        builder.assert_eq(output[4].clone(), public_packed[2]);
     }
 }
 
+/// Build the trace for [`Poseidon2HashAir::new`], which hashes exactly
+/// `POSEIDON2_INPUT_LEN` elements.
 pub fn build_poseidon2_hash_trace(input: &[BabyBear]) -> Result<Poseidon2HashTrace, String> {
-    if input.is_empty() {
-        return Err("Poseidon2 hash input must be non-empty".to_string());
-    }
     if input.len() != POSEIDON2_INPUT_LEN {
         return Err(format!(
             "Poseidon2 hash input length {} does not match expected {}",
@@ -182,6 +243,16 @@ pub fn build_poseidon2_hash_trace(input: &[BabyBear]) -> Result<Poseidon2HashTra
             POSEIDON2_INPUT_LEN
         ));
     }
+    build_poseidon2_hash_trace_with_perms::<POSEIDON2_NUM_PERMS>(input)
+}
+
+/// Build the trace for [`Poseidon2HashAir::with_input_len`]`(input.len())`.
+///
+/// Errors if `input` is empty or does not take exactly `NUM_PERMS` permutations.
+pub fn build_poseidon2_hash_trace_with_perms<const NUM_PERMS: usize>(
+    input: &[BabyBear],
+) -> Result<Poseidon2HashTrace, String> {
+    check_input_len(input.len(), NUM_PERMS)?;
 
     let perm = default_babybear_poseidon2_16();
     let mut state = [BabyBear::ZERO; POSEIDON2_WIDTH];
@@ -195,9 +266,12 @@ pub fn build_poseidon2_hash_trace(input: &[BabyBear]) -> Result<Poseidon2HashTra
         perm.permute_mut(&mut state);
     }
 
-    let digest: [BabyBear; POSEIDON2_OUT] = state[..POSEIDON2_OUT]
-        .try_into()
-        .map_err(|_| "Failed to extract Poseidon2 digest".to_string())?;
+    // Squeeze a second block with one more permutation of the full state
+    let mut digest = [BabyBear::ZERO; POSEIDON2_DIGEST_LEN];
+    digest[..POSEIDON2_OUT].copy_from_slice(&state[..POSEIDON2_OUT]);
+    inputs.push(state);
+    perm.permute_mut(&mut state);
+    digest[POSEIDON2_OUT..].copy_from_slice(&state[..POSEIDON2_OUT]);
 
     let constants = RoundConstants::new(
         BABYBEAR_RC16_EXTERNAL_INITIAL,
@@ -213,7 +287,7 @@ pub fn build_poseidon2_hash_trace(input: &[BabyBear]) -> Result<Poseidon2HashTra
         POSEIDON2_SBOX_REGISTERS,
         POSEIDON2_HALF_FULL_ROUNDS,
         POSEIDON2_PARTIAL_ROUNDS,
-        POSEIDON2_NUM_PERMS,
+        NUM_PERMS,
     >(inputs, &constants, 0);
 
     Ok(Poseidon2HashTrace {
@@ -222,3 +296,131 @@ pub fn build_poseidon2_hash_trace(input: &[BabyBear]) -> Result<Poseidon2HashTra
         num_permutations,
     })
 }
+
+fn check_input_len(input_len: usize, num_perms: usize) -> Result<(), String> {
+    if input_len == 0 {
+        return Err("Poseidon2 hash input must be non-empty".to_string());
+    }
+    if poseidon2_num_permutations(input_len) != num_perms {
+        return Err(format!(
+            "Poseidon2 hash input length {input_len} needs {} permutations, not {num_perms}",
+            poseidon2_num_permutations(input_len)
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use p3_symmetric::{CryptographicHasher, PaddingFreeSponge};
+
+    fn input(len: usize) -> Vec<BabyBear> {
+        (0..len as u32)
+            .map(|i| BabyBear::from_u32(3 * i + 1))
+            .collect()
+    }
+
+    fn check_digest<const NUM_PERMS: usize>(len: usize) {
+        let input = input(len);
+        let hash = build_poseidon2_hash_trace_with_perms::<NUM_PERMS>(&input).unwrap();
+        assert_eq!(hash.num_permutations, NUM_PERMS);
+
+        let sponge = PaddingFreeSponge::<_, POSEIDON2_WIDTH, POSEIDON2_RATE, POSEIDON2_OUT>::new(
+            default_babybear_poseidon2_16(),
+        );
+        let reference: [BabyBear; POSEIDON2_OUT] = sponge.hash_iter(input.iter().copied());
+        assert_eq!(hash.digest[..POSEIDON2_OUT], reference);
+
+        let air = Poseidon2HashAir::<NUM_PERMS>::with_input_len(len).unwrap();
+        assert_eq!(air.input_len(), len);
+        assert_eq!(air.num_public_values(), len + POSEIDON2_DIGEST_LEN + 1);
+        assert_eq!(hash.trace.width(), air.width());
+    }
+
+    #[test]
+    fn test_variable_input_lengths_match_sponge() {
+        check_digest::<{ poseidon2_num_permutations(8) }>(8);
+        check_digest::<{ poseidon2_num_permutations(16) }>(16);
+        check_digest::<{ poseidon2_num_permutations(35) }>(35);
+        check_digest::<{ poseidon2_num_permutations(5) }>(5);
+        check_digest::<POSEIDON2_NUM_PERMS>(POSEIDON2_INPUT_LEN);
+    }
+
+    /// The permutation inputs of the hash trace's single row.
+    fn permutation_inputs<const NUM_PERMS: usize>(
+        hash: &Poseidon2HashTrace,
+    ) -> Vec<[BabyBear; POSEIDON2_WIDTH]> {
+        let perm_width = num_cols::<
+            POSEIDON2_WIDTH,
+            POSEIDON2_SBOX_DEGREE,
+            POSEIDON2_SBOX_REGISTERS,
+            POSEIDON2_HALF_FULL_ROUNDS,
+            POSEIDON2_PARTIAL_ROUNDS,
+        >();
+        let row = hash.trace.row_slice(0).unwrap();
+        (0..NUM_PERMS)
+            .map(|perm_idx| {
+                let cols: &Poseidon2Cols<
+                    BabyBear,
+                    POSEIDON2_WIDTH,
+                    POSEIDON2_SBOX_DEGREE,
+                    POSEIDON2_SBOX_REGISTERS,
+                    POSEIDON2_HALF_FULL_ROUNDS,
+                    POSEIDON2_PARTIAL_ROUNDS,
+                > = row[perm_idx * perm_width..(perm_idx + 1) * perm_width].borrow();
+                cols.inputs.clone()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_short_final_block_padding() {
+        let perm = default_babybear_poseidon2_16();
+
+        // 13 elements: one full block, then 5 elements over the first output
+        const LEN: usize = 13;
+        let input = input(LEN);
+        let hash =
+            build_poseidon2_hash_trace_with_perms::<{ poseidon2_num_permutations(LEN) }>(&input)
+                .unwrap();
+        let inputs = permutation_inputs::<{ poseidon2_num_permutations(LEN) }>(&hash);
+
+        let mut first = [BabyBear::ZERO; POSEIDON2_WIDTH];
+        first[..POSEIDON2_RATE].copy_from_slice(&input[..POSEIDON2_RATE]);
+        assert_eq!(inputs[0], first);
+
+        let carried = perm.permute(first);
+        assert_eq!(inputs[1][..LEN - POSEIDON2_RATE], input[POSEIDON2_RATE..]);
+        assert_eq!(
+            inputs[1][LEN - POSEIDON2_RATE..],
+            carried[LEN - POSEIDON2_RATE..]
+        );
+
+        // The squeeze permutation starts from the final absorbed state
+        assert_eq!(inputs[2], perm.permute(inputs[1]));
+        assert_eq!(hash.digest[..POSEIDON2_OUT], inputs[2][..POSEIDON2_OUT]);
+
+        // A short first block leaves the remaining lanes zero
+        let hash =
+            build_poseidon2_hash_trace_with_perms::<{ poseidon2_num_permutations(5) }>(&input[..5])
+                .unwrap();
+        let inputs = permutation_inputs::<{ poseidon2_num_permutations(5) }>(&hash);
+        assert_eq!(inputs[0][..5], input[..5]);
+        assert!(inputs[0][5..].iter().all(|&lane| lane == BabyBear::ZERO));
+    }
+
+    #[test]
+    fn test_input_length_must_match_permutations() {
+        assert_eq!(poseidon2_num_permutations(8), 2);
+        assert_eq!(poseidon2_num_permutations(9), 3);
+        assert_eq!(poseidon2_num_permutations(35), 6);
+
+        assert!(build_poseidon2_hash_trace_with_perms::<3>(&input(8)).is_err());
+        assert!(build_poseidon2_hash_trace_with_perms::<2>(&[]).is_err());
+        assert!(Poseidon2HashAir::<6>::with_input_len(16).is_err());
+        assert!(Poseidon2HashAir::<2>::with_input_len(0).is_err());
+        assert!(build_poseidon2_hash_trace(&input(35)).is_err());
+        assert_eq!(Poseidon2HashAir::new().input_len(), POSEIDON2_INPUT_LEN);
+    }
+}
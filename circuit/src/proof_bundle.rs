@@ -0,0 +1,339 @@
+//! Versioned serialization of [`SchnorrAir`](crate::SchnorrAir) proofs together
+//! with their public values.
+//!
+//! # Format
+//!
+//! | Field | Encoding |
+//! |-------|----------|
+//! | `version` | `u32`, postcard varint |
+//! | `window` | `u32`, postcard varint, [`SchnorrAir::window`](crate::SchnorrAir::window) |
+//! | `public_challenge` | `bool`, [`SchnorrAir::public_challenge`](crate::SchnorrAir::public_challenge) |
+//! | `public_values` | length-prefixed canonical `u32`s |
+//! | `proof` | postcard encoding of the proof |
+//!
+//! The version is read first, so a bundle written under an older column layout
+//! is rejected with a clear error instead of failing somewhere inside the proof.
+//! The window and the public-challenge flag fix the trace width, the
+//! preprocessed table and the public-value count, so [`SchnorrProofBundle::air`]
+//! rebuilds the AIR the proof was made for.
+
+use p3_field::PrimeField32;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::schnorr_air::{
+    SchnorrAir, MAX_SCHNORR_WINDOW, SCHNORR_PUBLIC_VALUES, SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE,
+};
+use curve::KoalaBear;
+
+/// Format version written by [`SchnorrProofBundle::new`].
+///
+/// Bump whenever the Schnorr trace layout or public-value layout changes, since
+/// old proofs no longer verify against the new AIR.
+pub const PROOF_BUNDLE_VERSION: u32 = 2;
+
+/// A proof and the public values it was generated against.
+///
+/// `P` is normally `p3_uni_stark::Proof<SC>` for the STARK configuration used
+/// to prove; the bundle only needs it to be serde-serializable.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchnorrProofBundle<P> {
+    /// The STARK proof.
+    pub proof: P,
+    /// [`schnorr_public_values`](crate::schnorr_public_values) for the witness, or
+    /// [`schnorr_public_values_with_challenge`](crate::schnorr_public_values_with_challenge)
+    /// when `public_challenge` is set.
+    pub public_values: Vec<KoalaBear>,
+    /// Bits of each scalar consumed per row by the proving AIR.
+    pub window: usize,
+    /// Whether the proving AIR takes `e` as public input.
+    pub public_challenge: bool,
+    /// Format version, [`PROOF_BUNDLE_VERSION`] for bundles built with [`SchnorrProofBundle::new`].
+    pub version: u32,
+}
+
+impl<P> SchnorrProofBundle<P> {
+    /// Bundle a proof made for `air` with its public values at the current
+    /// format version, recording the AIR's window and public-challenge mode.
+    ///
+    /// Errors if `public_values` does not have the number of elements `air` takes.
+    pub fn new(proof: P, public_values: Vec<KoalaBear>, air: &SchnorrAir) -> Result<Self, String> {
+        check_public_values_len(public_values.len(), air.public_challenge)?;
+        Ok(Self {
+            proof,
+            public_values,
+            window: air.window,
+            public_challenge: air.public_challenge,
+            version: PROOF_BUNDLE_VERSION,
+        })
+    }
+
+    /// The AIR to verify the proof against, for a trace of `num_rows` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`SchnorrAir::with_window`].
+    pub fn air(&self, num_rows: usize) -> SchnorrAir {
+        let air = SchnorrAir::with_window(num_rows, self.window);
+        if self.public_challenge {
+            air.with_public_challenge()
+        } else {
+            air
+        }
+    }
+}
+
+impl<P: Serialize> SchnorrProofBundle<P> {
+    /// Serialize as `version || window || public_challenge || public_values || proof`
+    /// (see the module docs).
+    pub fn to_bytes(&self) -> Result<Vec<u8>, String> {
+        let public_values: Vec<u32> = self
+            .public_values
+            .iter()
+            .map(|v| v.as_canonical_u32())
+            .collect();
+        postcard::to_allocvec(&(
+            self.version,
+            self.window as u32,
+            self.public_challenge,
+            public_values,
+            &self.proof,
+        ))
+        .map_err(|err| format!("failed to serialize proof bundle: {err}"))
+    }
+}
+
+impl<P: DeserializeOwned> SchnorrProofBundle<P> {
+    /// Parse a bundle produced by [`SchnorrProofBundle::to_bytes`].
+    ///
+    /// Rejects unknown versions, windows outside `1..=MAX_SCHNORR_WINDOW`,
+    /// public-value vectors that are not exactly `SCHNORR_PUBLIC_VALUES` (or
+    /// `SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE` with the public-challenge flag)
+    /// canonical KoalaBear elements, and trailing bytes.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, String> {
+        let (version, rest): (u32, _) = postcard::take_from_bytes(bytes)
+            .map_err(|err| format!("failed to read proof bundle version: {err}"))?;
+        if version != PROOF_BUNDLE_VERSION {
+            return Err(format!(
+                "unsupported proof bundle version {version}, expected {PROOF_BUNDLE_VERSION}"
+            ));
+        }
+
+        let (window, rest): (u32, _) = postcard::take_from_bytes(rest)
+            .map_err(|err| format!("failed to read window: {err}"))?;
+        let window = window as usize;
+        if !(1..=MAX_SCHNORR_WINDOW).contains(&window) {
+            return Err(format!(
+                "window {window} is not between 1 and {MAX_SCHNORR_WINDOW}"
+            ));
+        }
+        let (public_challenge, rest): (bool, _) = postcard::take_from_bytes(rest)
+            .map_err(|err| format!("failed to read public-challenge flag: {err}"))?;
+
+        let (raw_values, rest): (Vec<u32>, _) = postcard::take_from_bytes(rest)
+            .map_err(|err| format!("failed to read public values: {err}"))?;
+        check_public_values_len(raw_values.len(), public_challenge)?;
+        let public_values = raw_values
+            .into_iter()
+            .map(|v| {
+                (v < KoalaBear::ORDER_U32)
+                    .then(|| KoalaBear::new(v))
+                    .ok_or_else(|| format!("public value {v} is not a canonical KoalaBear element"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let (proof, rest) = postcard::take_from_bytes(rest)
+            .map_err(|err| format!("failed to read proof: {err}"))?;
+        if !rest.is_empty() {
+            return Err(format!("{} trailing bytes after proof bundle", rest.len()));
+        }
+
+        Ok(Self {
+            proof,
+            public_values,
+            window,
+            public_challenge,
+            version,
+        })
+    }
+}
+
+fn check_public_values_len(len: usize, public_challenge: bool) -> Result<(), String> {
+    let expected = if public_challenge {
+        SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE
+    } else {
+        SCHNORR_PUBLIC_VALUES
+    };
+    if len != expected {
+        return Err(format!("expected {expected} public values, got {len}"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{test_config, TestConfig};
+    use crate::{
+        build_schnorr_trace, build_schnorr_trace_with_window, schnorr_public_values,
+        schnorr_public_values_with_challenge, SchnorrAir, SignatureWitness,
+    };
+    use p3_baby_bear::BabyBear;
+    use p3_field::PrimeCharacteristicRing;
+    use p3_matrix::dense::RowMajorMatrix;
+    use p3_matrix::Matrix;
+    use p3_uni_stark::{
+        prove_with_preprocessed, setup_preprocessed, verify_with_preprocessed, Proof,
+    };
+    use p3_util::log2_strict_usize;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use schnorr::SigningKey;
+
+    fn public_values() -> Vec<KoalaBear> {
+        (0..SCHNORR_PUBLIC_VALUES as u32)
+            .map(KoalaBear::new)
+            .collect()
+    }
+
+    fn witness() -> SignatureWitness {
+        let mut rng = StdRng::seed_from_u64(38);
+        let signing_key = SigningKey::random(&mut rng);
+        let message = vec![BabyBear::from_u32(1), BabyBear::from_u32(2)];
+        let signature = signing_key.sign(&mut rng, &message).unwrap();
+        SignatureWitness::new(&signature, &signing_key.verifying_key(), &message).unwrap()
+    }
+
+    /// Prove `trace` against `air`, round-trip the bundle through bytes and
+    /// verify the parsed proof against the AIR the bundle rebuilds.
+    fn assert_bundle_roundtrip(
+        air: &SchnorrAir,
+        trace: RowMajorMatrix<KoalaBear>,
+        public_values: Vec<KoalaBear>,
+    ) {
+        let config = test_config();
+        let (prover_data, vk) =
+            setup_preprocessed::<TestConfig, _>(&config, air, log2_strict_usize(air.num_rows))
+                .expect("preprocessed");
+        let proof =
+            prove_with_preprocessed(&config, air, trace, &public_values, Some(&prover_data));
+
+        let bundle = SchnorrProofBundle::new(proof, public_values, air).unwrap();
+        let bytes = bundle.to_bytes().unwrap();
+        let parsed = SchnorrProofBundle::<Proof<TestConfig>>::from_bytes(&bytes).unwrap();
+        assert_eq!(parsed.version, PROOF_BUNDLE_VERSION);
+        assert_eq!(parsed.window, air.window);
+        assert_eq!(parsed.public_challenge, air.public_challenge);
+        assert_eq!(parsed.public_values, bundle.public_values);
+        assert_eq!(parsed.to_bytes().unwrap(), bytes);
+        verify_with_preprocessed(
+            &config,
+            &parsed.air(air.num_rows),
+            &parsed.proof,
+            &parsed.public_values,
+            Some(&vk),
+        )
+        .expect("parsed proof verifies");
+    }
+
+    #[test]
+    fn test_bundle_roundtrip() {
+        let witness = witness();
+        let trace = build_schnorr_trace(&witness).trace;
+        let air = SchnorrAir::new(trace.height());
+        assert_bundle_roundtrip(&air, trace, schnorr_public_values(&witness));
+    }
+
+    #[test]
+    fn test_bundle_roundtrip_windowed() {
+        let witness = witness();
+        let trace = build_schnorr_trace_with_window(&witness, 3).trace;
+        let air = SchnorrAir::with_window(trace.height(), 3);
+        assert_bundle_roundtrip(&air, trace, schnorr_public_values(&witness));
+    }
+
+    #[test]
+    fn test_bundle_roundtrip_public_challenge() {
+        let witness = witness();
+        let trace = build_schnorr_trace(&witness).trace;
+        let air = SchnorrAir::new(trace.height()).with_public_challenge();
+        let public_values = schnorr_public_values_with_challenge(&witness);
+
+        // The 41-value layout is rejected for a public-challenge AIR
+        let short = SchnorrProofBundle::new(vec![1u8], schnorr_public_values(&witness), &air);
+        assert!(short.is_err());
+
+        assert_bundle_roundtrip(&air, trace, public_values);
+    }
+
+    #[test]
+    fn test_bundle_rejects_wrong_public_values_len() {
+        let proof = vec![1u8, 2, 3];
+        let air = SchnorrAir::new(256);
+        assert!(
+            SchnorrProofBundle::new(proof.clone(), public_values()[1..].to_vec(), &air).is_err()
+        );
+
+        for (public_challenge, len) in [
+            (false, SCHNORR_PUBLIC_VALUES - 1),
+            (false, SCHNORR_PUBLIC_VALUES + 1),
+            (false, SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE),
+            (true, SCHNORR_PUBLIC_VALUES),
+            (true, SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE + 1),
+        ] {
+            let bundle = SchnorrProofBundle {
+                proof: proof.clone(),
+                public_values: (0..len as u32).map(KoalaBear::new).collect(),
+                window: 1,
+                public_challenge,
+                version: PROOF_BUNDLE_VERSION,
+            };
+            let bytes = bundle.to_bytes().unwrap();
+            assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&bytes).is_err());
+        }
+    }
+
+    #[test]
+    fn test_bundle_rejects_malformed_bytes() {
+        let air = SchnorrAir::new(256);
+        let bundle = SchnorrProofBundle::new(vec![1u8, 2, 3], public_values(), &air).unwrap();
+        let bytes = bundle.to_bytes().unwrap();
+        assert_eq!(
+            SchnorrProofBundle::<Vec<u8>>::from_bytes(&bytes).unwrap(),
+            bundle
+        );
+
+        assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+
+        let mut trailing = bytes.clone();
+        trailing.push(0);
+        assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&trailing).is_err());
+
+        let mut future = bundle.clone();
+        future.version = PROOF_BUNDLE_VERSION + 1;
+        let future_bytes = future.to_bytes().unwrap();
+        assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&future_bytes).is_err());
+
+        let mut old = bundle.clone();
+        old.version = 1;
+        let old_bytes = old.to_bytes().unwrap();
+        assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&old_bytes).is_err());
+
+        for window in [0, MAX_SCHNORR_WINDOW + 1] {
+            let mut bad_window = bundle.clone();
+            bad_window.window = window;
+            let bad_bytes = bad_window.to_bytes().unwrap();
+            assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&bad_bytes).is_err());
+        }
+
+        let raw = postcard::to_allocvec(&(
+            PROOF_BUNDLE_VERSION,
+            1u32,
+            false,
+            vec![KoalaBear::ORDER_U32; SCHNORR_PUBLIC_VALUES],
+            vec![1u8],
+        ))
+        .unwrap();
+        assert!(SchnorrProofBundle::<Vec<u8>>::from_bytes(&raw).is_err());
+    }
+}
@@ -5,7 +5,9 @@
 //! elements as multiple KoalaBear field elements.
 
 use curve::{KoalaBear, ScalarField};
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, BaseAir, BaseAirWithPublicValues};
 use p3_field::{PrimeCharacteristicRing, PrimeField32};
+use p3_matrix::{dense::RowMajorMatrix, Matrix};
 
 /// Number of KoalaBear limbs needed to represent a scalar field element.
 /// The scalar field is ~252 bits, KoalaBear is 31 bits, so we need 9 limbs.
@@ -14,6 +16,13 @@ pub const SCALAR_LIMBS: usize = 9;
 /// Number of bits per limb (using 28 bits for easier arithmetic)
 pub const LIMB_BITS: u32 = 28;
 
+/// Number of scalar bits covered by the limbs.
+pub const SCALAR_BITS: usize = SCALAR_LIMBS * LIMB_BITS as usize;
+
+pub const SR_LIMB_START: usize = 0;
+pub const SR_BIT_START: usize = SR_LIMB_START + SCALAR_LIMBS;
+pub const SCALAR_RANGE_COLUMNS: usize = SR_BIT_START + SCALAR_BITS;
+
 /// Scalar field element represented as KoalaBear limbs for circuit operations.
 ///
 /// This representation breaks down a 252-bit scalar into 9 limbs of 28 bits each.
@@ -27,67 +36,206 @@ pub struct CircuitScalar {
 impl CircuitScalar {
     /// Create a scalar from a ScalarField element
     pub fn from_scalar_field(scalar: ScalarField) -> Self {
-        let canonical = scalar.to_canonical_u64_vec();
-        let mut limbs = [KoalaBear::ZERO; SCALAR_LIMBS];
+        let words = scalar.to_canonical_u64_vec();
+        let limbs = core::array::from_fn(|j| {
+            let bit = j * LIMB_BITS as usize;
+            let (word, shift) = (bit / 64, bit % 64);
+            // Limbs 2, 4 and 6 straddle a word boundary; their top bits come from
+            // the low end of the next word.
+            let mut value = words[word] >> shift;
+            if shift + LIMB_BITS as usize > 64 {
+                value |= words[word + 1] << (64 - shift);
+            }
+            KoalaBear::from_u32((value & ((1 << LIMB_BITS) - 1)) as u32)
+        });
 
-        // Convert 4 u64 limbs to 9 28-bit limbs
-        // Process bit by bit to avoid overflow
-        let mut bit_position = 0u32;
+        Self { limbs }
+    }
 
-        for limb in limbs.iter_mut().take(SCALAR_LIMBS) {
-            if bit_position >= 256 {
-                break;
+    /// Convert back to ScalarField
+    ///
+    /// The limbs must encode a value below the scalar field order, as those of
+    /// [`CircuitScalar::from_scalar_field`] do; debug builds panic otherwise
+    /// instead of silently reducing.
+    pub fn to_scalar_field(&self) -> ScalarField {
+        let mut words = [0u64; 4];
+
+        for (j, limb) in self.limbs.iter().enumerate() {
+            let value = limb.as_canonical_u32() as u64;
+            let bit = j * LIMB_BITS as usize;
+            let (word, shift) = (bit / 64, bit % 64);
+            words[word] |= value << shift;
+            if shift + LIMB_BITS as usize > 64 {
+                words[word + 1] |= value >> (64 - shift);
             }
+        }
 
-            let word_idx = (bit_position / 64) as usize;
-            let bit_offset = bit_position % 64;
-
-            if word_idx < 4 {
-                // Extract 28 bits starting at bit_position
-                let mut limb_value =
-                    (canonical[word_idx] >> bit_offset) & ((1u64 << LIMB_BITS) - 1);
+        debug_assert!(
+            ScalarField::try_from_canonical_limbs(words).is_some(),
+            "scalar limbs encode a value of at least the scalar field order"
+        );
+        ScalarField::from_canonical_limbs(words)
+    }
+}
 
-                // If we need more bits from the next word
-                if bit_offset + LIMB_BITS > 64 && word_idx + 1 < 4 {
-                    let bits_from_next = bit_offset + LIMB_BITS - 64;
-                    let next_bits = canonical[word_idx + 1] & ((1u64 << bits_from_next) - 1);
-                    limb_value |= next_bits << (LIMB_BITS - bits_from_next);
-                }
+/// Range-check AIR for [`CircuitScalar`] limbs.
+///
+/// Each row holds the nine limbs and the 252 little-endian bits of the scalar,
+/// in the same order as [`scalar_to_bits`](crate::scalar_to_bits). The bits are
+/// boolean and each limb equals the weighted sum of its 28 bits, so every limb
+/// lies in `[0, 2^28)`. The first row is bound to the public limbs.
+#[derive(Clone, Debug, Default)]
+pub struct ScalarRangeAir;
+
+impl BaseAir<KoalaBear> for ScalarRangeAir {
+    fn width(&self) -> usize {
+        SCALAR_RANGE_COLUMNS
+    }
+}
 
-                *limb = KoalaBear::from_u32(limb_value as u32);
-            }
+impl BaseAirWithPublicValues<KoalaBear> for ScalarRangeAir {
+    fn num_public_values(&self) -> usize {
+        SCALAR_LIMBS
+    }
+}
 
-            bit_position += LIMB_BITS;
+impl<AB> Air<AB> for ScalarRangeAir
+where
+    AB: AirBuilder<F = KoalaBear> + AirBuilderWithPublicValues,
+{
+    fn eval(&self, builder: &mut AB) {
+        let main = builder.main();
+        let local = main.row_slice(0).expect("scalar range trace is empty");
+        let row = (*local).as_ref();
+        let public = builder.public_values().to_vec();
+
+        eval_scalar_range(builder, row, 0);
+
+        let mut first = builder.when_first_row();
+        for (j, limb) in public.iter().enumerate() {
+            first.assert_eq(row[SR_LIMB_START + j].clone(), *limb);
         }
+    }
+}
 
-        Self { limbs }
+/// Constrain the limbs at `offset` to recombine from boolean bit columns.
+pub(crate) fn eval_scalar_range<AB: AirBuilder<F = KoalaBear>>(
+    builder: &mut AB,
+    row: &[AB::Var],
+    offset: usize,
+) {
+    for j in 0..SCALAR_LIMBS {
+        let mut recombined = AB::Expr::ZERO;
+        for k in 0..LIMB_BITS as usize {
+            let bit = row[offset + SR_BIT_START + j * LIMB_BITS as usize + k].clone();
+            builder.assert_bool(bit.clone());
+            recombined += AB::Expr::from(KoalaBear::from_u32(1 << k)) * bit;
+        }
+        builder.assert_eq(row[offset + SR_LIMB_START + j].clone(), recombined);
     }
+}
 
-    /// Convert back to ScalarField
-    pub fn to_scalar_field(&self) -> ScalarField {
-        let mut result = [0u64; 4];
+/// Build a [`ScalarRangeAir`] trace for `scalar`, padded with a zero row.
+///
+/// Only the low 28 bits of each limb are decomposed, so an out-of-range limb
+/// yields a trace that violates the recombination constraint.
+pub fn build_scalar_range_trace(scalar: &CircuitScalar) -> RowMajorMatrix<KoalaBear> {
+    let mut trace = vec![KoalaBear::ZERO; 2 * SCALAR_RANGE_COLUMNS];
+    for (j, limb) in scalar.limbs.iter().enumerate() {
+        let value = limb.as_canonical_u32();
+        trace[SR_LIMB_START + j] = *limb;
+        for k in 0..LIMB_BITS as usize {
+            trace[SR_BIT_START + j * LIMB_BITS as usize + k] =
+                KoalaBear::from_u32((value >> k) & 1);
+        }
+    }
+    RowMajorMatrix::new(trace, SCALAR_RANGE_COLUMNS)
+}
 
-        // Accumulate bits limb by limb
-        let mut bit_position = 0u32;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::scalar_to_bits;
+    use crate::test_utils::{check_constraints, test_config};
+    use curve::RandomField;
+    use p3_uni_stark::{prove, verify};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    fn prove_and_verify(scalar: &CircuitScalar) -> Result<(), String> {
+        let trace = build_scalar_range_trace(scalar);
+        let public_values = scalar.limbs.to_vec();
+        check_constraints(&ScalarRangeAir, &trace, None, &public_values)?;
+
+        let config = test_config();
+        let proof = prove(&config, &ScalarRangeAir, trace, &public_values);
+        verify(&config, &ScalarRangeAir, &proof, &public_values).map_err(|e| format!("{e:?}"))
+    }
 
-        for limb in &self.limbs {
-            let limb_value = limb.as_canonical_u32() as u64;
-            let word_idx = (bit_position / 64) as usize;
-            let bit_offset = bit_position % 64;
+    #[test]
+    fn test_scalar_limbs_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(81);
+        let edge_cases = [ScalarField::ZERO, ScalarField::ONE, -ScalarField::ONE];
+        let powers = (0..(-ScalarField::ONE).num_bits()).map(|k| {
+            let mut words = [0u64; 4];
+            words[k / 64] = 1 << (k % 64);
+            ScalarField::from_canonical_limbs(words)
+        });
+        let random = (0..1000).map(|_| ScalarField::random(&mut rng));
+
+        for scalar in edge_cases.into_iter().chain(powers).chain(random) {
+            let limbs = CircuitScalar::from_scalar_field(scalar);
+            assert!(limbs
+                .limbs
+                .iter()
+                .all(|l| l.as_canonical_u32() < 1 << LIMB_BITS));
+            assert_eq!(limbs.to_scalar_field(), scalar);
+
+            let bits = scalar_to_bits(&scalar);
+            for (j, limb) in limbs.limbs.iter().enumerate() {
+                let expected = (0..LIMB_BITS as usize)
+                    .filter(|&b| bits[j * LIMB_BITS as usize + b])
+                    .fold(0u32, |acc, b| acc | 1 << b);
+                assert_eq!(limb.as_canonical_u32(), expected);
+            }
+        }
+    }
 
-            if word_idx < 4 {
-                result[word_idx] |= limb_value << bit_offset;
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_to_scalar_field_catches_out_of_range_limbs() {
+        // 2^252 - 1 exceeds the 248-bit scalar field order
+        let max = CircuitScalar {
+            limbs: [KoalaBear::from_u32((1 << LIMB_BITS) - 1); SCALAR_LIMBS],
+        };
+        assert!(std::panic::catch_unwind(|| max.to_scalar_field()).is_err());
+
+        let top = CircuitScalar::from_scalar_field(-ScalarField::ONE);
+        assert_eq!(top.to_scalar_field(), -ScalarField::ONE);
+    }
 
-                // Handle overflow to next word
-                if bit_offset + LIMB_BITS > 64 && word_idx + 1 < 4 {
-                    let overflow_bits = bit_offset + LIMB_BITS - 64;
-                    result[word_idx + 1] |= limb_value >> (LIMB_BITS - overflow_bits);
-                }
-            }
+    #[test]
+    fn test_range_trace_matches_scalar_bits() {
+        let scalar = -ScalarField::from_canonical_u64(0x1234_5678);
+        let trace = build_scalar_range_trace(&CircuitScalar::from_scalar_field(scalar));
+        let row = trace.row_slice(0).unwrap();
+        let bits: Vec<bool> = row[SR_BIT_START..]
+            .iter()
+            .map(|b| *b == KoalaBear::ONE)
+            .collect();
+        assert_eq!(bits[..], scalar_to_bits(&scalar)[..SCALAR_BITS]);
+    }
 
-            bit_position += LIMB_BITS;
-        }
+    #[test]
+    fn test_range_check_accepts_valid_limbs() {
+        let scalar = CircuitScalar::from_scalar_field(-ScalarField::ONE);
+        assert_eq!(prove_and_verify(&scalar), Ok(()));
+    }
 
-        ScalarField::from_canonical_limbs(result)
+    #[test]
+    fn test_range_check_rejects_oversized_limb() {
+        let mut scalar = CircuitScalar::from_scalar_field(ScalarField::from_canonical_u64(5));
+        scalar.limbs[0] = KoalaBear::from_u32((1 << LIMB_BITS) + 5);
+        assert!(prove_and_verify(&scalar).is_err());
     }
 }
@@ -4,7 +4,11 @@ use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, BaseAir, BaseAirWithPu
 use p3_field::{Field, PrimeCharacteristicRing};
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
 
-use crate::{scalar_to_bits, CircuitPoint, SignatureWitness};
+use crate::fp8_gadgets::{
+    assert_fp8_eq, enforce_on_curve, fp8_a, fp8_add, fp8_mul, fp8_mul_scalar, fp8_one, fp8_sub,
+    read_fp8,
+};
+use crate::{scalar_to_bits, scalar_to_bits_trimmed, CircuitPoint, SignatureWitness};
 use curve::{BaseField, KoalaBear};
 
 pub const COORD_LIMBS: usize = 8;
@@ -31,6 +35,24 @@ pub const NUM_COLUMNS: usize = ACC_INF_COL + 1;
 pub const PUBLIC_BASE_LIMBS: usize = COORD_LIMBS * 2;
 pub const PUBLIC_OUT_LIMBS: usize = COORD_LIMBS * 2;
 
+// Windowed layout: fixed columns, then a one-hot digit selector of `2^w`
+// columns, then the table entries `2B..=2^w B` (x, y, inv, slope each).
+pub const WIN_ACC_X_START: usize = 0;
+pub const WIN_ACC_Y_START: usize = WIN_ACC_X_START + COORD_LIMBS;
+pub const WIN_ACC_INF_COL: usize = WIN_ACC_Y_START + COORD_LIMBS;
+pub const WIN_BASE_X_START: usize = WIN_ACC_INF_COL + 1;
+pub const WIN_BASE_Y_START: usize = WIN_BASE_X_START + COORD_LIMBS;
+pub const WIN_ADDEND_X_START: usize = WIN_BASE_Y_START + COORD_LIMBS;
+pub const WIN_ADDEND_Y_START: usize = WIN_ADDEND_X_START + COORD_LIMBS;
+pub const WIN_ADD_X_START: usize = WIN_ADDEND_Y_START + COORD_LIMBS;
+pub const WIN_ADD_Y_START: usize = WIN_ADD_X_START + COORD_LIMBS;
+pub const WIN_ADD_INV_START: usize = WIN_ADD_Y_START + COORD_LIMBS;
+pub const WIN_ADD_SLOPE_START: usize = WIN_ADD_INV_START + COORD_LIMBS;
+pub const WIN_ADD_ACTIVE_COL: usize = WIN_ADD_SLOPE_START + COORD_LIMBS;
+pub const WIN_SEL_START: usize = WIN_ADD_ACTIVE_COL + 1;
+const WIN_ENTRY_COLS: usize = COORD_LIMBS * 4;
+pub const MAX_WINDOW_BITS: usize = 8;
+
 #[derive(Clone, Debug)]
 pub struct ScalarMulTrace {
     pub trace: RowMajorMatrix<KoalaBear>,
@@ -164,10 +186,16 @@ pub(crate) fn eval_scalar_mul_core<AB: AirBuilder<F = KoalaBear>>(
     );
 }
 
+/// Double-and-add trace for `scalar_bits` (little-endian) times `base`.
+///
+/// The last row's accumulator is the result, so when the final bit is set
+/// (as with [`scalar_to_bits_trimmed`](crate::scalar_to_bits_trimmed)) one
+/// padding row is added after it before rounding up to a power of two.
 pub fn build_scalar_mul_trace(base: &CircuitPoint, scalar_bits: &[bool]) -> ScalarMulTrace {
     let mut acc = CircuitPoint::infinity();
     let mut current = base.clone();
-    let num_rows = scalar_bits.len().next_power_of_two();
+    let num_rows = (scalar_bits.len() + scalar_bits.last().copied().unwrap_or(false) as usize)
+        .next_power_of_two();
     let mut trace = Vec::with_capacity(num_rows * NUM_COLUMNS);
 
     for row_idx in 0..num_rows {
@@ -211,32 +239,7 @@ pub fn build_scalar_mul_trace(base: &CircuitPoint, scalar_bits: &[bool]) -> Scal
 
         let base_x = coeffs_to_base(current.x);
         let base_y = coeffs_to_base(current.y);
-        let double_num = {
-            let three = KoalaBear::new(3);
-            let a = coeffs_to_base([
-                KoalaBear::ZERO,
-                three,
-                KoalaBear::ZERO,
-                KoalaBear::ZERO,
-                KoalaBear::ZERO,
-                KoalaBear::ZERO,
-                KoalaBear::ZERO,
-                KoalaBear::ZERO,
-            ]);
-            let base_x2 = base_x * base_x;
-            base_x2
-                * coeffs_to_base([
-                    three,
-                    KoalaBear::ZERO,
-                    KoalaBear::ZERO,
-                    KoalaBear::ZERO,
-                    KoalaBear::ZERO,
-                    KoalaBear::ZERO,
-                    KoalaBear::ZERO,
-                    KoalaBear::ZERO,
-                ])
-                + a
-        };
+        let double_num = base_x * base_x * KoalaBear::new(3) + curve::CURVE_A();
         let double_den = base_y + base_y;
         let double_inv = double_den.inverse();
         let double_slope = double_num * double_inv;
@@ -260,12 +263,284 @@ pub fn build_scalar_mul_trace(base: &CircuitPoint, scalar_bits: &[bool]) -> Scal
     }
 }
 
+/// Number of main columns of a [`WindowedScalarMulAir`] with `window_bits`-bit windows.
+pub fn windowed_num_columns(window_bits: usize) -> usize {
+    let table_len = 1 << window_bits;
+    WIN_SEL_START + table_len + (table_len - 1) * WIN_ENTRY_COLS
+}
+
+/// Start of the point `k * B` in a windowed row (`k = 1` is the base itself).
+fn win_point_start(window_bits: usize, k: usize) -> usize {
+    if k == 1 {
+        WIN_BASE_X_START
+    } else {
+        WIN_SEL_START + (1 << window_bits) + (k - 2) * WIN_ENTRY_COLS
+    }
+}
+
+/// Scalar multiplication AIR consuming `window_bits` scalar bits per row.
+///
+/// Each row holds the current base `B = 2^(w·i) · base` and the table
+/// `B, 2B, ..., 2^w B`, built from one doubling and `2^w - 2` additions. A
+/// one-hot selector picks the digit `d` of the row and `d·B` is added into the
+/// accumulator; the last table entry becomes the base of the next row. The
+/// trace is `window_bits` times shorter than [`ScalarMulAir`] at the cost of
+/// a wider row. Public values are the same: base then output.
+#[derive(Clone, Debug)]
+pub struct WindowedScalarMulAir {
+    pub num_rows: usize,
+    pub window_bits: usize,
+}
+
+impl WindowedScalarMulAir {
+    pub fn new(num_rows: usize, window_bits: usize) -> Self {
+        assert!(num_rows.is_power_of_two(), "num_rows must be power of 2");
+        assert!(
+            (1..=MAX_WINDOW_BITS).contains(&window_bits),
+            "window_bits must be in 1..=MAX_WINDOW_BITS"
+        );
+        Self {
+            num_rows,
+            window_bits,
+        }
+    }
+}
+
+impl BaseAir<KoalaBear> for WindowedScalarMulAir {
+    fn width(&self) -> usize {
+        windowed_num_columns(self.window_bits)
+    }
+}
+
+impl BaseAirWithPublicValues<KoalaBear> for WindowedScalarMulAir {
+    fn num_public_values(&self) -> usize {
+        PUBLIC_BASE_LIMBS + PUBLIC_OUT_LIMBS
+    }
+}
+
+impl<AB> Air<AB> for WindowedScalarMulAir
+where
+    AB: AirBuilder<F = KoalaBear> + AirBuilderWithPublicValues,
+{
+    fn eval(&self, builder: &mut AB) {
+        let w = self.window_bits;
+        let table_len = 1 << w;
+        let main = builder.main();
+        let local = main
+            .row_slice(0)
+            .expect("windowed scalar mul trace is empty");
+        let row = (*local).as_ref();
+        let next_row = main
+            .row_slice(1)
+            .expect("windowed scalar mul trace row missing");
+        let next_row = (*next_row).as_ref();
+        let public = builder.public_values().to_vec();
+        let (base_public, out_public) = public.split_at(PUBLIC_BASE_LIMBS);
+
+        let mut first = builder.when_first_row();
+        first.assert_one(row[WIN_ACC_INF_COL].clone());
+        for i in 0..2 * COORD_LIMBS {
+            first.assert_eq(row[WIN_BASE_X_START + i].clone(), base_public[i]);
+            first.assert_zero(row[WIN_ACC_X_START + i].clone());
+        }
+        enforce_on_curve(builder, row, WIN_BASE_X_START, WIN_BASE_Y_START);
+
+        // One-hot digit selector.
+        let mut sel_sum = AB::Expr::ZERO;
+        for d in 0..table_len {
+            builder.assert_bool(row[WIN_SEL_START + d].clone());
+            sel_sum += row[WIN_SEL_START + d].clone();
+        }
+        builder.assert_one(sel_sum);
+        let sel_zero: AB::Expr = row[WIN_SEL_START].clone().into();
+
+        // Table: 2B by doubling, (k + 1)B = kB + B.
+        let start = win_point_start(w, 2);
+        enforce_tangent(
+            builder,
+            row,
+            WIN_BASE_X_START,
+            start + 2 * COORD_LIMBS,
+            start + 3 * COORD_LIMBS,
+            start,
+        );
+        for k in 3..=table_len {
+            let start = win_point_start(w, k);
+            enforce_chord(
+                builder,
+                row,
+                win_point_start(w, k - 1),
+                WIN_BASE_X_START,
+                start + 2 * COORD_LIMBS,
+                start + 3 * COORD_LIMBS,
+                start,
+            );
+        }
+
+        // Addend is the selected table entry (zero for digit 0).
+        for i in 0..2 * COORD_LIMBS {
+            let mut selected = AB::Expr::ZERO;
+            for d in 1..table_len {
+                let sel: AB::Expr = row[WIN_SEL_START + d].clone().into();
+                selected += sel * row[win_point_start(w, d) + i].clone();
+            }
+            builder.assert_eq(row[WIN_ADDEND_X_START + i].clone(), selected);
+        }
+
+        let acc_inf: AB::Expr = row[WIN_ACC_INF_COL].clone().into();
+        builder.assert_bool(acc_inf.clone());
+        builder.assert_eq(
+            row[WIN_ADD_ACTIVE_COL].clone(),
+            (AB::Expr::ONE - acc_inf.clone()) * (AB::Expr::ONE - sel_zero.clone()),
+        );
+        let mut add_builder = builder.when(row[WIN_ADD_ACTIVE_COL].clone());
+        enforce_chord(
+            &mut add_builder,
+            row,
+            WIN_ACC_X_START,
+            WIN_ADDEND_X_START,
+            WIN_ADD_INV_START,
+            WIN_ADD_SLOPE_START,
+            WIN_ADD_X_START,
+        );
+        let mut inf_builder = builder.when(acc_inf.clone());
+        for i in 0..2 * COORD_LIMBS {
+            inf_builder.assert_eq(
+                row[WIN_ADD_X_START + i].clone(),
+                row[WIN_ADDEND_X_START + i].clone(),
+            );
+        }
+
+        // Accumulator after this row's digit.
+        let stepped: Vec<AB::Expr> = (0..2 * COORD_LIMBS)
+            .map(|i| {
+                let acc: AB::Expr = row[WIN_ACC_X_START + i].clone().into();
+                let add: AB::Expr = row[WIN_ADD_X_START + i].clone().into();
+                acc.clone() + (AB::Expr::ONE - sel_zero.clone()) * (add - acc)
+            })
+            .collect();
+
+        let mut next = builder.when_transition();
+        for i in 0..2 * COORD_LIMBS {
+            next.assert_eq(next_row[WIN_ACC_X_START + i].clone(), stepped[i].clone());
+            next.assert_eq(
+                next_row[WIN_BASE_X_START + i].clone(),
+                row[win_point_start(w, table_len) + i].clone(),
+            );
+        }
+        next.assert_eq(next_row[WIN_ACC_INF_COL].clone(), acc_inf * sel_zero);
+
+        let mut last = builder.when_last_row();
+        for i in 0..2 * COORD_LIMBS {
+            last.assert_eq(stepped[i].clone(), out_public[i]);
+        }
+    }
+}
+
+/// Build a [`WindowedScalarMulAir`] trace for `scalar_bits` (little-endian).
+///
+/// The trace has `ceil(len / window_bits)` rows rounded up to a power of two;
+/// the final accumulator is `scalar · base`.
+pub fn build_scalar_mul_trace_windowed(
+    base: &CircuitPoint,
+    scalar_bits: &[bool],
+    window_bits: usize,
+) -> ScalarMulTrace {
+    assert!(
+        (1..=MAX_WINDOW_BITS).contains(&window_bits),
+        "window_bits must be in 1..=MAX_WINDOW_BITS"
+    );
+    let table_len = 1 << window_bits;
+    let width = windowed_num_columns(window_bits);
+    let num_rows = scalar_bits
+        .len()
+        .div_ceil(window_bits)
+        .max(1)
+        .next_power_of_two();
+    let mut trace = Vec::with_capacity(num_rows * width);
+
+    let mut acc = CircuitPoint::infinity();
+    let mut current = base.clone();
+
+    for row_idx in 0..num_rows {
+        let mut row = vec![KoalaBear::ZERO; width];
+        let digit = scalar_bits
+            .iter()
+            .skip(row_idx * window_bits)
+            .take(window_bits)
+            .rev()
+            .fold(0usize, |d, &bit| (d << 1) | bit as usize);
+
+        write_point(&mut row, WIN_ACC_X_START, &acc);
+        row[WIN_ACC_INF_COL] = KoalaBear::from_u32(acc.is_infinity as u32);
+        write_point(&mut row, WIN_BASE_X_START, &current);
+
+        // table[d - 1] = d * current
+        let mut table = Vec::with_capacity(table_len);
+        table.push(current.clone());
+        for k in 2..=table_len {
+            let start = win_point_start(window_bits, k);
+            let (entry, inv, slope) = if k == 2 {
+                let (inv, slope) = tangent_witness(&current);
+                (current.double(), inv, slope)
+            } else {
+                let prev = &table[k - 2];
+                let (inv, slope) = chord_witness(prev, &current);
+                (prev.add(&current), inv, slope)
+            };
+            write_point(&mut row, start, &entry);
+            write_base(&mut row, start + 2 * COORD_LIMBS, inv);
+            write_base(&mut row, start + 3 * COORD_LIMBS, slope);
+            table.push(entry);
+        }
+
+        row[WIN_SEL_START + digit] = KoalaBear::ONE;
+        let next_acc = if digit == 0 {
+            acc
+        } else {
+            let addend = &table[digit - 1];
+            let sum = acc.add(addend);
+            write_point(&mut row, WIN_ADDEND_X_START, addend);
+            write_point(&mut row, WIN_ADD_X_START, &sum);
+            if !acc.is_infinity {
+                let (inv, slope) = chord_witness(&acc, addend);
+                write_base(&mut row, WIN_ADD_INV_START, inv);
+                write_base(&mut row, WIN_ADD_SLOPE_START, slope);
+                row[WIN_ADD_ACTIVE_COL] = KoalaBear::ONE;
+            }
+            sum
+        };
+
+        trace.extend_from_slice(&row);
+        acc = next_acc;
+        current = table[table_len - 1].clone();
+    }
+
+    ScalarMulTrace {
+        trace: RowMajorMatrix::new(trace, width),
+    }
+}
+
 pub fn build_generator_mul_trace(witness: &SignatureWitness) -> ScalarMulTrace {
-    let s_bits = scalar_to_bits(&witness.s.to_scalar_field());
+    let s_bits = scalar_to_bits_trimmed(&witness.s.to_scalar_field());
     let generator = CircuitPoint::from_affine(&curve::Projective::generator().to_affine());
     build_scalar_mul_trace(&generator, &s_bits)
 }
 
+/// Denominator inverse and slope of the chord through `p` and `q`.
+fn chord_witness(p: &CircuitPoint, q: &CircuitPoint) -> (BaseField, BaseField) {
+    let inv = (q.x_as_basefield() - p.x_as_basefield()).inverse();
+    (inv, (q.y_as_basefield() - p.y_as_basefield()) * inv)
+}
+
+/// Denominator inverse and slope of the tangent at `p`.
+fn tangent_witness(p: &CircuitPoint) -> (BaseField, BaseField) {
+    let x = p.x_as_basefield();
+    let y = p.y_as_basefield();
+    let inv = (y + y).inverse();
+    (inv, (x * x * KoalaBear::new(3) + curve::CURVE_A()) * inv)
+}
+
 fn write_point(row: &mut [KoalaBear], start: usize, point: &CircuitPoint) {
     for i in 0..COORD_LIMBS {
         row[start + i] = point.x[i];
@@ -274,8 +549,7 @@ fn write_point(row: &mut [KoalaBear], start: usize, point: &CircuitPoint) {
 }
 
 fn write_base(row: &mut [KoalaBear], start: usize, value: BaseField) {
-    let coeffs: [KoalaBear; COORD_LIMBS] = unsafe { core::mem::transmute(value) };
-    row[start..start + COORD_LIMBS].copy_from_slice(&coeffs[..COORD_LIMBS]);
+    row[start..start + COORD_LIMBS].copy_from_slice(&curve::to_coeffs(value));
 }
 
 pub(crate) fn enforce_add_constraints<AB: AirBuilder<F = KoalaBear>>(
@@ -360,102 +634,170 @@ pub(crate) fn enforce_double_constraints<AB: AirBuilder<F = KoalaBear>>(
     assert_fp8_eq(builder, &double_y, &y3);
 }
 
-pub(crate) fn read_fp8<AB: AirBuilder<F = KoalaBear>>(
+/// Constrain `out = p + q` via the chord with witnessed `inv = 1 / (q.x - p.x)` and `slope`.
+///
+/// Points are laid out as `x` followed by `y` at their start column.
+fn enforce_chord<AB: AirBuilder<F = KoalaBear>>(
+    builder: &mut AB,
     row: &[AB::Var],
-    start: usize,
-) -> [AB::Expr; COORD_LIMBS] {
-    core::array::from_fn(|i| row[start + i].clone().into())
+    p: usize,
+    q: usize,
+    inv: usize,
+    slope: usize,
+    out: usize,
+) {
+    let px = read_fp8::<AB>(row, p);
+    let py = read_fp8::<AB>(row, p + COORD_LIMBS);
+    let qx = read_fp8::<AB>(row, q);
+    let qy = read_fp8::<AB>(row, q + COORD_LIMBS);
+    let inv = read_fp8::<AB>(row, inv);
+    let slope = read_fp8::<AB>(row, slope);
+    let out_x = read_fp8::<AB>(row, out);
+    let out_y = read_fp8::<AB>(row, out + COORD_LIMBS);
+
+    let den = fp8_sub::<AB>(&qx, &px);
+    assert_fp8_eq(builder, &fp8_mul::<AB>(&den, &inv), &fp8_one::<AB>());
+    assert_fp8_eq(
+        builder,
+        &slope,
+        &fp8_mul::<AB>(&fp8_sub::<AB>(&qy, &py), &inv),
+    );
+
+    let x3 = fp8_sub::<AB>(&fp8_sub::<AB>(&fp8_mul::<AB>(&slope, &slope), &px), &qx);
+    let y3 = fp8_sub::<AB>(&fp8_mul::<AB>(&slope, &fp8_sub::<AB>(&px, &out_x)), &py);
+    assert_fp8_eq(builder, &out_x, &x3);
+    assert_fp8_eq(builder, &out_y, &y3);
 }
 
-pub(crate) fn assert_fp8_eq<AB: AirBuilder<F = KoalaBear>>(
+/// Constrain `out = 2p` via the tangent with witnessed `inv = 1 / (2 p.y)` and `slope`.
+fn enforce_tangent<AB: AirBuilder<F = KoalaBear>>(
     builder: &mut AB,
-    lhs: &[AB::Expr; COORD_LIMBS],
-    rhs: &[AB::Expr; COORD_LIMBS],
+    row: &[AB::Var],
+    p: usize,
+    inv: usize,
+    slope: usize,
+    out: usize,
 ) {
-    for i in 0..COORD_LIMBS {
-        builder.assert_eq(lhs[i].clone(), rhs[i].clone());
-    }
-}
+    let px = read_fp8::<AB>(row, p);
+    let py = read_fp8::<AB>(row, p + COORD_LIMBS);
+    let inv = read_fp8::<AB>(row, inv);
+    let slope = read_fp8::<AB>(row, slope);
+    let out_x = read_fp8::<AB>(row, out);
+    let out_y = read_fp8::<AB>(row, out + COORD_LIMBS);
+
+    let den = fp8_mul_scalar::<AB>(&py, KoalaBear::from_u32(2));
+    assert_fp8_eq(builder, &fp8_mul::<AB>(&den, &inv), &fp8_one::<AB>());
+    let x2 = fp8_mul::<AB>(&px, &px);
+    let num = fp8_add::<AB>(
+        &fp8_mul_scalar::<AB>(&x2, KoalaBear::from_u32(3)),
+        &fp8_a::<AB>(),
+    );
+    assert_fp8_eq(builder, &slope, &fp8_mul::<AB>(&num, &inv));
 
-pub(crate) fn fp8_one<AB: AirBuilder<F = KoalaBear>>() -> [AB::Expr; COORD_LIMBS] {
-    let mut out = [AB::Expr::ZERO; COORD_LIMBS];
-    out[0] = AB::Expr::ONE;
-    out
+    let x3 = fp8_sub::<AB>(
+        &fp8_mul::<AB>(&slope, &slope),
+        &fp8_mul_scalar::<AB>(&px, KoalaBear::from_u32(2)),
+    );
+    let y3 = fp8_sub::<AB>(&fp8_mul::<AB>(&slope, &fp8_sub::<AB>(&px, &out_x)), &py);
+    assert_fp8_eq(builder, &out_x, &x3);
+    assert_fp8_eq(builder, &out_y, &y3);
 }
 
-pub(crate) fn fp8_a<AB: AirBuilder<F = KoalaBear>>() -> [AB::Expr; COORD_LIMBS] {
-    let mut out = [AB::Expr::ZERO; COORD_LIMBS];
-    out[1] = AB::Expr::from(KoalaBear::from_u32(3));
-    out
+fn coeffs_to_base(coeffs: [KoalaBear; COORD_LIMBS]) -> BaseField {
+    curve::from_coeffs(coeffs)
 }
 
-pub(crate) fn fp8_add<AB: AirBuilder<F = KoalaBear>>(
-    a: &[AB::Expr; COORD_LIMBS],
-    b: &[AB::Expr; COORD_LIMBS],
-) -> [AB::Expr; COORD_LIMBS] {
-    core::array::from_fn(|i| a[i].clone() + b[i].clone())
-}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::test_config;
+    use curve::{Affine, Group, ScalarField};
+    use p3_uni_stark::{prove, verify};
 
-pub(crate) fn fp8_sub<AB: AirBuilder<F = KoalaBear>>(
-    a: &[AB::Expr; COORD_LIMBS],
-    b: &[AB::Expr; COORD_LIMBS],
-) -> [AB::Expr; COORD_LIMBS] {
-    core::array::from_fn(|i| a[i].clone() - b[i].clone())
-}
+    fn encode_public(base: &CircuitPoint, out: &CircuitPoint) -> Vec<KoalaBear> {
+        [base.x, base.y, out.x, out.y].concat()
+    }
 
-pub(crate) fn fp8_mul_scalar<AB: AirBuilder<F = KoalaBear>>(
-    a: &[AB::Expr; COORD_LIMBS],
-    scalar: KoalaBear,
-) -> [AB::Expr; COORD_LIMBS] {
-    core::array::from_fn(|i| a[i].clone() * scalar)
-}
+    #[test]
+    fn test_curve_constants_match_curve_crate() {
+        // `fp8_a` and `fp8_b` are the coefficients of these exported constants
+        let g = CircuitPoint::from_affine(&Affine::generator());
+        let (x, y) = (g.x_as_basefield(), g.y_as_basefield());
+        assert_eq!(y * y, x * x * x + curve::CURVE_A() * x + curve::CURVE_B());
+    }
 
-pub(crate) fn fp8_mul<AB: AirBuilder<F = KoalaBear>>(
-    a: &[AB::Expr; COORD_LIMBS],
-    b: &[AB::Expr; COORD_LIMBS],
-) -> [AB::Expr; COORD_LIMBS] {
-    let mut t = vec![AB::Expr::ZERO; 2 * COORD_LIMBS - 1];
-    for (i, a_i) in a.iter().enumerate().take(COORD_LIMBS) {
-        for (j, b_j) in b.iter().enumerate().take(COORD_LIMBS) {
-            let idx = i + j;
-            t[idx] = t[idx].clone() + a_i.clone() * b_j.clone();
+    #[test]
+    fn test_windowed_trace_matches_scalar_mul() {
+        let scalar = ScalarField::from_canonical_u64(0x1234_5678_9abc_def1);
+        let base = Affine::generator().scalar_mul(&ScalarField::from_canonical_u64(7));
+        let expected = CircuitPoint::from_affine(&base.scalar_mul(&scalar));
+        let base = CircuitPoint::from_affine(&base);
+        let bits = scalar_to_bits(&scalar);
+
+        for window_bits in [1, 3, 4] {
+            let trace = build_scalar_mul_trace_windowed(&base, &bits, window_bits).trace;
+            assert_eq!(
+                trace.height(),
+                bits.len().div_ceil(window_bits).next_power_of_two()
+            );
+
+            let last = trace.row_slice(trace.height() - 1).unwrap();
+            let last = (*last).as_ref();
+            let sel_zero = last[WIN_SEL_START] == KoalaBear::ONE;
+            let out_start = if sel_zero {
+                WIN_ACC_X_START
+            } else {
+                WIN_ADD_X_START
+            };
+            assert_eq!(&last[out_start..out_start + COORD_LIMBS], &expected.x);
+            assert_eq!(
+                &last[out_start + COORD_LIMBS..out_start + 2 * COORD_LIMBS],
+                &expected.y
+            );
         }
     }
 
-    let w = KoalaBear::from_u32(3);
-    let mut out = [AB::Expr::ZERO; COORD_LIMBS];
-    for k in 0..COORD_LIMBS {
-        let mut acc = t[k].clone();
-        if k + COORD_LIMBS < t.len() {
-            acc += t[k + COORD_LIMBS].clone() * w;
+    #[test]
+    fn test_trimmed_bits_keep_accumulator() {
+        let base = Affine::generator().scalar_mul(&ScalarField::from_canonical_u64(5));
+        let base = CircuitPoint::from_affine(&base);
+
+        // 32 bits exercises the extra padding row after a set top bit
+        for value in [0xdead_beef_u64, 0x8000_0000, 0x1234] {
+            let scalar = ScalarField::from_canonical_u64(value);
+            let full = build_scalar_mul_trace(&base, &scalar_to_bits(&scalar)).trace;
+            let trimmed = build_scalar_mul_trace(&base, &scalar_to_bits_trimmed(&scalar)).trace;
+            assert!(trimmed.height() < full.height());
+
+            let full_last = full.row_slice(full.height() - 1).unwrap();
+            let full_last = (*full_last).as_ref();
+            let trimmed_last = trimmed.row_slice(trimmed.height() - 1).unwrap();
+            let trimmed_last = (*trimmed_last).as_ref();
+            let acc = ACC_X_START..ACC_Y_START + COORD_LIMBS;
+            assert_eq!(&full_last[acc.clone()], &trimmed_last[acc]);
+
+            let air = ScalarMulAir::new(trimmed.height());
+            let expected = CircuitPoint::from_affine(&base.to_affine().scalar_mul(&scalar));
+            let public_values = encode_public(&base, &expected);
+            let config = test_config();
+            let proof = prove(&config, &air, trimmed, &public_values);
+            verify(&config, &air, &proof, &public_values).expect("trimmed proof verifies");
         }
-        out[k] = acc;
     }
-    out
-}
 
-pub(crate) fn enforce_on_curve<AB: AirBuilder<F = KoalaBear>>(
-    builder: &mut AB,
-    row: &[AB::Var],
-    x_start: usize,
-    y_start: usize,
-) {
-    let x = read_fp8::<AB>(row, x_start);
-    let y = read_fp8::<AB>(row, y_start);
-    let y2 = fp8_mul::<AB>(&y, &y);
-    let x2 = fp8_mul::<AB>(&x, &x);
-    let x3 = fp8_mul::<AB>(&x2, &x);
-    let ax = fp8_mul::<AB>(&x, &fp8_a::<AB>());
-    let rhs = fp8_add::<AB>(&fp8_add::<AB>(&x3, &ax), &fp8_b::<AB>());
-    assert_fp8_eq(builder, &y2, &rhs);
-}
+    #[test]
+    fn test_windowed_prove_verify() {
+        let scalar = ScalarField::from_canonical_u64(0xdead_beef_cafe);
+        let base = CircuitPoint::from_affine(&Affine::generator());
+        let expected = CircuitPoint::from_affine(&Affine::generator().scalar_mul(&scalar));
+        let bits = scalar_to_bits(&scalar);
 
-pub(crate) fn fp8_b<AB: AirBuilder<F = KoalaBear>>() -> [AB::Expr; COORD_LIMBS] {
-    let mut out = [AB::Expr::ZERO; COORD_LIMBS];
-    out[0] = AB::Expr::from(KoalaBear::from_u32(42639));
-    out
-}
+        let trace = build_scalar_mul_trace_windowed(&base, &bits, 4).trace;
+        let air = WindowedScalarMulAir::new(trace.height(), 4);
+        let public_values = encode_public(&base, &expected);
 
-fn coeffs_to_base(coeffs: [KoalaBear; COORD_LIMBS]) -> BaseField {
-    unsafe { core::mem::transmute(coeffs) }
+        let config = test_config();
+        let proof = prove(&config, &air, trace, &public_values);
+        verify(&config, &air, &proof, &public_values).expect("windowed proof verifies");
+    }
 }
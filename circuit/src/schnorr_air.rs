@@ -1,54 +1,255 @@
 //! Schnorr verification AIR using two scalar-mul traces and a final add.
+//!
+//! # Public values
+//!
+//! | Range | Contents |
+//! |-------|----------|
+//! | `0..16` | `pk.x`, `pk.y` coefficients |
+//! | `16..32` | `R.x`, `R.y` coefficients |
+//! | `32..41` | `-e mod n` as nine 28-bit limbs (see [`CircuitScalar`]) |
+//! | `41..50` | `e` as nine 28-bit limbs, only with [`SchnorrAir::with_public_challenge`] |
+//!
+//! The challenge bits driving the `pk` multiplication are accumulated into limb
+//! columns and bound to the `-e` public limbs, so the proof is only valid for
+//! one challenge. The challenge itself is proven in a companion
+//! [`Poseidon2HashAir`](crate::Poseidon2HashAir) proof over BabyBear, whose
+//! public values expose `R || pk || len(msg) || msg` and the digest. The two AIRs live over
+//! different fields and cannot share a trace, so the verifier links them with
+//! [`check_challenge_binding`], which recomputes `-e` from the hash digest.
+//!
+//! Protocols that fix `e` externally, such as a recursive verifier whose
+//! challenge comes from a parent transcript, can skip the hash proof and use
+//! [`SchnorrAir::with_public_challenge`]: the verifier then supplies `e` itself
+//! as the trailing limbs, and the AIR checks `e + (-e) = n`, which pins the
+//! challenge bits of the trace to it.
+
+use core::ops::Range;
 
 use p3_air::{
     Air, AirBuilder, AirBuilderWithPublicValues, BaseAir, BaseAirWithPublicValues, PairBuilder,
 };
-use p3_field::{Field, PrimeCharacteristicRing};
+use p3_baby_bear::BabyBear;
+use p3_field::{batch_multiplicative_inverse, Field, PrimeCharacteristicRing, PrimeField32};
 use p3_matrix::{dense::RowMajorMatrix, Matrix};
 
-use crate::scalar_mul_air::{
+use crate::fp8_gadgets::{
     assert_fp8_eq, enforce_on_curve, fp8_a, fp8_add, fp8_mul, fp8_mul_scalar, fp8_one, fp8_sub,
-    read_fp8, COORD_LIMBS,
+    read_fp8,
 };
-use crate::{scalar_to_bits, CircuitPoint, SignatureWitness};
-use curve::{BaseField, KoalaBear};
+use crate::poseidon2_hash_air::{POSEIDON2_DIGEST_LEN, POSEIDON2_INPUT_LEN};
+use crate::scalar_mul_air::COORD_LIMBS;
+use crate::{
+    build_poseidon2_hash_trace, scalar_to_bits, CircuitPoint, CircuitScalar, Poseidon2HashTrace,
+    SignatureWitness, LIMB_BITS, SCALAR_LIMBS,
+};
+use curve::{from_coeffs, Affine, BaseField, KoalaBear, ScalarField};
 
 pub const SCHNORR_BASE_PUBLIC: usize = COORD_LIMBS * 2; // pk
 pub const SCHNORR_R_PUBLIC: usize = COORD_LIMBS * 2; // R
-pub const SCHNORR_PUBLIC_VALUES: usize = SCHNORR_BASE_PUBLIC + SCHNORR_R_PUBLIC;
+pub const SCHNORR_NEG_E_PUBLIC: usize = SCALAR_LIMBS; // -e limbs
+pub const SCHNORR_PUBLIC_VALUES: usize =
+    SCHNORR_BASE_PUBLIC + SCHNORR_R_PUBLIC + SCHNORR_NEG_E_PUBLIC;
+/// `e` limbs appended in public-challenge mode.
+pub const SCHNORR_E_PUBLIC: usize = SCALAR_LIMBS;
+/// Public values of [`SchnorrAir::with_public_challenge`]: `pk || R || -e || e`.
+pub const SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE: usize = SCHNORR_PUBLIC_VALUES + SCHNORR_E_PUBLIC;
+/// Number of challenge bits covered by the `-e` limbs; higher bits must be zero.
+pub const SCHNORR_E_BITS: usize = SCALAR_LIMBS * LIMB_BITS as usize;
 
 pub const GS_OFFSET: usize = 0;
+// Each step adds `s_bit * G` (G from the preprocessed table) and then
+// `e_bit * pk` into the accumulator, and doubles pk for the next step. A row
+// holds `window` steps side by side, followed by the `-e` limb columns.
 pub const DS_ACC_X_START: usize = 0;
 pub const DS_ACC_Y_START: usize = DS_ACC_X_START + COORD_LIMBS;
 pub const DS_PK_X_START: usize = DS_ACC_Y_START + COORD_LIMBS;
 pub const DS_PK_Y_START: usize = DS_PK_X_START + COORD_LIMBS;
 pub const DS_PK_DOUBLE_X_START: usize = DS_PK_Y_START + COORD_LIMBS;
 pub const DS_PK_DOUBLE_Y_START: usize = DS_PK_DOUBLE_X_START + COORD_LIMBS;
-pub const DS_PK_DOUBLE_NUM_START: usize = DS_PK_DOUBLE_Y_START + COORD_LIMBS;
-pub const DS_PK_DOUBLE_DEN_START: usize = DS_PK_DOUBLE_NUM_START + COORD_LIMBS;
-pub const DS_PK_DOUBLE_INV_START: usize = DS_PK_DOUBLE_DEN_START + COORD_LIMBS;
+pub const DS_PK_DOUBLE_INV_START: usize = DS_PK_DOUBLE_Y_START + COORD_LIMBS;
 pub const DS_PK_DOUBLE_SLOPE_START: usize = DS_PK_DOUBLE_INV_START + COORD_LIMBS;
-pub const DS_SUM_X_START: usize = DS_PK_DOUBLE_SLOPE_START + COORD_LIMBS;
-pub const DS_SUM_Y_START: usize = DS_SUM_X_START + COORD_LIMBS;
-pub const DS_SUM_NUM_START: usize = DS_SUM_Y_START + COORD_LIMBS;
-pub const DS_SUM_DEN_START: usize = DS_SUM_NUM_START + COORD_LIMBS;
-pub const DS_SUM_INV_START: usize = DS_SUM_DEN_START + COORD_LIMBS;
-pub const DS_SUM_SLOPE_START: usize = DS_SUM_INV_START + COORD_LIMBS;
-pub const DS_ADDEND_X_START: usize = DS_SUM_SLOPE_START + COORD_LIMBS;
-pub const DS_ADDEND_Y_START: usize = DS_ADDEND_X_START + COORD_LIMBS;
-pub const DS_ADD_X_START: usize = DS_ADDEND_Y_START + COORD_LIMBS;
+pub const DS_G_ADD_X_START: usize = DS_PK_DOUBLE_SLOPE_START + COORD_LIMBS;
+pub const DS_G_ADD_Y_START: usize = DS_G_ADD_X_START + COORD_LIMBS;
+pub const DS_G_ADD_INV_START: usize = DS_G_ADD_Y_START + COORD_LIMBS;
+pub const DS_G_ADD_SLOPE_START: usize = DS_G_ADD_INV_START + COORD_LIMBS;
+pub const DS_MID_X_START: usize = DS_G_ADD_SLOPE_START + COORD_LIMBS;
+pub const DS_MID_Y_START: usize = DS_MID_X_START + COORD_LIMBS;
+pub const DS_ADD_X_START: usize = DS_MID_Y_START + COORD_LIMBS;
 pub const DS_ADD_Y_START: usize = DS_ADD_X_START + COORD_LIMBS;
-pub const DS_ADD_NUM_START: usize = DS_ADD_Y_START + COORD_LIMBS;
-pub const DS_ADD_DEN_START: usize = DS_ADD_NUM_START + COORD_LIMBS;
-pub const DS_ADD_INV_START: usize = DS_ADD_DEN_START + COORD_LIMBS;
+pub const DS_ADD_INV_START: usize = DS_ADD_Y_START + COORD_LIMBS;
 pub const DS_ADD_SLOPE_START: usize = DS_ADD_INV_START + COORD_LIMBS;
 pub const DS_S_BIT_COL: usize = DS_ADD_SLOPE_START + COORD_LIMBS;
 pub const DS_E_BIT_COL: usize = DS_S_BIT_COL + 1;
 pub const DS_ACC_INF_COL: usize = DS_E_BIT_COL + 1;
-pub const SCHNORR_COLUMNS: usize = DS_ACC_INF_COL + 1;
+pub const DS_MID_INF_COL: usize = DS_ACC_INF_COL + 1;
+pub const DS_G_ADD_ACTIVE_COL: usize = DS_MID_INF_COL + 1;
+pub const DS_ADD_ACTIVE_COL: usize = DS_G_ADD_ACTIVE_COL + 1;
+/// Columns of one step; step `k` of a row starts at `k * DS_STEP_COLUMNS`.
+pub const DS_STEP_COLUMNS: usize = DS_ADD_ACTIVE_COL + 1;
+/// Start of the `-e` limb columns at window 1, see [`schnorr_columns`].
+pub const DS_E_LIMB_START: usize = DS_STEP_COLUMNS;
+/// Main trace width at window 1.
+pub const SCHNORR_COLUMNS: usize = schnorr_columns(1);
+// Preprocessed columns of one step: the generator multiple `2^i * G` added by
+// step `i`, and the weight of challenge bit `i` in its `-e` limb.
 pub const GS_PREP_BASE_X_START: usize = 0;
 pub const GS_PREP_BASE_Y_START: usize = GS_PREP_BASE_X_START + COORD_LIMBS;
-pub const GS_PREP_COLS: usize = GS_PREP_BASE_Y_START + COORD_LIMBS;
+pub const GS_PREP_E_WEIGHT_START: usize = GS_PREP_BASE_Y_START + COORD_LIMBS;
+pub const GS_PREP_E_ACTIVE_COL: usize = GS_PREP_E_WEIGHT_START + SCALAR_LIMBS;
+/// Preprocessed columns of one step; the table is `gs_prep_columns(window)` wide.
+pub const GS_PREP_COLS: usize = GS_PREP_E_ACTIVE_COL + 1;
+/// Largest window [`SchnorrAir::with_window`] accepts.
+///
+/// The last row must only hold the always-zero bits at or above 248 (the
+/// scalar field has 248 bits), since `R` is read from its accumulator before
+/// any of its steps.
+pub const MAX_SCHNORR_WINDOW: usize = 8;
+
+/// Main trace width when each row holds `window` steps.
+pub const fn schnorr_columns(window: usize) -> usize {
+    window * DS_STEP_COLUMNS + SCALAR_LIMBS
+}
+
+/// Preprocessed trace width when each row holds `window` steps.
+pub const fn gs_prep_columns(window: usize) -> usize {
+    window * GS_PREP_COLS
+}
+
+/// Named column ranges of the main trace at window 1.
+///
+/// Each range is the half-open span of its columns in a row of
+/// [`build_schnorr_trace`]; single-column flags are ranges of length one. At a
+/// larger window, step `k` of a row uses the same per-step ranges shifted by
+/// `k * DS_STEP_COLUMNS`, and the `-e` limbs start at `window * DS_STEP_COLUMNS`.
+/// Returned by [`column_layout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ColumnLayout {
+    /// Accumulator `x` before this step.
+    pub acc_x: Range<usize>,
+    /// Accumulator `y` before this step.
+    pub acc_y: Range<usize>,
+    /// `2^i * pk` added by step `i`, `x` coordinate.
+    pub pk_x: Range<usize>,
+    /// `2^i * pk` added by step `i`, `y` coordinate.
+    pub pk_y: Range<usize>,
+    /// `2^(i+1) * pk`, `x` coordinate.
+    pub pk_double_x: Range<usize>,
+    /// `2^(i+1) * pk`, `y` coordinate.
+    pub pk_double_y: Range<usize>,
+    /// Inverse of the doubling denominator `2 * pk.y`.
+    pub pk_double_inv: Range<usize>,
+    /// Slope of the pk doubling.
+    pub pk_double_slope: Range<usize>,
+    /// `acc + 2^i * G`, `x` coordinate.
+    pub g_add_x: Range<usize>,
+    /// `acc + 2^i * G`, `y` coordinate.
+    pub g_add_y: Range<usize>,
+    /// Inverse of the generator addition denominator.
+    pub g_add_inv: Range<usize>,
+    /// Slope of the generator addition.
+    pub g_add_slope: Range<usize>,
+    /// Accumulator after the `s_bit * G` addition, `x` coordinate.
+    pub mid_x: Range<usize>,
+    /// Accumulator after the `s_bit * G` addition, `y` coordinate.
+    pub mid_y: Range<usize>,
+    /// `mid + 2^i * pk`, `x` coordinate.
+    pub add_x: Range<usize>,
+    /// `mid + 2^i * pk`, `y` coordinate.
+    pub add_y: Range<usize>,
+    /// Inverse of the pk addition denominator.
+    pub add_inv: Range<usize>,
+    /// Slope of the pk addition.
+    pub add_slope: Range<usize>,
+    /// Bit of `s` consumed by this step.
+    pub s_bit: Range<usize>,
+    /// Bit of `-e` consumed by this step.
+    pub e_bit: Range<usize>,
+    /// Whether the accumulator is the point at infinity.
+    pub acc_inf: Range<usize>,
+    /// Whether `mid` is the point at infinity.
+    pub mid_inf: Range<usize>,
+    /// Whether the generator addition is a generic (non-infinity) addition.
+    pub g_add_active: Range<usize>,
+    /// Whether the pk addition is a generic (non-infinity) addition.
+    pub add_active: Range<usize>,
+    /// The nine `-e` limb accumulators, after the step columns.
+    pub e_limbs: Range<usize>,
+}
+
+impl ColumnLayout {
+    /// All ranges in column order, with their names.
+    pub fn ranges(&self) -> [(&'static str, Range<usize>); 25] {
+        [
+            ("acc_x", self.acc_x.clone()),
+            ("acc_y", self.acc_y.clone()),
+            ("pk_x", self.pk_x.clone()),
+            ("pk_y", self.pk_y.clone()),
+            ("pk_double_x", self.pk_double_x.clone()),
+            ("pk_double_y", self.pk_double_y.clone()),
+            ("pk_double_inv", self.pk_double_inv.clone()),
+            ("pk_double_slope", self.pk_double_slope.clone()),
+            ("g_add_x", self.g_add_x.clone()),
+            ("g_add_y", self.g_add_y.clone()),
+            ("g_add_inv", self.g_add_inv.clone()),
+            ("g_add_slope", self.g_add_slope.clone()),
+            ("mid_x", self.mid_x.clone()),
+            ("mid_y", self.mid_y.clone()),
+            ("add_x", self.add_x.clone()),
+            ("add_y", self.add_y.clone()),
+            ("add_inv", self.add_inv.clone()),
+            ("add_slope", self.add_slope.clone()),
+            ("s_bit", self.s_bit.clone()),
+            ("e_bit", self.e_bit.clone()),
+            ("acc_inf", self.acc_inf.clone()),
+            ("mid_inf", self.mid_inf.clone()),
+            ("g_add_active", self.g_add_active.clone()),
+            ("add_active", self.add_active.clone()),
+            ("e_limbs", self.e_limbs.clone()),
+        ]
+    }
+
+    /// Total number of columns covered by the layout.
+    pub fn width(&self) -> usize {
+        self.ranges().iter().map(|(_, range)| range.len()).sum()
+    }
+}
+
+/// Column layout of the main trace at window 1, matching the `DS_*` offsets.
+///
+/// External trace builders and debuggers should read offsets from here rather
+/// than hardcoding them; the layout's [`width`](ColumnLayout::width) is always
+/// [`SCHNORR_COLUMNS`].
+pub fn column_layout() -> ColumnLayout {
+    let fp8 = |start: usize| start..start + COORD_LIMBS;
+    let col = |index: usize| index..index + 1;
+    ColumnLayout {
+        acc_x: fp8(DS_ACC_X_START),
+        acc_y: fp8(DS_ACC_Y_START),
+        pk_x: fp8(DS_PK_X_START),
+        pk_y: fp8(DS_PK_Y_START),
+        pk_double_x: fp8(DS_PK_DOUBLE_X_START),
+        pk_double_y: fp8(DS_PK_DOUBLE_Y_START),
+        pk_double_inv: fp8(DS_PK_DOUBLE_INV_START),
+        pk_double_slope: fp8(DS_PK_DOUBLE_SLOPE_START),
+        g_add_x: fp8(DS_G_ADD_X_START),
+        g_add_y: fp8(DS_G_ADD_Y_START),
+        g_add_inv: fp8(DS_G_ADD_INV_START),
+        g_add_slope: fp8(DS_G_ADD_SLOPE_START),
+        mid_x: fp8(DS_MID_X_START),
+        mid_y: fp8(DS_MID_Y_START),
+        add_x: fp8(DS_ADD_X_START),
+        add_y: fp8(DS_ADD_Y_START),
+        add_inv: fp8(DS_ADD_INV_START),
+        add_slope: fp8(DS_ADD_SLOPE_START),
+        s_bit: col(DS_S_BIT_COL),
+        e_bit: col(DS_E_BIT_COL),
+        acc_inf: col(DS_ACC_INF_COL),
+        mid_inf: col(DS_MID_INF_COL),
+        g_add_active: col(DS_G_ADD_ACTIVE_COL),
+        add_active: col(DS_ADD_ACTIVE_COL),
+        e_limbs: DS_E_LIMB_START..DS_E_LIMB_START + SCALAR_LIMBS,
+    }
+}
 
 #[derive(Clone, Debug)]
 pub struct SchnorrTrace {
@@ -58,28 +259,81 @@ pub struct SchnorrTrace {
 #[derive(Clone, Debug)]
 pub struct SchnorrAir {
     pub num_rows: usize,
+    /// Bits of `s` and of `-e` consumed per row.
+    pub window: usize,
+    /// Whether `e` is appended to the public values, see
+    /// [`SchnorrAir::with_public_challenge`].
+    pub public_challenge: bool,
 }
 
 impl SchnorrAir {
+    /// AIR consuming one bit of each scalar per row.
     pub fn new(num_rows: usize) -> Self {
+        Self::with_window(num_rows, 1)
+    }
+
+    /// AIR whose rows each hold `window` steps, for traces from
+    /// [`build_schnorr_trace_with_window`].
+    ///
+    /// The preprocessed table holds the `window` generator multiples
+    /// `2^(window * row) * G .. 2^(window * row + window - 1) * G` per row, so a
+    /// window of `k` cuts the height by `k` at `k` times the width.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_rows` is not a power of two, `window` is not in
+    /// `1..=MAX_SCHNORR_WINDOW`, or the `num_rows * window` steps cannot hold all
+    /// [`SCHNORR_E_BITS`] challenge bits. A shorter trace would leave the top
+    /// limb of `-e` unconstrained by the accumulator.
+    pub fn with_window(num_rows: usize, window: usize) -> Self {
         assert!(num_rows.is_power_of_two(), "num_rows must be power of 2");
-        Self { num_rows }
+        assert!(
+            (1..=MAX_SCHNORR_WINDOW).contains(&window),
+            "window must be between 1 and {MAX_SCHNORR_WINDOW}"
+        );
+        assert!(
+            num_rows * window >= SCHNORR_E_BITS,
+            "{num_rows} rows of {window} steps cannot consume the {SCHNORR_E_BITS} scalar bits"
+        );
+        Self {
+            num_rows,
+            window,
+            public_challenge: false,
+        }
+    }
+
+    /// Also take the challenge `e` as public input, after the `-e` limbs.
+    ///
+    /// The AIR constrains the two limb vectors to sum to the scalar field order
+    /// `n` with boolean carries, so a proof only verifies for the public `e`
+    /// whose negation drove the trace. The public values grow to
+    /// [`SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE`]; build them with
+    /// [`schnorr_public_values_with_challenge`]. The verifier must encode `e`
+    /// canonically (each limb below `2^28`), and `e` must be nonzero, as every
+    /// challenge from `schnorr::hash_challenge` is.
+    pub fn with_public_challenge(mut self) -> Self {
+        self.public_challenge = true;
+        self
     }
 }
 
 impl BaseAir<KoalaBear> for SchnorrAir {
     fn width(&self) -> usize {
-        SCHNORR_COLUMNS
+        schnorr_columns(self.window)
     }
 
     fn preprocessed_trace(&self) -> Option<RowMajorMatrix<KoalaBear>> {
-        Some(build_gs_preprocessed_trace(self.num_rows))
+        Some(build_gs_preprocessed_trace(self.num_rows, self.window))
     }
 }
 
 impl BaseAirWithPublicValues<KoalaBear> for SchnorrAir {
     fn num_public_values(&self) -> usize {
-        SCHNORR_PUBLIC_VALUES
+        if self.public_challenge {
+            SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE
+        } else {
+            SCHNORR_PUBLIC_VALUES
+        }
     }
 }
 
@@ -99,12 +353,48 @@ where
             .expect("schnorr preprocessed is empty");
         let preprocessed_row = (*preprocessed_row).as_ref();
 
+        let preprocessed_next = preprocessed
+            .row_slice(1)
+            .expect("schnorr preprocessed next row missing");
+        let preprocessed_next = (*preprocessed_next).as_ref();
+
         let public = builder.public_values().to_vec();
-        let (pk_public, r_public) = public.split_at(SCHNORR_BASE_PUBLIC);
+        let (pk_public, rest) = public.split_at(SCHNORR_BASE_PUBLIC);
+        let (r_public, rest) = rest.split_at(SCHNORR_R_PUBLIC);
+        let (neg_e_public, e_public) = rest.split_at(SCHNORR_NEG_E_PUBLIC);
+        if self.public_challenge {
+            eval_public_challenge(builder, neg_e_public, e_public);
+        }
 
-        eval_double_scalar_core(builder, row, next_row, preprocessed_row, GS_OFFSET);
+        // Each step hands its accumulator and doubled pk to the next step in
+        // the row, and the last step to the first step of the next row.
+        for step in 0..self.window {
+            let offset = GS_OFFSET + step * DS_STEP_COLUMNS;
+            let out = eval_double_scalar_core(
+                builder,
+                row,
+                preprocessed_row,
+                offset,
+                step * GS_PREP_COLS,
+            );
+            if step + 1 < self.window {
+                assert_step_link(builder, row, offset + DS_STEP_COLUMNS, out);
+            } else {
+                assert_step_link(&mut builder.when_transition(), next_row, GS_OFFSET, out);
+            }
+        }
+        eval_challenge_limbs(
+            builder,
+            row,
+            next_row,
+            preprocessed_row,
+            preprocessed_next,
+            neg_e_public,
+            self.window,
+        );
 
         let mut first = builder.when_first_row();
+        first.assert_one(row[DS_ACC_INF_COL].clone());
         for i in 0..COORD_LIMBS {
             first.assert_eq(row[DS_PK_X_START + i].clone(), pk_public[i]);
             first.assert_eq(row[DS_PK_Y_START + i].clone(), pk_public[i + COORD_LIMBS]);
@@ -132,29 +422,300 @@ where
     }
 }
 
+/// Constrain the public `e` limbs to be the negation of the `-e` limbs mod `n`.
+///
+/// For nonzero `e` the two integers sum to exactly `n`, so limb `j` satisfies
+/// `e_j + (-e)_j + c_j = n_j + 2^28 * c_(j+1)` with `c_0 = 0`, boolean carries
+/// and no carry out of the top limb. The carries are linear in the public
+/// values, so no witness columns are needed.
+fn eval_public_challenge<AB: AirBuilderWithPublicValues<F = KoalaBear>>(
+    builder: &mut AB,
+    neg_e_public: &[AB::PublicVar],
+    e_public: &[AB::PublicVar],
+) {
+    // `n - 1` is even, so its lowest limb takes the `+ 1` without a carry.
+    let mut order = CircuitScalar::from_scalar_field(-ScalarField::ONE).limbs;
+    order[0] += KoalaBear::ONE;
+    let inv_base = KoalaBear::from_u32(1 << LIMB_BITS).inverse();
+
+    let mut carry = AB::Expr::ZERO;
+    for j in 0..SCALAR_LIMBS {
+        let excess: AB::Expr =
+            e_public[j].clone().into() + neg_e_public[j].clone().into() + carry - order[j];
+        if j + 1 < SCALAR_LIMBS {
+            carry = excess * inv_base;
+            builder.assert_bool(carry.clone());
+        } else {
+            builder.assert_zero(excess);
+        }
+    }
+}
+
+/// Bind the challenge bit columns to the public `-e` limbs.
+///
+/// Limb `j` accumulates `e_bit * 2^(i - 28j)` over the steps `i` it covers, using
+/// preprocessed weights; challenge bits past [`SCHNORR_E_BITS`] are forced to zero.
+fn eval_challenge_limbs<AB: AirBuilder<F = KoalaBear>>(
+    builder: &mut AB,
+    row: &[AB::Var],
+    next_row: &[AB::Var],
+    preprocessed_row: &[AB::Var],
+    preprocessed_next: &[AB::Var],
+    neg_e_public: &[AB::PublicVar],
+    window: usize,
+) {
+    for step in 0..window {
+        let e_bit: AB::Expr = row[step * DS_STEP_COLUMNS + DS_E_BIT_COL].clone().into();
+        let active: AB::Expr = preprocessed_row[step * GS_PREP_COLS + GS_PREP_E_ACTIVE_COL]
+            .clone()
+            .into();
+        builder.assert_zero((AB::Expr::ONE - active) * e_bit);
+    }
+
+    let limb_start = window * DS_STEP_COLUMNS;
+    for j in 0..SCALAR_LIMBS {
+        // Weighted challenge bits of all steps in a row
+        let row_sum = |row: &[AB::Var], prep: &[AB::Var]| {
+            let mut sum = AB::Expr::ZERO;
+            for step in 0..window {
+                let e_bit: AB::Expr = row[step * DS_STEP_COLUMNS + DS_E_BIT_COL].clone().into();
+                sum += e_bit * prep[step * GS_PREP_COLS + GS_PREP_E_WEIGHT_START + j].clone();
+            }
+            sum
+        };
+
+        let limb = row[limb_start + j].clone();
+        builder
+            .when_first_row()
+            .assert_eq(limb.clone(), row_sum(row, preprocessed_row));
+
+        builder.when_transition().assert_eq(
+            next_row[limb_start + j].clone(),
+            limb.clone() + row_sum(next_row, preprocessed_next),
+        );
+
+        builder
+            .when_last_row()
+            .assert_eq(limb, neg_e_public[j].clone());
+    }
+}
+
+/// Public values for [`SchnorrAir`]: `pk || R || -e` (see the module docs).
+///
+/// Same as [`SignatureWitness::public_values`], as a `Vec`.
+pub fn schnorr_public_values(witness: &SignatureWitness) -> Vec<KoalaBear> {
+    witness.public_values().to_vec()
+}
+
+/// Public values for [`SchnorrAir::with_public_challenge`]: `pk || R || -e || e`.
+pub fn schnorr_public_values_with_challenge(witness: &SignatureWitness) -> Vec<KoalaBear> {
+    let mut public_values = witness.public_values().to_vec();
+    public_values.extend_from_slice(&witness.challenge.limbs);
+    public_values
+}
+
+/// Build the companion [`Poseidon2HashAir`](crate::Poseidon2HashAir) trace proving
+/// `e = Poseidon2(R || pk || len(msg) || msg)` for this witness.
+///
+/// The hash AIR has a fixed input length, so the message must have
+/// `POSEIDON2_INPUT_LEN - 33` elements.
+pub fn build_challenge_hash_trace(
+    witness: &SignatureWitness,
+) -> Result<Poseidon2HashTrace, String> {
+    build_poseidon2_hash_trace(&challenge_hash_input(witness))
+}
+
+/// Public values for the companion hash proof:
+/// `R || pk || len(msg) || msg || digest || num_permutations`, where `digest` is
+/// the 16-element squeezed sponge output.
+pub fn challenge_hash_public_values(witness: &SignatureWitness) -> Result<Vec<BabyBear>, String> {
+    let input = challenge_hash_input(witness);
+    let hash = build_poseidon2_hash_trace(&input)?;
+
+    let mut public_values = input;
+    public_values.extend_from_slice(&hash.digest);
+    public_values.push(BabyBear::from_usize(hash.num_permutations));
+    Ok(public_values)
+}
+
+/// Check that a [`SchnorrAir`] proof and a [`Poseidon2HashAir`](crate::Poseidon2HashAir)
+/// proof talk about the same signature.
+///
+/// Both proofs must be verified separately. This checks that the hash input
+/// starts with the `R` and `pk` of the Schnorr public values, that its length
+/// element matches the message that follows, and that the `-e` limbs equal the negated challenge reduced from the hash digest, exactly as
+/// `schnorr::hash_challenge` derives it.
+///
+/// A public `R` with an odd y-coordinate is rejected, matching
+/// `VerifyingKey::verify`: `R` is public, so its parity needs no constraint.
+///
+/// A digest that reduces to zero is rejected. `schnorr::hash_challenge` then
+/// re-hashes with a counter appended, which the hash proof does not cover; the
+/// case has probability about `2^-248`.
+pub fn check_challenge_binding(schnorr_public: &[KoalaBear], hash_public: &[BabyBear]) -> bool {
+    if schnorr_public.len() != SCHNORR_PUBLIC_VALUES
+        || hash_public.len() != POSEIDON2_INPUT_LEN + POSEIDON2_DIGEST_LEN + 1
+    {
+        return false;
+    }
+
+    let (pk_public, rest) = schnorr_public.split_at(SCHNORR_BASE_PUBLIC);
+    let (r_public, neg_e_public) = rest.split_at(SCHNORR_R_PUBLIC);
+    let r_coord = |i: usize| from_coeffs(core::array::from_fn(|j| r_public[i * COORD_LIMBS + j]));
+    if Affine::new(r_coord(0), r_coord(1)).has_odd_y() {
+        return false;
+    }
+
+    let to_babybear = |v: &KoalaBear| BabyBear::from_u32(v.as_canonical_u32());
+    let points_match = r_public
+        .iter()
+        .chain(pk_public)
+        .map(to_babybear)
+        .eq(hash_public[..SCHNORR_R_PUBLIC + SCHNORR_BASE_PUBLIC]
+            .iter()
+            .copied());
+    if !points_match {
+        return false;
+    }
+
+    let len_index = SCHNORR_R_PUBLIC + SCHNORR_BASE_PUBLIC;
+    if hash_public[len_index] != BabyBear::from_usize(POSEIDON2_INPUT_LEN - (len_index + 1)) {
+        return false;
+    }
+
+    let digest = &hash_public[POSEIDON2_INPUT_LEN..POSEIDON2_INPUT_LEN + POSEIDON2_DIGEST_LEN];
+    let e = challenge_from_digest(digest);
+    if e.is_zero() {
+        return false;
+    }
+    let neg_e = CircuitScalar::from_scalar_field(-e);
+    neg_e.limbs[..] == *neg_e_public
+}
+
+fn challenge_hash_input(witness: &SignatureWitness) -> Vec<BabyBear> {
+    let to_babybear = |v: &KoalaBear| BabyBear::from_u32(v.as_canonical_u32());
+    let mut input = Vec::with_capacity(2 * SCHNORR_R_PUBLIC + 1 + witness.message.len());
+    input.extend(witness.r.x.iter().chain(&witness.r.y).map(to_babybear));
+    input.extend(
+        witness
+            .public_key
+            .x
+            .iter()
+            .chain(&witness.public_key.y)
+            .map(to_babybear),
+    );
+    input.push(BabyBear::from_u32(witness.message.len() as u32));
+    input.extend_from_slice(&witness.message);
+    input
+}
+
+/// Read the digest as a little-endian base-`p_BabyBear` integer reduced mod the
+/// scalar field order, with the packing `schnorr::hash_challenge` uses.
+fn challenge_from_digest(digest: &[BabyBear]) -> ScalarField {
+    schnorr::pack_digest_to_scalar(digest)
+}
+
+/// Build the [`SchnorrAir`] trace at the smallest power-of-two height that
+/// covers every bit of `s` and `-e` and all [`SCHNORR_E_BITS`] challenge bits.
 pub fn build_schnorr_trace(witness: &SignatureWitness) -> SchnorrTrace {
     let s_bits = scalar_to_bits(&witness.s.to_scalar_field());
-    let neg_e = -witness.challenge.to_scalar_field();
-    let neg_e_bits = scalar_to_bits(&neg_e);
-    let trace = build_double_scalar_trace(&s_bits, &neg_e_bits, &witness.public_key);
+    let neg_e_bits = scalar_to_bits(&-witness.challenge.to_scalar_field());
+    let height = s_bits
+        .len()
+        .max(neg_e_bits.len())
+        .max(SCHNORR_E_BITS)
+        .next_power_of_two();
+    build_schnorr_trace_with_height(witness, height)
+}
+
+/// Build the [`SchnorrAir`] trace padded to `height` rows, for proving against
+/// `SchnorrAir::new(height)` at a fixed height.
+///
+/// Rows past the last scalar bit have both bits zero: the accumulator is
+/// carried unchanged while `pk` keeps doubling, so the padding satisfies the
+/// same constraints as any other row and the last row still holds `R`.
+///
+/// # Panics
+///
+/// Panics if `height` is not a power of two or is smaller than the height
+/// [`build_schnorr_trace`] would pick.
+pub fn build_schnorr_trace_with_height(witness: &SignatureWitness, height: usize) -> SchnorrTrace {
+    build_windowed_schnorr_trace(witness, height, 1)
+}
+
+/// Build the trace for [`SchnorrAir::with_window`], with `window` steps per row,
+/// at the smallest power-of-two height that covers every bit of `s` and `-e`.
+///
+/// # Panics
+///
+/// Panics if `window` is not in `1..=MAX_SCHNORR_WINDOW`.
+pub fn build_schnorr_trace_with_window(witness: &SignatureWitness, window: usize) -> SchnorrTrace {
+    assert!(
+        (1..=MAX_SCHNORR_WINDOW).contains(&window),
+        "window must be between 1 and {MAX_SCHNORR_WINDOW}"
+    );
+    let s_bits = scalar_to_bits(&witness.s.to_scalar_field());
+    let neg_e_bits = scalar_to_bits(&-witness.challenge.to_scalar_field());
+    let height = s_bits
+        .len()
+        .max(neg_e_bits.len())
+        .max(SCHNORR_E_BITS)
+        .div_ceil(window)
+        .next_power_of_two();
+    build_windowed_schnorr_trace(witness, height, window)
+}
+
+fn build_windowed_schnorr_trace(
+    witness: &SignatureWitness,
+    height: usize,
+    window: usize,
+) -> SchnorrTrace {
+    let s_bits = scalar_to_bits(&witness.s.to_scalar_field());
+    let neg_e_bits = scalar_to_bits(&-witness.challenge.to_scalar_field());
+    assert!(height.is_power_of_two(), "height must be power of 2");
+    assert!(
+        height * window >= s_bits.len().max(neg_e_bits.len()).max(SCHNORR_E_BITS),
+        "height {height} is too small for the scalar bits"
+    );
+    let steps = height * window;
+    let generator = CircuitPoint::from_affine(&curve::Projective::generator().to_affine());
+    let trace = build_double_scalar_trace(
+        &s_bits,
+        &neg_e_bits,
+        &doubling_chain(&witness.public_key, steps + 1),
+        &doubling_chain(&generator, steps),
+        height,
+        window,
+    );
 
     SchnorrTrace {
-        trace: RowMajorMatrix::new(trace, SCHNORR_COLUMNS),
+        trace: RowMajorMatrix::new(trace, schnorr_columns(window)),
     }
 }
 
-fn build_gs_preprocessed_trace(num_rows: usize) -> RowMajorMatrix<KoalaBear> {
-    let mut trace = Vec::with_capacity(num_rows * GS_PREP_COLS);
+fn build_gs_preprocessed_trace(num_rows: usize, window: usize) -> RowMajorMatrix<KoalaBear> {
+    let width = gs_prep_columns(window);
+    let mut trace = Vec::with_capacity(num_rows * width);
     let mut current = CircuitPoint::from_affine(&curve::Projective::generator().to_affine());
 
-    for _ in 0..num_rows {
-        let mut row = vec![KoalaBear::ZERO; GS_PREP_COLS];
-        write_preprocessed_point(&mut row, GS_PREP_BASE_X_START, &current);
+    for row_idx in 0..num_rows {
+        let mut row = vec![KoalaBear::ZERO; width];
+        for step in 0..window {
+            let bit_idx = row_idx * window + step;
+            let offset = step * GS_PREP_COLS;
+            write_preprocessed_point(&mut row, offset + GS_PREP_BASE_X_START, &current);
+            if bit_idx < SCHNORR_E_BITS {
+                let limb = bit_idx / LIMB_BITS as usize;
+                let shift = bit_idx % LIMB_BITS as usize;
+                row[offset + GS_PREP_E_WEIGHT_START + limb] = KoalaBear::from_u32(1 << shift);
+                row[offset + GS_PREP_E_ACTIVE_COL] = KoalaBear::ONE;
+            }
+            current = current.double();
+        }
         trace.extend_from_slice(&row);
-        current = current.double();
     }
 
-    RowMajorMatrix::new(trace, GS_PREP_COLS)
+    RowMajorMatrix::new(trace, width)
 }
 
 fn write_preprocessed_point(row: &mut [KoalaBear], start: usize, point: &CircuitPoint) {
@@ -164,81 +725,148 @@ fn write_preprocessed_point(row: &mut [KoalaBear], start: usize, point: &Circuit
     }
 }
 
+/// Steps per rayon task in [`doubling_chain`]; each task starts with one
+/// scalar multiplication, so chunks must be long enough to amortize it.
+#[cfg(feature = "parallel")]
+const DOUBLING_CHUNK: usize = 64;
+
+/// `base * 2^i` for `i` in `0..len`: the points the trace doubles through.
+///
+/// With the `parallel` feature the chain is split into chunks that each start
+/// from a scalar multiplication by `2^start` and are doubled on their own
+/// thread; the result is identical to [`doubling_chain_serial`].
+fn doubling_chain(base: &CircuitPoint, len: usize) -> Vec<CircuitPoint> {
+    #[cfg(feature = "parallel")]
+    {
+        doubling_chain_parallel(base, len)
+    }
+    #[cfg(not(feature = "parallel"))]
+    {
+        doubling_chain_serial(base, len)
+    }
+}
+
+fn doubling_chain_serial(base: &CircuitPoint, len: usize) -> Vec<CircuitPoint> {
+    core::iter::successors(Some(base.clone()), |p| Some(p.double()))
+        .take(len)
+        .collect()
+}
+
+#[cfg(feature = "parallel")]
+fn doubling_chain_parallel(base: &CircuitPoint, len: usize) -> Vec<CircuitPoint> {
+    use curve::Group;
+    use rayon::prelude::*;
+
+    let affine = base.to_affine();
+    let two = ScalarField::from_canonical_u64(2);
+    let chunks: Vec<Vec<CircuitPoint>> = (0..len.div_ceil(DOUBLING_CHUNK))
+        .into_par_iter()
+        .map(|chunk| {
+            let start = chunk * DOUBLING_CHUNK;
+            // The group has prime order, so `2^start` may be reduced mod the order.
+            let first = affine.scalar_mul(&two.pow(&[start as u64, 0, 0, 0]));
+            doubling_chain_serial(
+                &CircuitPoint::from_affine(&first),
+                DOUBLING_CHUNK.min(len - start),
+            )
+        })
+        .collect();
+    chunks.concat()
+}
+
+/// Fill the double-scalar rows from the precomputed doubling chains.
+///
+/// `pk_chain` holds `pk * 2^i` for every step plus one (the last step's
+/// doubling), `g_chain` holds `G * 2^i` for every step.
 fn build_double_scalar_trace(
     s_bits: &[bool],
     e_bits: &[bool],
-    pk: &CircuitPoint,
+    pk_chain: &[CircuitPoint],
+    g_chain: &[CircuitPoint],
+    num_rows: usize,
+    window: usize,
 ) -> Vec<KoalaBear> {
+    debug_assert_eq!(pk_chain.len(), num_rows * window + 1);
+    debug_assert_eq!(g_chain.len(), num_rows * window);
+    let width = schnorr_columns(window);
+    let limb_start = window * DS_STEP_COLUMNS;
     let mut acc = CircuitPoint::infinity();
-    let mut acc_inf = true;
-    let mut pk_current = pk.clone();
-    let mut g_current = CircuitPoint::from_affine(&curve::Projective::generator().to_affine());
-    let num_rows = s_bits.len().max(e_bits.len()).next_power_of_two();
-    let mut trace = Vec::with_capacity(num_rows * SCHNORR_COLUMNS);
+    let mut trace = Vec::with_capacity(num_rows * width);
+    let mut e_limbs = [0u32; SCALAR_LIMBS];
+    // Chord and tangent denominators are inverted in one batch after the
+    // points are laid out, instead of one Fp8 inversion per slope.
+    let mut slopes = Vec::with_capacity(3 * num_rows * window);
 
     for row_idx in 0..num_rows {
-        let mut row = vec![KoalaBear::ZERO; SCHNORR_COLUMNS];
-        let s_bit = if row_idx < s_bits.len() {
-            s_bits[row_idx]
-        } else {
-            false
-        };
-        let e_bit = if row_idx < e_bits.len() {
-            e_bits[row_idx]
-        } else {
-            false
-        };
-
-        write_point(&mut row, DS_ACC_X_START, &acc);
-        write_point(&mut row, DS_PK_X_START, &pk_current);
-
-        let pk_double = pk_current.double();
-        write_point(&mut row, DS_PK_DOUBLE_X_START, &pk_double);
-        fill_double_intermediates(&mut row, DS_PK_DOUBLE_NUM_START, &pk_current);
-
-        let sum = pk_current.add(&g_current);
-        write_point(&mut row, DS_SUM_X_START, &sum);
-        fill_add_intermediates(&mut row, DS_SUM_NUM_START, &pk_current, &g_current);
-
-        let addend = match (s_bit, e_bit) {
-            (false, false) => CircuitPoint::infinity(),
-            (true, false) => g_current.clone(),
-            (false, true) => pk_current.clone(),
-            (true, true) => sum.clone(),
-        };
-        write_point(&mut row, DS_ADDEND_X_START, &addend);
-
-        let addend_inf = addend.is_infinity;
+        let mut row = vec![KoalaBear::ZERO; width];
+        for step in 0..window {
+            let bit_idx = row_idx * window + step;
+            let offset = step * DS_STEP_COLUMNS;
+            let step_start = row_idx * width + offset;
+            let s_bit = s_bits.get(bit_idx).copied().unwrap_or(false);
+            let e_bit = e_bits.get(bit_idx).copied().unwrap_or(false);
+            let pk_current = &pk_chain[bit_idx];
+            let g_current = &g_chain[bit_idx];
+
+            write_point(&mut row, offset + DS_ACC_X_START, &acc);
+            write_point(&mut row, offset + DS_PK_X_START, pk_current);
+
+            write_point(
+                &mut row,
+                offset + DS_PK_DOUBLE_X_START,
+                &pk_chain[bit_idx + 1],
+            );
+            slopes.push(double_slope(
+                step_start + DS_PK_DOUBLE_INV_START,
+                pk_current,
+            ));
+
+            // acc + s_bit * G
+            let mid = if s_bit {
+                let sum = acc.add(g_current);
+                write_point(&mut row, offset + DS_G_ADD_X_START, &sum);
+                if !acc.is_infinity {
+                    slopes.push(add_slope(step_start + DS_G_ADD_INV_START, &acc, g_current));
+                    row[offset + DS_G_ADD_ACTIVE_COL] = KoalaBear::ONE;
+                }
+                sum
+            } else {
+                acc.clone()
+            };
+            write_point(&mut row, offset + DS_MID_X_START, &mid);
+
+            // mid + e_bit * pk
+            let next_acc = if e_bit {
+                let sum = mid.add(pk_current);
+                write_point(&mut row, offset + DS_ADD_X_START, &sum);
+                if !mid.is_infinity {
+                    slopes.push(add_slope(step_start + DS_ADD_INV_START, &mid, pk_current));
+                    row[offset + DS_ADD_ACTIVE_COL] = KoalaBear::ONE;
+                }
+                sum
+            } else {
+                mid.clone()
+            };
+
+            row[offset + DS_S_BIT_COL] = KoalaBear::from_u32(s_bit as u32);
+            row[offset + DS_E_BIT_COL] = KoalaBear::from_u32(e_bit as u32);
+            row[offset + DS_ACC_INF_COL] = KoalaBear::from_u32(acc.is_infinity as u32);
+            row[offset + DS_MID_INF_COL] = KoalaBear::from_u32(mid.is_infinity as u32);
+
+            if e_bit && bit_idx < SCHNORR_E_BITS {
+                e_limbs[bit_idx / LIMB_BITS as usize] |= 1 << (bit_idx % LIMB_BITS as usize);
+            }
 
-        if !acc_inf && !addend_inf {
-            let acc_add = acc.add(&addend);
-            write_point(&mut row, DS_ADD_X_START, &acc_add);
-            fill_add_intermediates(&mut row, DS_ADD_NUM_START, &acc, &addend);
-        } else if acc_inf && !addend_inf {
-            write_point(&mut row, DS_ADD_X_START, &addend);
-        } else {
-            write_point(&mut row, DS_ADD_X_START, &acc);
+            acc = next_acc;
         }
 
-        row[DS_S_BIT_COL] = KoalaBear::from_u32(s_bit as u32);
-        row[DS_E_BIT_COL] = KoalaBear::from_u32(e_bit as u32);
-        row[DS_ACC_INF_COL] = KoalaBear::from_u32(acc_inf as u32);
-
-        trace.extend_from_slice(&row);
-
-        if acc_inf {
-            if !addend_inf {
-                acc = addend;
-                acc_inf = false;
-            }
-        } else if !addend_inf {
-            acc = acc.add(&addend);
+        for (j, &limb) in e_limbs.iter().enumerate() {
+            row[limb_start + j] = KoalaBear::from_u32(limb);
         }
-
-        pk_current = pk_double;
-        g_current = g_current.double();
+        trace.extend_from_slice(&row);
     }
 
+    fill_slopes(&mut trace, &slopes);
     trace
 }
 
@@ -249,63 +877,42 @@ fn write_point(row: &mut [KoalaBear], start: usize, point: &CircuitPoint) {
     }
 }
 
-fn fill_add_intermediates(
-    row: &mut [KoalaBear],
+/// A slope `num / den` whose inverse and value go at `start` in the trace.
+struct PendingSlope {
     start: usize,
-    acc: &CircuitPoint,
-    base: &CircuitPoint,
-) {
-    let acc_x = coeffs_to_base(acc.x);
-    let acc_y = coeffs_to_base(acc.y);
-    let base_x = coeffs_to_base(base.x);
-    let base_y = coeffs_to_base(base.y);
+    num: BaseField,
+    den: BaseField,
+}
 
-    let add_num = base_y - acc_y;
-    let add_den = base_x - acc_x;
-    let add_inv = add_den.inverse();
-    let add_slope = add_num * add_inv;
+/// The chord slope for `acc + base`.
+fn add_slope(start: usize, acc: &CircuitPoint, base: &CircuitPoint) -> PendingSlope {
+    PendingSlope {
+        start,
+        num: base.y_as_basefield() - acc.y_as_basefield(),
+        den: base.x_as_basefield() - acc.x_as_basefield(),
+    }
+}
 
-    write_base(row, start, add_num);
-    write_base(row, start + COORD_LIMBS, add_den);
-    write_base(row, start + 2 * COORD_LIMBS, add_inv);
-    write_base(row, start + 3 * COORD_LIMBS, add_slope);
+/// The tangent slope for doubling `base`.
+fn double_slope(start: usize, base: &CircuitPoint) -> PendingSlope {
+    let base_x = base.x_as_basefield();
+    let base_y = base.y_as_basefield();
+    PendingSlope {
+        start,
+        num: base_x * base_x * KoalaBear::from_u32(3) + curve::CURVE_A(),
+        den: base_y + base_y,
+    }
 }
 
-fn fill_double_intermediates(row: &mut [KoalaBear], start: usize, base: &CircuitPoint) {
-    let base_x = coeffs_to_base(base.x);
-    let base_y = coeffs_to_base(base.y);
-    let three = KoalaBear::from_u32(3);
-    let a = coeffs_to_base([
-        KoalaBear::ZERO,
-        three,
-        KoalaBear::ZERO,
-        KoalaBear::ZERO,
-        KoalaBear::ZERO,
-        KoalaBear::ZERO,
-        KoalaBear::ZERO,
-        KoalaBear::ZERO,
-    ]);
-    let base_x2 = base_x * base_x;
-    let double_num = base_x2
-        * coeffs_to_base([
-            three,
-            KoalaBear::ZERO,
-            KoalaBear::ZERO,
-            KoalaBear::ZERO,
-            KoalaBear::ZERO,
-            KoalaBear::ZERO,
-            KoalaBear::ZERO,
-            KoalaBear::ZERO,
-        ])
-        + a;
-    let double_den = base_y + base_y;
-    let double_inv = double_den.inverse();
-    let double_slope = double_num * double_inv;
-
-    write_base(row, start, double_num);
-    write_base(row, start + COORD_LIMBS, double_den);
-    write_base(row, start + 2 * COORD_LIMBS, double_inv);
-    write_base(row, start + 3 * COORD_LIMBS, double_slope);
+/// Write the denominator inverse and slope of every pending slope, sharing
+/// one field inversion across all of them (Montgomery's trick).
+fn fill_slopes(trace: &mut [KoalaBear], slopes: &[PendingSlope]) {
+    let dens: Vec<BaseField> = slopes.iter().map(|slope| slope.den).collect();
+    let invs = batch_multiplicative_inverse(&dens);
+    for (slope, inv) in slopes.iter().zip(invs) {
+        write_base(trace, slope.start, inv);
+        write_base(trace, slope.start + COORD_LIMBS, slope.num * inv);
+    }
 }
 
 struct Fp8PointExpr<'a, E> {
@@ -313,9 +920,8 @@ struct Fp8PointExpr<'a, E> {
     y: &'a [E; COORD_LIMBS],
 }
 
+/// Witnessed denominator inverse and slope of a chord or tangent.
 struct SlopeIntermediatesExpr<'a, E> {
-    num: &'a [E; COORD_LIMBS],
-    den: &'a [E; COORD_LIMBS],
     inv: &'a [E; COORD_LIMBS],
     slope: &'a [E; COORD_LIMBS],
 }
@@ -327,12 +933,10 @@ fn enforce_add_constraints_with_base<AB: AirBuilder<F = KoalaBear>>(
     add: SlopeIntermediatesExpr<'_, AB::Expr>,
     out: Fp8PointExpr<'_, AB::Expr>,
 ) {
-    let num_expected = fp8_sub::<AB>(base.y, acc.y);
-    let den_expected = fp8_sub::<AB>(base.x, acc.x);
-    assert_fp8_eq(builder, add.num, &num_expected);
-    assert_fp8_eq(builder, add.den, &den_expected);
-    assert_fp8_eq(builder, &fp8_mul::<AB>(add.den, add.inv), &fp8_one::<AB>());
-    assert_fp8_eq(builder, add.slope, &fp8_mul::<AB>(add.num, add.inv));
+    let num = fp8_sub::<AB>(base.y, acc.y);
+    let den = fp8_sub::<AB>(base.x, acc.x);
+    assert_fp8_eq(builder, &fp8_mul::<AB>(&den, add.inv), &fp8_one::<AB>());
+    assert_fp8_eq(builder, add.slope, &fp8_mul::<AB>(&num, add.inv));
 
     let slope2 = fp8_mul::<AB>(add.slope, add.slope);
     let x3 = fp8_sub::<AB>(&fp8_sub::<AB>(&slope2, acc.x), base.x);
@@ -350,21 +954,11 @@ fn enforce_double_constraints_with_base<AB: AirBuilder<F = KoalaBear>>(
     let three = KoalaBear::from_u32(3);
     let a = fp8_a::<AB>();
     let x2 = fp8_mul::<AB>(base.x, base.x);
-    let num_expected = fp8_add::<AB>(&fp8_mul_scalar::<AB>(&x2, three), &a);
-    let den_expected = fp8_mul_scalar::<AB>(base.y, KoalaBear::from_u32(2));
+    let num = fp8_add::<AB>(&fp8_mul_scalar::<AB>(&x2, three), &a);
+    let den = fp8_mul_scalar::<AB>(base.y, KoalaBear::from_u32(2));
 
-    assert_fp8_eq(builder, double.num, &num_expected);
-    assert_fp8_eq(builder, double.den, &den_expected);
-    assert_fp8_eq(
-        builder,
-        &fp8_mul::<AB>(double.den, double.inv),
-        &fp8_one::<AB>(),
-    );
-    assert_fp8_eq(
-        builder,
-        double.slope,
-        &fp8_mul::<AB>(double.num, double.inv),
-    );
+    assert_fp8_eq(builder, &fp8_mul::<AB>(&den, double.inv), &fp8_one::<AB>());
+    assert_fp8_eq(builder, double.slope, &fp8_mul::<AB>(&num, double.inv));
 
     let slope2 = fp8_mul::<AB>(double.slope, double.slope);
     let x3 = fp8_sub::<AB>(
@@ -379,59 +973,55 @@ fn enforce_double_constraints_with_base<AB: AirBuilder<F = KoalaBear>>(
     assert_fp8_eq(builder, out.y, &y3);
 }
 
-pub(crate) fn eval_double_scalar_core<AB: AirBuilder<F = KoalaBear>>(
+/// The accumulator, doubled `pk` and accumulator infinity flag one step hands
+/// to the next.
+struct StepOutput<E> {
+    acc_x: [E; COORD_LIMBS],
+    acc_y: [E; COORD_LIMBS],
+    pk_x: [E; COORD_LIMBS],
+    pk_y: [E; COORD_LIMBS],
+    acc_inf: E,
+}
+
+/// Constrain the step at `offset`, whose generator multiple is at `prep_offset`
+/// in the preprocessed row, and return what it hands to the next step.
+fn eval_double_scalar_core<AB: AirBuilder<F = KoalaBear>>(
     builder: &mut AB,
     row: &[AB::Var],
-    next_row: &[AB::Var],
     preprocessed_row: &[AB::Var],
     offset: usize,
-) {
-    let s_bit = row[offset + DS_S_BIT_COL].clone();
-    let e_bit = row[offset + DS_E_BIT_COL].clone();
+    prep_offset: usize,
+) -> StepOutput<AB::Expr> {
+    let s_bit: AB::Expr = row[offset + DS_S_BIT_COL].clone().into();
+    let e_bit: AB::Expr = row[offset + DS_E_BIT_COL].clone().into();
+    let acc_inf: AB::Expr = row[offset + DS_ACC_INF_COL].clone().into();
+    let mid_inf: AB::Expr = row[offset + DS_MID_INF_COL].clone().into();
     builder.assert_bool(s_bit.clone());
     builder.assert_bool(e_bit.clone());
-    builder.assert_bool(row[offset + DS_ACC_INF_COL].clone());
-
-    let addend_inf_expr: AB::Expr =
-        (AB::Expr::ONE - s_bit.clone().into()) * (AB::Expr::ONE - e_bit.clone().into());
-    let add_sel = AB::Expr::ONE - addend_inf_expr.clone();
-
-    let mut next = builder.when_transition();
-    for i in 0..COORD_LIMBS {
-        let acc = row[offset + DS_ACC_X_START + i].clone();
-        let acc_add = row[offset + DS_ADD_X_START + i].clone();
-        let acc_next = next_row[offset + DS_ACC_X_START + i].clone();
-        next.assert_eq(acc_next, acc.clone() + add_sel.clone() * (acc_add - acc));
-
-        let accy = row[offset + DS_ACC_Y_START + i].clone();
-        let accy_add = row[offset + DS_ADD_Y_START + i].clone();
-        let accy_next = next_row[offset + DS_ACC_Y_START + i].clone();
-        next.assert_eq(
-            accy_next,
-            accy.clone() + add_sel.clone() * (accy_add - accy),
-        );
-
-        let pk_next = next_row[offset + DS_PK_X_START + i].clone();
-        let pk_double = row[offset + DS_PK_DOUBLE_X_START + i].clone();
-        next.assert_eq(pk_next, pk_double);
-
-        let pky_next = next_row[offset + DS_PK_Y_START + i].clone();
-        let pky_double = row[offset + DS_PK_DOUBLE_Y_START + i].clone();
-        next.assert_eq(pky_next, pky_double);
-    }
-
-    let acc_inf = row[offset + DS_ACC_INF_COL].clone();
-    let next_acc_inf = next_row[offset + DS_ACC_INF_COL].clone();
-    next.assert_bool(next_acc_inf.clone());
-    let acc_inf_expr: AB::Expr = acc_inf.clone().into();
-    next.assert_eq(next_acc_inf.clone(), acc_inf_expr.clone() * addend_inf_expr);
+    builder.assert_bool(acc_inf.clone());
+    builder.assert_eq(
+        mid_inf.clone(),
+        acc_inf.clone() * (AB::Expr::ONE - s_bit.clone()),
+    );
+    builder.assert_eq(
+        row[offset + DS_G_ADD_ACTIVE_COL].clone(),
+        (AB::Expr::ONE - acc_inf.clone()) * s_bit.clone(),
+    );
+    builder.assert_eq(
+        row[offset + DS_ADD_ACTIVE_COL].clone(),
+        (AB::Expr::ONE - mid_inf.clone()) * e_bit.clone(),
+    );
 
-    let g_x = read_fp8::<AB>(preprocessed_row, GS_PREP_BASE_X_START);
-    let g_y = read_fp8::<AB>(preprocessed_row, GS_PREP_BASE_Y_START);
+    let g_x = read_fp8::<AB>(preprocessed_row, prep_offset + GS_PREP_BASE_X_START);
+    let g_y = read_fp8::<AB>(preprocessed_row, prep_offset + GS_PREP_BASE_Y_START);
+    let acc_x = read_fp8::<AB>(row, offset + DS_ACC_X_START);
+    let acc_y = read_fp8::<AB>(row, offset + DS_ACC_Y_START);
     let pk_x = read_fp8::<AB>(row, offset + DS_PK_X_START);
     let pk_y = read_fp8::<AB>(row, offset + DS_PK_Y_START);
-    let pk_double_num = read_fp8::<AB>(row, offset + DS_PK_DOUBLE_NUM_START);
-    let pk_double_den = read_fp8::<AB>(row, offset + DS_PK_DOUBLE_DEN_START);
+    let mid_x = read_fp8::<AB>(row, offset + DS_MID_X_START);
+    let mid_y = read_fp8::<AB>(row, offset + DS_MID_Y_START);
+
+    // pk doubling feeds the next step.
     let pk_double_inv = read_fp8::<AB>(row, offset + DS_PK_DOUBLE_INV_START);
     let pk_double_slope = read_fp8::<AB>(row, offset + DS_PK_DOUBLE_SLOPE_START);
     let pk_double_x = read_fp8::<AB>(row, offset + DS_PK_DOUBLE_X_START);
@@ -440,8 +1030,6 @@ pub(crate) fn eval_double_scalar_core<AB: AirBuilder<F = KoalaBear>>(
         builder,
         Fp8PointExpr { x: &pk_x, y: &pk_y },
         SlopeIntermediatesExpr {
-            num: &pk_double_num,
-            den: &pk_double_den,
             inv: &pk_double_inv,
             slope: &pk_double_slope,
         },
@@ -451,76 +1039,56 @@ pub(crate) fn eval_double_scalar_core<AB: AirBuilder<F = KoalaBear>>(
         },
     );
 
-    let sum_x = read_fp8::<AB>(row, offset + DS_SUM_X_START);
-    let sum_y = read_fp8::<AB>(row, offset + DS_SUM_Y_START);
-    let sum_num = read_fp8::<AB>(row, offset + DS_SUM_NUM_START);
-    let sum_den = read_fp8::<AB>(row, offset + DS_SUM_DEN_START);
-    let sum_inv = read_fp8::<AB>(row, offset + DS_SUM_INV_START);
-    let sum_slope = read_fp8::<AB>(row, offset + DS_SUM_SLOPE_START);
+    // mid = acc + s_bit * G
+    let g_add_x = read_fp8::<AB>(row, offset + DS_G_ADD_X_START);
+    let g_add_y = read_fp8::<AB>(row, offset + DS_G_ADD_Y_START);
+    let g_add_inv = read_fp8::<AB>(row, offset + DS_G_ADD_INV_START);
+    let g_add_slope = read_fp8::<AB>(row, offset + DS_G_ADD_SLOPE_START);
+    let mut g_add_builder = builder.when(row[offset + DS_G_ADD_ACTIVE_COL].clone());
     enforce_add_constraints_with_base(
-        builder,
-        Fp8PointExpr { x: &pk_x, y: &pk_y },
+        &mut g_add_builder,
+        Fp8PointExpr {
+            x: &acc_x,
+            y: &acc_y,
+        },
         Fp8PointExpr { x: &g_x, y: &g_y },
         SlopeIntermediatesExpr {
-            num: &sum_num,
-            den: &sum_den,
-            inv: &sum_inv,
-            slope: &sum_slope,
+            inv: &g_add_inv,
+            slope: &g_add_slope,
         },
         Fp8PointExpr {
-            x: &sum_x,
-            y: &sum_y,
+            x: &g_add_x,
+            y: &g_add_y,
         },
     );
-
-    let addend_x = read_fp8::<AB>(row, offset + DS_ADDEND_X_START);
-    let addend_y = read_fp8::<AB>(row, offset + DS_ADDEND_Y_START);
-
-    let s_expr: AB::Expr = s_bit.clone().into();
-    let e_expr: AB::Expr = e_bit.clone().into();
-    let sel00 = (AB::Expr::ONE - s_expr.clone()) * (AB::Expr::ONE - e_expr.clone());
-    let sel10 = s_expr.clone() * (AB::Expr::ONE - e_expr.clone());
-    let sel01 = (AB::Expr::ONE - s_expr.clone()) * e_expr.clone();
-    let sel11 = s_expr * e_expr;
+    let mut g_init_builder = builder.when(acc_inf.clone() * s_bit.clone());
+    assert_fp8_eq(&mut g_init_builder, &g_add_x, &g_x);
+    assert_fp8_eq(&mut g_init_builder, &g_add_y, &g_y);
     for i in 0..COORD_LIMBS {
         builder.assert_eq(
-            addend_x[i].clone(),
-            sel00.clone() * AB::Expr::ZERO
-                + sel10.clone() * g_x[i].clone()
-                + sel01.clone() * pk_x[i].clone()
-                + sel11.clone() * sum_x[i].clone(),
+            mid_x[i].clone(),
+            acc_x[i].clone() + s_bit.clone() * (g_add_x[i].clone() - acc_x[i].clone()),
         );
         builder.assert_eq(
-            addend_y[i].clone(),
-            sel00.clone() * AB::Expr::ZERO
-                + sel10.clone() * g_y[i].clone()
-                + sel01.clone() * pk_y[i].clone()
-                + sel11.clone() * sum_y[i].clone(),
+            mid_y[i].clone(),
+            acc_y[i].clone() + s_bit.clone() * (g_add_y[i].clone() - acc_y[i].clone()),
         );
     }
 
-    let mut add_builder = builder.when((AB::Expr::ONE - acc_inf_expr.clone()) * add_sel.clone());
-    let acc_x = read_fp8::<AB>(row, offset + DS_ACC_X_START);
-    let acc_y = read_fp8::<AB>(row, offset + DS_ACC_Y_START);
-    let add_num = read_fp8::<AB>(row, offset + DS_ADD_NUM_START);
-    let add_den = read_fp8::<AB>(row, offset + DS_ADD_DEN_START);
-    let add_inv = read_fp8::<AB>(row, offset + DS_ADD_INV_START);
-    let add_slope = read_fp8::<AB>(row, offset + DS_ADD_SLOPE_START);
+    // acc_next = mid + e_bit * pk
     let add_x = read_fp8::<AB>(row, offset + DS_ADD_X_START);
     let add_y = read_fp8::<AB>(row, offset + DS_ADD_Y_START);
+    let add_inv = read_fp8::<AB>(row, offset + DS_ADD_INV_START);
+    let add_slope = read_fp8::<AB>(row, offset + DS_ADD_SLOPE_START);
+    let mut add_builder = builder.when(row[offset + DS_ADD_ACTIVE_COL].clone());
     enforce_add_constraints_with_base(
         &mut add_builder,
         Fp8PointExpr {
-            x: &acc_x,
-            y: &acc_y,
-        },
-        Fp8PointExpr {
-            x: &addend_x,
-            y: &addend_y,
+            x: &mid_x,
+            y: &mid_y,
         },
+        Fp8PointExpr { x: &pk_x, y: &pk_y },
         SlopeIntermediatesExpr {
-            num: &add_num,
-            den: &add_den,
             inv: &add_inv,
             slope: &add_slope,
         },
@@ -529,40 +1097,30 @@ pub(crate) fn eval_double_scalar_core<AB: AirBuilder<F = KoalaBear>>(
             y: &add_y,
         },
     );
-
-    let mut init_builder = builder.when(acc_inf_expr.clone() * add_sel.clone());
-    for i in 0..COORD_LIMBS {
-        init_builder.assert_eq(
-            row[offset + DS_ADD_X_START + i].clone(),
-            row[offset + DS_ADDEND_X_START + i].clone(),
-        );
-        init_builder.assert_eq(
-            row[offset + DS_ADD_Y_START + i].clone(),
-            row[offset + DS_ADDEND_Y_START + i].clone(),
-        );
-    }
-
-    let mut acc_curve_builder = builder.when(AB::Expr::ONE - acc_inf_expr);
+    let mut init_builder = builder.when(mid_inf.clone() * e_bit.clone());
+    assert_fp8_eq(&mut init_builder, &add_x, &pk_x);
+    assert_fp8_eq(&mut init_builder, &add_y, &pk_y);
+
+    let out = StepOutput {
+        acc_x: core::array::from_fn(|i| {
+            mid_x[i].clone() + e_bit.clone() * (add_x[i].clone() - mid_x[i].clone())
+        }),
+        acc_y: core::array::from_fn(|i| {
+            mid_y[i].clone() + e_bit.clone() * (add_y[i].clone() - mid_y[i].clone())
+        }),
+        pk_x: pk_double_x,
+        pk_y: pk_double_y,
+        acc_inf: mid_inf * (AB::Expr::ONE - e_bit),
+    };
+
+    let mut acc_curve_builder = builder.when(AB::Expr::ONE - acc_inf);
     enforce_on_curve(
         &mut acc_curve_builder,
         row,
         offset + DS_ACC_X_START,
         offset + DS_ACC_Y_START,
     );
-    let mut addend_curve_builder = builder.when(add_sel);
-    enforce_on_curve(
-        &mut addend_curve_builder,
-        row,
-        offset + DS_ADDEND_X_START,
-        offset + DS_ADDEND_Y_START,
-    );
     let mut base_curve_builder = builder.when(KoalaBear::ONE);
-    enforce_on_curve(
-        &mut base_curve_builder,
-        row,
-        offset + DS_SUM_X_START,
-        offset + DS_SUM_Y_START,
-    );
     enforce_on_curve(
         &mut base_curve_builder,
         row,
@@ -572,16 +1130,528 @@ pub(crate) fn eval_double_scalar_core<AB: AirBuilder<F = KoalaBear>>(
     enforce_on_curve(
         &mut base_curve_builder,
         preprocessed_row,
-        GS_PREP_BASE_X_START,
-        GS_PREP_BASE_Y_START,
+        prep_offset + GS_PREP_BASE_X_START,
+        prep_offset + GS_PREP_BASE_Y_START,
     );
+
+    out
+}
+
+/// Constrain the step at `offset` in `row` to start from `out`.
+fn assert_step_link<AB: AirBuilder<F = KoalaBear>>(
+    builder: &mut AB,
+    row: &[AB::Var],
+    offset: usize,
+    out: StepOutput<AB::Expr>,
+) {
+    for i in 0..COORD_LIMBS {
+        builder.assert_eq(
+            row[offset + DS_ACC_X_START + i].clone(),
+            out.acc_x[i].clone(),
+        );
+        builder.assert_eq(
+            row[offset + DS_ACC_Y_START + i].clone(),
+            out.acc_y[i].clone(),
+        );
+        builder.assert_eq(row[offset + DS_PK_X_START + i].clone(), out.pk_x[i].clone());
+        builder.assert_eq(row[offset + DS_PK_Y_START + i].clone(), out.pk_y[i].clone());
+    }
+    builder.assert_eq(row[offset + DS_ACC_INF_COL].clone(), out.acc_inf);
 }
 
 fn coeffs_to_base(coeffs: [KoalaBear; COORD_LIMBS]) -> BaseField {
-    unsafe { core::mem::transmute(coeffs) }
+    curve::from_coeffs(coeffs)
 }
 
 fn write_base(row: &mut [KoalaBear], start: usize, value: BaseField) {
-    let coeffs: [KoalaBear; COORD_LIMBS] = unsafe { core::mem::transmute(value) };
-    row[start..start + COORD_LIMBS].copy_from_slice(&coeffs[..COORD_LIMBS]);
+    row[start..start + COORD_LIMBS].copy_from_slice(&curve::to_coeffs(value));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{check_constraints, test_config, TestConfig};
+    use p3_uni_stark::{prove_with_preprocessed, setup_preprocessed, verify_with_preprocessed};
+    use p3_util::log2_strict_usize;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use schnorr::SigningKey;
+
+    fn witness() -> SignatureWitness {
+        let mut rng = StdRng::seed_from_u64(7);
+        let signing_key = SigningKey::random(&mut rng);
+        let message = vec![
+            BabyBear::from_u32(1),
+            BabyBear::from_u32(2),
+            BabyBear::from_u32(3),
+        ];
+        let signature = signing_key.sign(&mut rng, &message).unwrap();
+        SignatureWitness::new(&signature, &signing_key.verifying_key(), &message).unwrap()
+    }
+
+    #[test]
+    fn test_challenge_binding_rejects_odd_r() {
+        let mut rng = StdRng::seed_from_u64(80);
+        let signing_key = SigningKey::random(&mut rng);
+        let message = vec![
+            BabyBear::from_u32(1),
+            BabyBear::from_u32(2),
+            BabyBear::from_u32(3),
+        ];
+        let signature = signing_key.sign(&mut rng, &message).unwrap();
+        let negated = schnorr::Signature {
+            r: -signature.r,
+            s: -signature.s,
+        };
+
+        // Both public-value vectors are consistent with the negated R, so only
+        // its parity tells it apart
+        let witness =
+            SignatureWitness::new(&negated, &signing_key.verifying_key(), &message).unwrap();
+        let schnorr_public = schnorr_public_values(&witness);
+        let hash_public = challenge_hash_public_values(&witness).unwrap();
+        assert!(!check_challenge_binding(&schnorr_public, &hash_public));
+    }
+
+    #[test]
+    fn test_challenge_binding() {
+        let witness = witness();
+        let schnorr_public = schnorr_public_values(&witness);
+        let hash_public = challenge_hash_public_values(&witness).unwrap();
+        assert!(check_challenge_binding(&schnorr_public, &hash_public));
+
+        let mut wrong_e = schnorr_public.clone();
+        wrong_e[SCHNORR_BASE_PUBLIC + SCHNORR_R_PUBLIC] += KoalaBear::ONE;
+        assert!(!check_challenge_binding(&wrong_e, &hash_public));
+
+        let mut wrong_r = hash_public.clone();
+        wrong_r[0] += BabyBear::ONE;
+        assert!(!check_challenge_binding(&schnorr_public, &wrong_r));
+
+        // A length element that disagrees with the message is rejected.
+        let mut wrong_len = hash_public.clone();
+        wrong_len[SCHNORR_R_PUBLIC + SCHNORR_BASE_PUBLIC] = BabyBear::from_u32(7);
+        assert!(!check_challenge_binding(&schnorr_public, &wrong_len));
+
+        // A digest packing to zero is rejected even with matching zero limbs.
+        let mut zero_digest = hash_public.clone();
+        zero_digest[POSEIDON2_INPUT_LEN..POSEIDON2_INPUT_LEN + POSEIDON2_DIGEST_LEN]
+            .fill(BabyBear::ZERO);
+        let mut zero_e = schnorr_public.clone();
+        zero_e[SCHNORR_BASE_PUBLIC + SCHNORR_R_PUBLIC..].fill(KoalaBear::ZERO);
+        assert!(!check_challenge_binding(&zero_e, &zero_digest));
+    }
+
+    #[test]
+    fn test_digest_packing_matches_schnorr() {
+        let witness = witness();
+        let hash_public = challenge_hash_public_values(&witness).unwrap();
+        let digest = &hash_public[POSEIDON2_INPUT_LEN..POSEIDON2_INPUT_LEN + POSEIDON2_DIGEST_LEN];
+        assert_eq!(
+            challenge_from_digest(digest),
+            witness.challenge.to_scalar_field()
+        );
+
+        // Two 31-bit digits pack to d0 + d1 * p_BabyBear
+        let p = BabyBear::ORDER_U32 as u64;
+        let digits = [BabyBear::from_u32(7), BabyBear::NEG_ONE];
+        assert_eq!(
+            schnorr::pack_digest_to_scalar(&digits),
+            ScalarField::from_canonical_u64(7 + (p - 1) * p)
+        );
+        assert_eq!(
+            challenge_from_digest(&digits),
+            schnorr::pack_digest_to_scalar(&digits)
+        );
+    }
+
+    #[test]
+    fn test_witness_public_values_layout() {
+        let witness = witness();
+        let public_values = witness.public_values();
+        let (pk, rest) = public_values.split_at(SCHNORR_BASE_PUBLIC);
+        let (r, neg_e) = rest.split_at(SCHNORR_R_PUBLIC);
+
+        assert_eq!(pk, [witness.public_key.x, witness.public_key.y].concat());
+        assert_eq!(r, [witness.r.x, witness.r.y].concat());
+        assert_eq!(neg_e.len(), SCHNORR_NEG_E_PUBLIC);
+        assert_eq!(
+            CircuitScalar {
+                limbs: neg_e.try_into().unwrap()
+            }
+            .to_scalar_field(),
+            -witness.challenge.to_scalar_field()
+        );
+        assert_eq!(schnorr_public_values(&witness), public_values);
+    }
+
+    #[test]
+    fn test_trace_accumulates_neg_e_limbs() {
+        let witness = witness();
+        let trace = build_schnorr_trace(&witness).trace;
+        let last = trace.row_slice(trace.height() - 1).unwrap();
+        let neg_e = CircuitScalar::from_scalar_field(-witness.challenge.to_scalar_field());
+        assert_eq!(
+            &(*last)[DS_E_LIMB_START..DS_E_LIMB_START + SCALAR_LIMBS],
+            &neg_e.limbs[..]
+        );
+    }
+
+    #[test]
+    fn test_batch_inverted_slopes_match_per_row() {
+        use p3_field::Field;
+
+        let read_base = |row: &[KoalaBear], start: usize| {
+            coeffs_to_base(row[start..start + COORD_LIMBS].try_into().unwrap())
+        };
+        // Inverse and slope columns as the per-row `.inverse()` would fill them
+        let check = |row: &[KoalaBear], start: usize, num: BaseField, den: BaseField| {
+            let inv = den.inverse();
+            assert_eq!(read_base(row, start), inv);
+            assert_eq!(read_base(row, start + COORD_LIMBS), num * inv);
+        };
+
+        let witness = witness();
+        let trace = build_schnorr_trace(&witness).trace;
+        let preprocessed = build_gs_preprocessed_trace(trace.height(), 1);
+        let three = KoalaBear::from_u32(3);
+        let mut a = [KoalaBear::ZERO; COORD_LIMBS];
+        a[1] = three;
+        let a = coeffs_to_base(a);
+
+        for row_idx in 0..trace.height() {
+            let row = trace.row_slice(row_idx).unwrap();
+            let row = (*row).as_ref();
+            let prep = preprocessed.row_slice(row_idx).unwrap();
+            let prep = (*prep).as_ref();
+
+            let pk_x = read_base(row, DS_PK_X_START);
+            let pk_y = read_base(row, DS_PK_X_START + COORD_LIMBS);
+            check(
+                row,
+                DS_PK_DOUBLE_INV_START,
+                pk_x * pk_x * three + a,
+                pk_y + pk_y,
+            );
+
+            if row[DS_G_ADD_ACTIVE_COL] == KoalaBear::ONE {
+                let acc_x = read_base(row, DS_ACC_X_START);
+                let acc_y = read_base(row, DS_ACC_X_START + COORD_LIMBS);
+                let g_x = read_base(prep, GS_PREP_BASE_X_START);
+                let g_y = read_base(prep, GS_PREP_BASE_X_START + COORD_LIMBS);
+                check(row, DS_G_ADD_INV_START, g_y - acc_y, g_x - acc_x);
+            }
+
+            if row[DS_ADD_ACTIVE_COL] == KoalaBear::ONE {
+                let mid_x = read_base(row, DS_MID_X_START);
+                let mid_y = read_base(row, DS_MID_X_START + COORD_LIMBS);
+                check(row, DS_ADD_INV_START, pk_y - mid_y, pk_x - mid_x);
+            }
+        }
+    }
+
+    #[test]
+    fn test_schnorr_prove_verify() {
+        let witness = witness();
+        let trace = build_schnorr_trace(&witness).trace;
+        let height = trace.height();
+        let air = SchnorrAir::new(height);
+        let public_values = schnorr_public_values(&witness);
+
+        let config = test_config();
+        let (prover_data, vk) =
+            setup_preprocessed::<TestConfig, _>(&config, &air, log2_strict_usize(height))
+                .expect("preprocessed");
+        let proof =
+            prove_with_preprocessed(&config, &air, trace, &public_values, Some(&prover_data));
+        verify_with_preprocessed(&config, &air, &proof, &public_values, Some(&vk))
+            .expect("schnorr proof verifies");
+    }
+
+    #[test]
+    fn test_schnorr_prove_verify_public_challenge() {
+        use curve::{Affine, Group, RandomField};
+
+        let mut rng = StdRng::seed_from_u64(98);
+        let signing_key = SigningKey::random(&mut rng);
+        let verifying_key = signing_key.verifying_key();
+
+        // A challenge fixed outside the hash, as a parent transcript would
+        let e = ScalarField::random(&mut rng);
+        let mut nonce = ScalarField::random(&mut rng);
+        if Affine::mul_generator(&nonce).has_odd_y() {
+            nonce = -nonce;
+        }
+        let signature = signing_key.sign_prehashed(nonce, e).unwrap();
+        assert!(verifying_key.verify_prehashed(e, &signature).unwrap());
+        let witness = SignatureWitness {
+            r: CircuitPoint::from_affine(&signature.r),
+            s: CircuitScalar::from_scalar_field(signature.s),
+            public_key: CircuitPoint::from_affine(&verifying_key.as_affine()),
+            message: Vec::new(),
+            challenge: CircuitScalar::from_scalar_field(e),
+        };
+
+        let trace = build_schnorr_trace(&witness).trace;
+        let height = trace.height();
+        let air = SchnorrAir::new(height).with_public_challenge();
+        let public_values = schnorr_public_values_with_challenge(&witness);
+        assert_eq!(public_values.len(), SCHNORR_PUBLIC_VALUES_WITH_CHALLENGE);
+        assert_eq!(
+            public_values[SCHNORR_PUBLIC_VALUES..],
+            CircuitScalar::from_scalar_field(e).limbs
+        );
+
+        let config = test_config();
+        let (prover_data, vk) =
+            setup_preprocessed::<TestConfig, _>(&config, &air, log2_strict_usize(height))
+                .expect("preprocessed");
+        let proof =
+            prove_with_preprocessed(&config, &air, trace, &public_values, Some(&prover_data));
+        verify_with_preprocessed(&config, &air, &proof, &public_values, Some(&vk))
+            .expect("proof with a public challenge verifies");
+
+        // The proof does not verify for any other public challenge
+        let mut wrong_e = public_values.clone();
+        wrong_e[SCHNORR_PUBLIC_VALUES] += KoalaBear::ONE;
+        assert!(verify_with_preprocessed(&config, &air, &proof, &wrong_e, Some(&vk)).is_err());
+    }
+
+    #[test]
+    fn test_schnorr_prove_verify_padded_height() {
+        let witness = witness();
+        assert_eq!(build_schnorr_trace(&witness).trace.height(), 256);
+
+        let height = 512;
+        let trace = build_schnorr_trace_with_height(&witness, height).trace;
+        assert_eq!(trace.height(), height);
+        let air = SchnorrAir::new(height);
+        let public_values = schnorr_public_values(&witness);
+
+        let config = test_config();
+        let (prover_data, vk) =
+            setup_preprocessed::<TestConfig, _>(&config, &air, log2_strict_usize(height))
+                .expect("preprocessed");
+        let proof =
+            prove_with_preprocessed(&config, &air, trace, &public_values, Some(&prover_data));
+        verify_with_preprocessed(&config, &air, &proof, &public_values, Some(&vk))
+            .expect("padded schnorr proof verifies");
+    }
+
+    #[test]
+    fn test_schnorr_prove_verify_windowed() {
+        let witness = witness();
+        let public_values = schnorr_public_values(&witness);
+        let config = test_config();
+
+        for (window, expected_height) in [(1, 256), (4, 64)] {
+            let trace = build_schnorr_trace_with_window(&witness, window).trace;
+            let height = trace.height();
+            assert_eq!(height, expected_height);
+            assert_eq!(trace.width(), schnorr_columns(window));
+
+            // Stage 0 of the last row holds R, and the limbs sum every step.
+            let last = trace.row_slice(height - 1).unwrap();
+            let neg_e = CircuitScalar::from_scalar_field(-witness.challenge.to_scalar_field());
+            let limb_start = window * DS_STEP_COLUMNS;
+            assert_eq!(
+                &(*last)[limb_start..limb_start + SCALAR_LIMBS],
+                &neg_e.limbs[..]
+            );
+
+            let air = SchnorrAir::with_window(height, window);
+            assert_eq!(
+                air.preprocessed_trace().unwrap().width(),
+                gs_prep_columns(window)
+            );
+            let (prover_data, vk) =
+                setup_preprocessed::<TestConfig, _>(&config, &air, log2_strict_usize(height))
+                    .expect("preprocessed");
+            let proof =
+                prove_with_preprocessed(&config, &air, trace, &public_values, Some(&prover_data));
+            verify_with_preprocessed(&config, &air, &proof, &public_values, Some(&vk))
+                .expect("windowed schnorr proof verifies");
+        }
+    }
+
+    #[test]
+    fn test_rejects_undersized_height() {
+        assert_eq!(SchnorrAir::new(256).num_rows, 256);
+        assert_eq!(SchnorrAir::with_window(32, 8).num_rows, 32);
+        // 128 rows cover only half of the 252 challenge bits
+        assert!(std::panic::catch_unwind(|| SchnorrAir::new(128)).is_err());
+        assert!(std::panic::catch_unwind(|| SchnorrAir::with_window(32, 4)).is_err());
+
+        let witness = witness();
+        assert!(
+            std::panic::catch_unwind(|| build_schnorr_trace_with_height(&witness, 128)).is_err()
+        );
+    }
+
+    /// [`SchnorrAir`] with its preprocessed trace replaced, so tests can commit
+    /// to a corrupted generator table.
+    struct WithPreprocessed {
+        air: SchnorrAir,
+        preprocessed: RowMajorMatrix<KoalaBear>,
+    }
+
+    impl BaseAir<KoalaBear> for WithPreprocessed {
+        fn width(&self) -> usize {
+            self.air.width()
+        }
+
+        fn preprocessed_trace(&self) -> Option<RowMajorMatrix<KoalaBear>> {
+            Some(self.preprocessed.clone())
+        }
+    }
+
+    impl BaseAirWithPublicValues<KoalaBear> for WithPreprocessed {
+        fn num_public_values(&self) -> usize {
+            self.air.num_public_values()
+        }
+    }
+
+    impl<AB> Air<AB> for WithPreprocessed
+    where
+        AB: AirBuilder<F = KoalaBear> + AirBuilderWithPublicValues + PairBuilder,
+    {
+        fn eval(&self, builder: &mut AB) {
+            self.air.eval(builder);
+        }
+    }
+
+    fn prove_and_verify(
+        trace: RowMajorMatrix<KoalaBear>,
+        preprocessed: RowMajorMatrix<KoalaBear>,
+        public_values: &[KoalaBear],
+    ) -> Result<(), String> {
+        let height = trace.height();
+        let air = WithPreprocessed {
+            air: SchnorrAir::new(height),
+            preprocessed,
+        };
+        check_constraints(&air, &trace, Some(&air.preprocessed), public_values)?;
+
+        let config = test_config();
+        let (prover_data, vk) =
+            setup_preprocessed::<TestConfig, _>(&config, &air, log2_strict_usize(height))
+                .expect("preprocessed");
+        let proof =
+            prove_with_preprocessed(&config, &air, trace, public_values, Some(&prover_data));
+        verify_with_preprocessed(&config, &air, &proof, public_values, Some(&vk))
+            .map_err(|e| format!("{e:?}"))
+    }
+
+    /// Proves the honest witness with `corrupt` applied to the trace, preprocessed
+    /// trace and public values.
+    fn prove_corrupted(
+        corrupt: impl FnOnce(
+            &mut RowMajorMatrix<KoalaBear>,
+            &mut RowMajorMatrix<KoalaBear>,
+            &mut Vec<KoalaBear>,
+        ),
+    ) -> Result<(), String> {
+        let witness = witness();
+        let mut trace = build_schnorr_trace(&witness).trace;
+        let mut preprocessed = build_gs_preprocessed_trace(trace.height(), 1);
+        let mut public_values = schnorr_public_values(&witness);
+        corrupt(&mut trace, &mut preprocessed, &mut public_values);
+        prove_and_verify(trace, preprocessed, &public_values)
+    }
+
+    fn cell(matrix: &mut RowMajorMatrix<KoalaBear>, row: usize, col: usize) -> &mut KoalaBear {
+        let width = matrix.width();
+        &mut matrix.values[row * width + col]
+    }
+
+    #[test]
+    fn test_column_layout_covers_trace() {
+        let layout = column_layout();
+        assert_eq!(layout.width(), SCHNORR_COLUMNS);
+
+        // Ranges are contiguous and in column order, so none overlap
+        let mut next = 0;
+        for (name, range) in layout.ranges() {
+            assert_eq!(
+                range.start, next,
+                "{name} does not follow the previous range"
+            );
+            next = range.end;
+        }
+        assert_eq!(next, SCHNORR_COLUMNS);
+        assert_eq!(layout.s_bit, DS_S_BIT_COL..DS_S_BIT_COL + 1);
+    }
+
+    #[test]
+    fn test_uncorrupted_trace_verifies() {
+        assert_eq!(prove_corrupted(|_, _, _| {}), Ok(()));
+    }
+
+    #[test]
+    fn test_rejects_wrong_final_accumulator() {
+        // Public R no longer matches the accumulator
+        assert!(prove_corrupted(|_, _, public_values| {
+            public_values[SCHNORR_BASE_PUBLIC] += KoalaBear::ONE;
+        })
+        .is_err());
+        // Accumulator no longer matches public R
+        assert!(prove_corrupted(|trace, _, _| {
+            let last = trace.height() - 1;
+            *cell(trace, last, DS_ACC_X_START) += KoalaBear::ONE;
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_rejects_wrong_generator_binding() {
+        assert!(prove_corrupted(|_, preprocessed, _| {
+            *cell(preprocessed, 0, GS_PREP_BASE_X_START) += KoalaBear::ONE;
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_boolean_bits() {
+        assert!(prove_corrupted(|trace, _, _| {
+            *cell(trace, 5, DS_S_BIT_COL) = KoalaBear::from_u32(2);
+        })
+        .is_err());
+        assert!(prove_corrupted(|trace, _, _| {
+            *cell(trace, 5, DS_E_BIT_COL) = KoalaBear::from_u32(2);
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_rejects_flipped_acc_inf() {
+        assert!(prove_corrupted(|trace, _, _| {
+            let flag = cell(trace, 0, DS_ACC_INF_COL);
+            *flag = KoalaBear::ONE - *flag;
+        })
+        .is_err());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_trace_matches_serial() {
+        let witness = witness();
+        let s_bits = scalar_to_bits(&witness.s.to_scalar_field());
+        let neg_e_bits = scalar_to_bits(&-witness.challenge.to_scalar_field());
+        let generator = CircuitPoint::from_affine(&curve::Projective::generator().to_affine());
+
+        for window in [1, 3] {
+            let parallel = build_schnorr_trace_with_window(&witness, window).trace;
+            let height = parallel.height();
+            let steps = height * window;
+            let serial = build_double_scalar_trace(
+                &s_bits,
+                &neg_e_bits,
+                &doubling_chain_serial(&witness.public_key, steps + 1),
+                &doubling_chain_serial(&generator, steps),
+                height,
+                window,
+            );
+            assert_eq!(parallel.values, serial);
+        }
+    }
 }
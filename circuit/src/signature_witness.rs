@@ -2,7 +2,10 @@
 
 use crate::point_ops::CircuitPoint;
 use crate::scalar_arithmetic::CircuitScalar;
+use crate::schnorr_air::{SCHNORR_BASE_PUBLIC, SCHNORR_PUBLIC_VALUES, SCHNORR_R_PUBLIC};
+use curve::{Affine, KoalaBear};
 use p3_baby_bear::BabyBear;
+use p3_field::PrimeCharacteristicRing;
 use schnorr::{hash_challenge, Signature, VerifyingKey};
 
 /// Witness data for the signature verification circuit.
@@ -20,7 +23,7 @@ pub struct SignatureWitness {
     pub public_key: CircuitPoint,
     /// The message (as BabyBear field elements)
     pub message: Vec<BabyBear>,
-    /// The challenge e = H(R || pk || msg)
+    /// The challenge e = H(R || pk || len(msg) || msg)
     pub challenge: CircuitScalar,
 }
 
@@ -45,4 +48,92 @@ impl SignatureWitness {
             challenge: CircuitScalar::from_scalar_field(challenge),
         })
     }
+
+    /// Like [`SignatureWitness::new`], but also checks that `R` has an even
+    /// y-coordinate and that `s·G - e·pk == R`, as `VerifyingKey::verify` does.
+    ///
+    /// A witness for an invalid signature builds a trace that fails to prove;
+    /// this reports the mismatch at construction time instead, at the cost of
+    /// one double scalar multiplication.
+    pub fn new_checked(
+        signature: &Signature,
+        public_key: &VerifyingKey,
+        message: &[BabyBear],
+    ) -> Result<Self, String> {
+        if signature.r.has_odd_y() {
+            return Err("Signature R has an odd y-coordinate".to_string());
+        }
+        let witness = Self::new(signature, public_key, message)?;
+
+        let e = witness.challenge.to_scalar_field();
+        let lhs = Affine::double_scalar_mul_basepoint(&signature.s, &-e, &public_key.as_affine());
+        if lhs != signature.r {
+            return Err(format!(
+                "Signature does not verify: s·G - e·pk = {:?}, expected R = {:?}",
+                lhs, signature.r
+            ));
+        }
+        Ok(witness)
+    }
+
+    /// Public values for [`SchnorrAir`](crate::SchnorrAir) in the order the AIR
+    /// reads them: `pk.x || pk.y || R.x || R.y || -e` (see the
+    /// [`schnorr_air`](crate::schnorr_air) module docs).
+    pub fn public_values(&self) -> [KoalaBear; SCHNORR_PUBLIC_VALUES] {
+        let neg_e = CircuitScalar::from_scalar_field(-self.challenge.to_scalar_field());
+        let coords = [&self.public_key.x, &self.public_key.y, &self.r.x, &self.r.y];
+
+        let mut public_values = [KoalaBear::ZERO; SCHNORR_PUBLIC_VALUES];
+        let (points, neg_e_public) =
+            public_values.split_at_mut(SCHNORR_BASE_PUBLIC + SCHNORR_R_PUBLIC);
+        for (chunk, coord) in points.chunks_exact_mut(8).zip(coords) {
+            chunk.copy_from_slice(coord);
+        }
+        neg_e_public.copy_from_slice(&neg_e.limbs);
+        public_values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use curve::ScalarField;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+    use schnorr::SigningKey;
+
+    #[test]
+    fn test_new_checked_rejects_tampered_s() {
+        let mut rng = StdRng::seed_from_u64(40);
+        let signing_key = SigningKey::random(&mut rng);
+        let verifying_key = signing_key.verifying_key();
+        let message = vec![BabyBear::from_u32(1), BabyBear::from_u32(2)];
+        let signature = signing_key.sign(&mut rng, &message).unwrap();
+
+        assert!(SignatureWitness::new_checked(&signature, &verifying_key, &message).is_ok());
+
+        let tampered = Signature {
+            r: signature.r,
+            s: signature.s + ScalarField::ONE,
+        };
+        assert!(SignatureWitness::new(&tampered, &verifying_key, &message).is_ok());
+        let err = SignatureWitness::new_checked(&tampered, &verifying_key, &message).unwrap_err();
+        assert!(err.contains("does not verify"));
+    }
+
+    #[test]
+    fn test_new_checked_rejects_negated_signature() {
+        let mut rng = StdRng::seed_from_u64(80);
+        let signing_key = SigningKey::random(&mut rng);
+        let verifying_key = signing_key.verifying_key();
+        let message = vec![BabyBear::from_u32(1), BabyBear::from_u32(2)];
+        let signature = signing_key.sign(&mut rng, &message).unwrap();
+
+        let negated = Signature {
+            r: -signature.r,
+            s: -signature.s,
+        };
+        let err = SignatureWitness::new_checked(&negated, &verifying_key, &message).unwrap_err();
+        assert!(err.contains("odd y-coordinate"));
+    }
 }
@@ -0,0 +1,170 @@
+//! Shared STARK configuration and constraint checking for circuit tests.
+
+use p3_air::{Air, AirBuilder, AirBuilderWithPublicValues, PairBuilder};
+use p3_challenger::{HashChallenger, SerializingChallenger32};
+use p3_commit::ExtensionMmcs;
+use p3_dft::Radix2DitParallel;
+use p3_field::extension::BinomialExtensionField;
+use p3_field::PrimeCharacteristicRing;
+use p3_fri::{FriParameters, TwoAdicFriPcs};
+use p3_keccak::{Keccak256Hash, KeccakF};
+use p3_matrix::dense::RowMajorMatrix;
+use p3_matrix::Matrix;
+use p3_merkle_tree::MerkleTreeMmcs;
+use p3_symmetric::{CompressionFunctionFromHasher, PaddingFreeSponge, SerializingHasher};
+use p3_uni_stark::StarkConfig;
+
+use curve::KoalaBear;
+
+type Val = KoalaBear;
+type Challenge = BinomialExtensionField<Val, 4>;
+type ByteHash = Keccak256Hash;
+type U64Hash = PaddingFreeSponge<KeccakF, 25, 17, 4>;
+type FieldHash = SerializingHasher<U64Hash>;
+type MyCompress = CompressionFunctionFromHasher<U64Hash, 2, 4>;
+type ValMmcs = MerkleTreeMmcs<
+    [Val; p3_keccak::VECTOR_LEN],
+    [u64; p3_keccak::VECTOR_LEN],
+    FieldHash,
+    MyCompress,
+    4,
+>;
+type ChallengeMmcs = ExtensionMmcs<Val, Challenge, ValMmcs>;
+type Challenger = SerializingChallenger32<Val, HashChallenger<u8, ByteHash, 32>>;
+type Dft = Radix2DitParallel<Val>;
+type Pcs = TwoAdicFriPcs<Val, Dft, ValMmcs, ChallengeMmcs>;
+
+pub(crate) type TestConfig = StarkConfig<Pcs, Challenge, Challenger>;
+
+/// Keccak-based config matching `examples/prove_verify_signature.rs`, with fewer queries.
+pub(crate) fn test_config() -> TestConfig {
+    let u64_hash = U64Hash::new(KeccakF {});
+    let field_hash = FieldHash::new(u64_hash);
+    let compress = MyCompress::new(u64_hash);
+    let val_mmcs = ValMmcs::new(field_hash, compress);
+    let challenge_mmcs = ChallengeMmcs::new(val_mmcs.clone());
+    let challenger = Challenger::from_hasher(vec![], ByteHash {});
+
+    let fri_params = FriParameters {
+        log_blowup: 3,
+        log_final_poly_len: 0,
+        num_queries: 16,
+        commit_proof_of_work_bits: 1,
+        query_proof_of_work_bits: 1,
+        mmcs: challenge_mmcs,
+    };
+    let pcs = Pcs::new(Dft::default(), val_mmcs, fri_params);
+    TestConfig::new(pcs, challenger)
+}
+
+/// Evaluates an AIR on one `(local, next)` row pair and records whether any
+/// constraint is nonzero, where the prover's own debug check would panic.
+pub(crate) struct ConstraintChecker<'a> {
+    main: RowMajorMatrix<Val>,
+    preprocessed: RowMajorMatrix<Val>,
+    public_values: &'a [Val],
+    is_first_row: Val,
+    is_last_row: Val,
+    is_transition: Val,
+    failed: bool,
+}
+
+impl AirBuilder for ConstraintChecker<'_> {
+    type F = Val;
+    type Expr = Val;
+    type Var = Val;
+    type M = RowMajorMatrix<Val>;
+
+    fn main(&self) -> Self::M {
+        self.main.clone()
+    }
+
+    fn is_first_row(&self) -> Self::Expr {
+        self.is_first_row
+    }
+
+    fn is_last_row(&self) -> Self::Expr {
+        self.is_last_row
+    }
+
+    fn is_transition_window(&self, size: usize) -> Self::Expr {
+        assert_eq!(size, 2, "only two-row windows are supported");
+        self.is_transition
+    }
+
+    fn assert_zero<I: Into<Self::Expr>>(&mut self, x: I) {
+        if x.into() != Val::ZERO {
+            self.failed = true;
+        }
+    }
+}
+
+impl AirBuilderWithPublicValues for ConstraintChecker<'_> {
+    type PublicVar = Val;
+
+    fn public_values(&self) -> &[Self::PublicVar] {
+        self.public_values
+    }
+}
+
+impl PairBuilder for ConstraintChecker<'_> {
+    fn preprocessed(&self) -> Self::M {
+        self.preprocessed.clone()
+    }
+}
+
+/// The two-row window starting at `row`, wrapping around to the first row.
+fn window(matrix: &RowMajorMatrix<Val>, row: usize) -> RowMajorMatrix<Val> {
+    let width = matrix.width();
+    let next = (row + 1) % matrix.height();
+    let values = [
+        &matrix.values[row * width..(row + 1) * width],
+        &matrix.values[next * width..(next + 1) * width],
+    ]
+    .concat();
+    RowMajorMatrix::new(values, width)
+}
+
+/// Checks every row of `main` against `air`, returning the first row whose
+/// constraints do not vanish.
+///
+/// Proving an unsatisfied trace panics in debug builds, so tests that feed the
+/// prover malformed traces call this first and get an error instead.
+pub(crate) fn check_constraints<A>(
+    air: &A,
+    main: &RowMajorMatrix<Val>,
+    preprocessed: Option<&RowMajorMatrix<Val>>,
+    public_values: &[Val],
+) -> Result<(), String>
+where
+    A: for<'a> Air<ConstraintChecker<'a>>,
+{
+    let height = main.height();
+    if let Some(preprocessed) = preprocessed {
+        if preprocessed.height() != height {
+            return Err(format!(
+                "preprocessed height {} differs from trace height {height}",
+                preprocessed.height()
+            ));
+        }
+    }
+    let empty = RowMajorMatrix::new(vec![Val::ZERO; height], 1);
+    let preprocessed = preprocessed.unwrap_or(&empty);
+
+    for row in 0..height {
+        let mut builder = ConstraintChecker {
+            main: window(main, row),
+            preprocessed: window(preprocessed, row),
+            public_values,
+            is_first_row: Val::from_bool(row == 0),
+            is_last_row: Val::from_bool(row == height - 1),
+            is_transition: Val::from_bool(row != height - 1),
+            failed: false,
+        };
+        air.eval(&mut builder);
+        if builder.failed {
+            return Err(format!("constraints not satisfied on row {row}"));
+        }
+    }
+    Ok(())
+}
@@ -1,5 +1,7 @@
-use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use curve::{BaseField, KoalaBear};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use curve::{BaseField, KoalaBear, RandomField, ScalarField};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 
 fn bench_koalabear_mul(c: &mut Criterion) {
     c.bench_function("koalabear_mul", |bencher| {
@@ -36,5 +38,145 @@ fn bench_koalabear_ext8_mul(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_koalabear_mul, bench_koalabear_ext8_mul);
+/// The schoolbook Montgomery multiply that CIOS replaced, kept as a baseline.
+///
+/// Works on raw limbs, so it takes canonical values where the field keeps
+/// Montgomery form; the cost does not depend on which representation the
+/// operands are in.
+mod schoolbook {
+    const MODULUS: [u64; 4] = [
+        0xf2154ff8a2e94d81,
+        0xf85ccc2efc3068fa,
+        0x40f5f26a5ae1748f,
+        0x00f06e44682c2aa4,
+    ];
+    const MU: u64 = 0x921d21f874d30d7f;
+
+    /// `a * b * 2^-256 mod p`.
+    pub fn montgomery_mul(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+        let mut t = [0u64; 8];
+        for i in 0..4 {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let product = (a[i] as u128) * (b[j] as u128) + (t[i + j] as u128) + carry;
+                t[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            t[i + 4] = carry as u64;
+        }
+
+        for i in 0..4 {
+            let k = t[i].wrapping_mul(MU);
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let product = (k as u128) * (MODULUS[j] as u128) + (t[i + j] as u128) + carry;
+                t[i + j] = product as u64;
+                carry = product >> 64;
+            }
+            for j in 4..8 - i {
+                let sum = (t[i + j] as u128) + carry;
+                t[i + j] = sum as u64;
+                carry = sum >> 64;
+            }
+        }
+
+        let high = [t[4], t[5], t[6], t[7]];
+        let mut reduced = [0u64; 4];
+        let mut borrow = false;
+        for (out, (&h, &m)) in reduced.iter_mut().zip(high.iter().zip(&MODULUS)) {
+            let (diff, b1) = h.overflowing_sub(m);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            *out = diff;
+            borrow = b1 || b2;
+        }
+        if borrow {
+            high
+        } else {
+            reduced
+        }
+    }
+}
+
+/// Unrolled CIOS against the schoolbook baseline it replaced.
+///
+/// Measured with both functions copied into a standalone harness on a single
+/// virtualized Xeon core (best of 15 runs, 1024 random operand pairs): 26.0 ns
+/// for CIOS against 26.2 ns for schoolbook on independent products, and
+/// 36.5 ns against 40.2 ns on a dependent chain. Throughput is unchanged
+/// within noise; the gain is up to about 9% in latency, which is what a
+/// scalar-multiplication ladder sees.
+fn bench_scalar_mul(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = ScalarField::random(&mut rng);
+    let b = ScalarField::random(&mut rng);
+    let (a_limbs, b_limbs) = (a.to_canonical_u64_vec(), b.to_canonical_u64_vec());
+
+    // The baseline computes the same Montgomery product: a * b * 2^-256
+    let two_256 = ScalarField::from_canonical_u64(2).pow(&[256, 0, 0, 0]);
+    assert_eq!(
+        ScalarField::from_canonical_limbs(schoolbook::montgomery_mul(a_limbs, b_limbs)) * two_256,
+        a * b
+    );
+
+    let mut group = c.benchmark_group("scalar_mul");
+    group.bench_function("cios", |bencher| {
+        bencher.iter(|| black_box(black_box(a) * black_box(b)))
+    });
+    group.bench_function("schoolbook_baseline", |bencher| {
+        bencher.iter(|| {
+            black_box(schoolbook::montgomery_mul(
+                black_box(a_limbs),
+                black_box(b_limbs),
+            ))
+        })
+    });
+    group.finish();
+}
+
+fn bench_scalar_square(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = ScalarField::random(&mut rng);
+
+    c.bench_function("scalar_mul_self", |bencher| {
+        bencher.iter(|| black_box(black_box(a) * black_box(a)))
+    });
+    c.bench_function("scalar_square", |bencher| {
+        bencher.iter(|| black_box(black_box(a).square()))
+    });
+}
+
+fn bench_scalar_batch_inverse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar_inverse");
+
+    for size in [64, 256].iter() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let elems: Vec<ScalarField> = (0..*size).map(|_| ScalarField::random(&mut rng)).collect();
+
+        group.bench_with_input(BenchmarkId::new("individual", size), size, |bencher, _| {
+            bencher.iter(|| {
+                let inverses: Vec<ScalarField> =
+                    black_box(&elems).iter().map(|e| e.inverse()).collect();
+                black_box(inverses)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("batch", size), size, |bencher, _| {
+            bencher.iter(|| {
+                let mut inverses = black_box(&elems).clone();
+                ScalarField::batch_inverse(&mut inverses);
+                black_box(inverses)
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_koalabear_mul,
+    bench_koalabear_ext8_mul,
+    bench_scalar_mul,
+    bench_scalar_square,
+    bench_scalar_batch_inverse
+);
 criterion_main!(benches);
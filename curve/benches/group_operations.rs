@@ -1,5 +1,5 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
-use curve::{Affine, Group, Projective, RandomField, ScalarField};
+use curve::{mul_generator_affine, Affine, Group, Projective, RandomField, ScalarField};
 use rand::rngs::StdRng;
 use rand::SeedableRng;
 
@@ -29,6 +29,17 @@ fn bench_affine_add(c: &mut Criterion) {
     });
 }
 
+fn bench_projective_double_in_place(c: &mut Criterion) {
+    let g = Projective::generator();
+    c.bench_function("projective_double_in_place", |bencher| {
+        bencher.iter(|| {
+            let mut p = black_box(g);
+            p.double_in_place();
+            black_box(p)
+        })
+    });
+}
+
 fn bench_projective_add(c: &mut Criterion) {
     let g = Projective::generator();
     let h = Projective::generator_pedersen();
@@ -37,6 +48,18 @@ fn bench_projective_add(c: &mut Criterion) {
     });
 }
 
+fn bench_projective_add_assign(c: &mut Criterion) {
+    let g = Projective::generator();
+    let h = Projective::generator_pedersen();
+    c.bench_function("projective_add_assign", |bencher| {
+        bencher.iter(|| {
+            let mut p = black_box(g);
+            p.add_assign_projective(black_box(&h));
+            black_box(p)
+        })
+    });
+}
+
 fn bench_affine_scalar_mul(c: &mut Criterion) {
     let g = Affine::generator();
     let mut rng = StdRng::seed_from_u64(42);
@@ -57,6 +80,31 @@ fn bench_projective_scalar_mul(c: &mut Criterion) {
     });
 }
 
+fn bench_scalar_mul_coordinates(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let scalar = random_scalar(&mut rng);
+    let mut group = c.benchmark_group("scalar_mul_coordinates");
+
+    // Both end in affine so the projective path pays its single final inversion
+    let g_affine = Affine::generator();
+    group.bench_function("affine", |bencher| {
+        bencher.iter(|| black_box(black_box(g_affine).scalar_mul(black_box(&scalar))))
+    });
+
+    let g_projective = Projective::generator();
+    group.bench_function("projective", |bencher| {
+        bencher.iter(|| {
+            black_box(
+                black_box(g_projective)
+                    .scalar_mul(black_box(&scalar))
+                    .to_affine(),
+            )
+        })
+    });
+
+    group.finish();
+}
+
 fn bench_affine_scalar_mul_windowed(c: &mut Criterion) {
     let g = Affine::generator();
     let mut rng = StdRng::seed_from_u64(42);
@@ -77,6 +125,32 @@ fn bench_projective_scalar_mul_windowed(c: &mut Criterion) {
     });
 }
 
+fn bench_double_scalar_mul_basepoint(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let a = random_scalar(&mut rng);
+    let b = random_scalar(&mut rng);
+    let p = Affine::generator().scalar_mul(&random_scalar(&mut rng));
+
+    // `a * G + b * P` through the shared doubling chain with the wNAF table for
+    // `P`, against the two products computed separately.
+    let mut group = c.benchmark_group("double_scalar_mul_basepoint");
+    group.bench_function("wnaf_table", |bencher| {
+        bencher.iter(|| {
+            black_box(Affine::double_scalar_mul_basepoint(
+                black_box(&a),
+                black_box(&b),
+                black_box(&p),
+            ))
+        })
+    });
+    group.bench_function("separate", |bencher| {
+        bencher.iter(|| {
+            black_box(Affine::mul_generator(black_box(&a)) + black_box(p).scalar_mul(black_box(&b)))
+        })
+    });
+    group.finish();
+}
+
 fn bench_affine_mul_generator(c: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(42);
     let scalar = random_scalar(&mut rng);
@@ -86,6 +160,26 @@ fn bench_affine_mul_generator(c: &mut Criterion) {
     });
 }
 
+fn bench_mul_generator_affine_cached(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let scalars: Vec<ScalarField> = (0..16).map(|_| random_scalar(&mut rng)).collect();
+    let mut group = c.benchmark_group("mul_generator_affine");
+
+    // With the table cached, 16 calls cost 16 times one call; rebuilding the
+    // 256-entry table per call would show up as a large constant on both.
+    group.bench_function("single", |bencher| {
+        bencher.iter(|| black_box(mul_generator_affine(black_box(&scalars[0]))))
+    });
+    group.bench_function("repeated_16", |bencher| {
+        bencher.iter(|| {
+            for scalar in &scalars {
+                black_box(mul_generator_affine(black_box(scalar)));
+            }
+        })
+    });
+    group.finish();
+}
+
 fn bench_projective_mul_generator(c: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(42);
     let scalar = random_scalar(&mut rng);
@@ -117,9 +211,17 @@ fn bench_affine_msm(c: &mut Criterion) {
         // Generate random scalars
         let scalars: Vec<ScalarField> = (0..*size).map(|_| random_scalar(&mut rng)).collect();
 
-        group.bench_with_input(BenchmarkId::from_parameter(size), size, |bencher, _| {
+        group.bench_with_input(BenchmarkId::new("naive", size), size, |bencher, _| {
+            bencher.iter(|| {
+                black_box(&points)
+                    .iter()
+                    .zip(black_box(&scalars).iter())
+                    .fold(Affine::INFINITY, |acc, (p, s)| acc + p.scalar_mul(s))
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("pippenger", size), size, |bencher, _| {
             bencher.iter(|| {
-                black_box(<Affine as Group>::multi_scalar_mul(
+                black_box(<Affine as Group>::msm_pippenger(
                     black_box(&points),
                     black_box(&scalars),
                 ))
@@ -163,19 +265,47 @@ fn bench_projective_msm(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_batch_normalize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_normalize");
+
+    for size in [16, 64, 256].iter() {
+        let g = Projective::generator();
+        let points: Vec<Projective> = (1..=*size).map(|i| g.mul_u64(i as u64)).collect();
+
+        group.bench_with_input(BenchmarkId::new("per_point", size), size, |bencher, _| {
+            bencher.iter(|| {
+                black_box(&points)
+                    .iter()
+                    .map(|p| p.to_affine())
+                    .collect::<Vec<Affine>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("batched", size), size, |bencher, _| {
+            bencher.iter(|| black_box(Projective::batch_normalize(black_box(&points))))
+        });
+    }
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_affine_double,
     bench_projective_double,
+    bench_projective_double_in_place,
     bench_affine_add,
     bench_projective_add,
+    bench_projective_add_assign,
     bench_affine_scalar_mul,
     bench_projective_scalar_mul,
+    bench_scalar_mul_coordinates,
     bench_affine_scalar_mul_windowed,
     bench_projective_scalar_mul_windowed,
+    bench_double_scalar_mul_basepoint,
     bench_affine_mul_generator,
+    bench_mul_generator_affine_cached,
     bench_projective_mul_generator,
     bench_affine_msm,
-    bench_projective_msm
+    bench_projective_msm,
+    bench_batch_normalize
 );
 criterion_main!(benches);
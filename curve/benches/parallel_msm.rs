@@ -0,0 +1,44 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use curve::{Affine, Group, RandomField, ScalarField};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+fn bench_msm_threads(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm_threads");
+    group.sample_size(10);
+
+    let size = 1024;
+    let mut rng = StdRng::seed_from_u64(12345);
+    let g = Affine::generator();
+    let points: Vec<Affine> = (0..size).map(|i| g.mul_u64(i as u64 + 1)).collect();
+    let scalars: Vec<ScalarField> = (0..size).map(|_| ScalarField::random(&mut rng)).collect();
+
+    let max_threads = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let thread_counts = [1, 2, 4, 8, 16]
+        .into_iter()
+        .filter(|&threads| threads <= max_threads);
+    for threads in thread_counts {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .expect("thread pool");
+        group.bench_with_input(
+            BenchmarkId::from_parameter(threads),
+            &threads,
+            |bencher, _| {
+                bencher.iter(|| {
+                    pool.install(|| {
+                        black_box(<Affine as Group>::multi_scalar_mul(
+                            black_box(&points),
+                            black_box(&scalars),
+                        ))
+                    })
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_msm_threads);
+criterion_main!(benches);
@@ -0,0 +1,37 @@
+//! Compile check for `curve` without its `std` feature.
+//!
+//! Not a workspace member, so `std` is not unified in by the other crates.
+//! Build for a target without `std` to confirm the crate stays `no_std`:
+//!
+//! ```text
+//! cargo build --manifest-path curve/no_std_check/Cargo.toml --target thumbv7em-none-eabihf
+//! ```
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use curve::{double_scalar_mul_basepoint_affine, Affine, Group, Projective, ScalarField};
+
+/// Exercises variable-base, fixed-base, double and multi-scalar multiplication
+/// and batch normalization, returning `a·G + b·H`.
+pub fn scalar_mul_smoke(a: u64, b: u64) -> Affine {
+    let a = ScalarField::from_canonical_u64(a);
+    let b = ScalarField::from_canonical_u64(b);
+    let g = Affine::generator();
+    let h = Affine::generator_pedersen();
+
+    let sum = g.scalar_mul(&a) + h.scalar_mul_windowed(&b);
+    let double = double_scalar_mul_basepoint_affine(&a, &b, &h);
+    let msm = Affine::multi_scalar_mul(&[g, h], &[a, b]);
+
+    let projective: Vec<Projective> = [sum, double, msm, Affine::mul_generator(&a)]
+        .iter()
+        .map(Projective::from)
+        .collect();
+    let normalized = Projective::batch_normalize(&projective);
+    debug_assert!(normalized[..3].iter().all(|p| *p == normalized[0]));
+
+    normalized[0]
+}
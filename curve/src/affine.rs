@@ -9,16 +9,32 @@
 // Curve embedding degree: 13275135371624374170499121716818749745493763186509316010881571947667737196 (>2^242)
 // Twist security (Pollard-Rho): 120.86
 
-use crate::basefield::{from_coeffs, BaseField};
-use crate::{double_scalar_mul_basepoint_affine, mul_generator_affine, Group, ScalarField};
+use crate::basefield::{
+    from_canonical_bytes, from_coeffs, sgn0, sqrt, to_canonical_bytes, to_coeffs, BaseField,
+};
+use crate::{
+    double_scalar_mul_basepoint_affine, mul_generator_affine, Group, Projective, ScalarBits,
+    ScalarField,
+};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::Sum;
 use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
-use p3_field::{Field, PrimeCharacteristicRing};
+use p3_field::{Field, PrimeCharacteristicRing, PrimeField32};
 use p3_koala_bear::KoalaBear;
 use serde::{Deserialize, Serialize};
 
+/// Flag byte marking the point at infinity in a compressed encoding.
+const COMPRESSED_INFINITY: u8 = 0x02;
+
 /// Affine point on the elliptic curve.
 /// Represents a point in affine coordinates (x, y) or the point at infinity.
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Equality ignores the coordinates of the point at infinity, so an infinity
+/// value with leftover coordinates still equals [`Affine::INFINITY`].
+/// Deserializing an infinity point zeroes its coordinates.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+#[serde(from = "RawAffine")]
 pub struct Affine {
     /// The x-coordinate of the point (Fp8 element)
     pub x: BaseField,
@@ -28,6 +44,37 @@ pub struct Affine {
     pub is_infinity: bool,
 }
 
+/// Serialized form of [`Affine`], normalized on the way in.
+#[derive(Deserialize)]
+#[serde(rename = "Affine")]
+struct RawAffine {
+    x: BaseField,
+    y: BaseField,
+    is_infinity: bool,
+}
+
+impl From<RawAffine> for Affine {
+    fn from(raw: RawAffine) -> Self {
+        if raw.is_infinity {
+            Affine::INFINITY
+        } else {
+            Affine::new(raw.x, raw.y)
+        }
+    }
+}
+
+impl PartialEq for Affine {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_infinity, other.is_infinity) {
+            (true, true) => true,
+            (false, false) => self.x == other.x && self.y == other.y,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Affine {}
+
 impl Affine {
     // Curve parameters: y^2 = x^3 + a*x + b
     // a = 3*u where u is the primitive element of the extension
@@ -35,21 +82,14 @@ impl Affine {
 
     /// Get the 'a' coefficient: 3*u
     #[inline]
-    fn curve_a() -> BaseField {
-        // Create 3*u: coefficients [0, 3, 0, 0, 0, 0, 0, 0]
-        // The second component (index 1) represents u^1
-        let zero = KoalaBear::ZERO;
-        let three = KoalaBear::new(3);
-        from_coeffs([zero, three, zero, zero, zero, zero, zero, zero])
+    pub(crate) fn curve_a() -> BaseField {
+        crate::CURVE_A()
     }
 
     /// Get the 'b' coefficient: 42639
     #[inline]
-    fn curve_b() -> BaseField {
-        // Create constant 42639 in the extension field
-        let zero = KoalaBear::ZERO;
-        let b = KoalaBear::new(42639);
-        from_coeffs([b, zero, zero, zero, zero, zero, zero, zero])
+    pub(crate) fn curve_b() -> BaseField {
+        crate::CURVE_B()
     }
 
     /// The point at infinity (identity element)
@@ -68,6 +108,12 @@ impl Affine {
         }
     }
 
+    /// Create an affine point, returning `None` if `(x, y)` is not on the curve.
+    pub fn from_coords_checked(x: BaseField, y: BaseField) -> Option<Self> {
+        let point = Affine::new(x, y);
+        point.is_on_curve().then_some(point)
+    }
+
     /// Check if this point is the point at infinity.
     #[inline]
     pub fn is_infinity(&self) -> bool {
@@ -89,6 +135,36 @@ impl Affine {
         y2 == rhs
     }
 
+    /// Check that this is a non-identity point of the prime-order group.
+    ///
+    /// The curve has cofactor 1, so every point on the curve already lies in
+    /// the prime-order group and no scalar multiplication by the order is
+    /// needed; this reduces to `is_on_curve() && !is_infinity()`. The identity
+    /// is excluded because it is never a valid public key or commitment.
+    pub fn is_in_subgroup(&self) -> bool {
+        !self.is_infinity && self.is_on_curve()
+    }
+
+    /// Check whether this point has order dividing the cofactor, i.e. whether
+    /// clearing the cofactor sends it to infinity.
+    ///
+    /// With cofactor 1 only the point at infinity has small order. The check is
+    /// kept explicit so that code validating adversarial inputs reads the same as
+    /// it would on a curve with a nontrivial cofactor.
+    pub fn is_small_order(&self) -> bool {
+        self.clear_cofactor().is_infinity()
+    }
+
+    /// Multiply by the cofactor, mapping any curve point into the prime-order
+    /// group.
+    ///
+    /// The cofactor is 1, so this returns `self` unchanged; it exists so generic
+    /// code written for curves with a cofactor compiles uniformly here.
+    #[inline]
+    pub fn clear_cofactor(&self) -> Self {
+        *self
+    }
+
     /// Generator point from SSWU on 'ZKM2'.
     pub fn generator() -> Self {
         // (1195559694*u^7 + 1368232771*u^6 + 438909494*u^5 + 1825476283*u^4 +
@@ -189,6 +265,193 @@ impl Affine {
         Affine::new(self.x, -self.y)
     }
 
+    /// Whether y has odd parity (`sgn0`), the flag bit of [`Affine::to_compressed`].
+    ///
+    /// Exactly one of `P` and `-P` is odd unless `y = 0`. The point at infinity
+    /// is even.
+    pub fn has_odd_y(&self) -> bool {
+        !self.is_infinity && sgn0(self.y)
+    }
+
+    /// Compress to 33 bytes: the canonical x-coordinate followed by a flag byte.
+    ///
+    /// The flag byte is `0x00`/`0x01` for the parity (`sgn0`) of y, or `0x02`
+    /// for the identity, in which case x is all zeros.
+    pub fn to_compressed(&self) -> [u8; 33] {
+        let mut bytes = [0u8; 33];
+        if self.is_infinity {
+            bytes[32] = COMPRESSED_INFINITY;
+            return bytes;
+        }
+        bytes[..32].copy_from_slice(&to_canonical_bytes(self.x));
+        bytes[32] = sgn0(self.y) as u8;
+        bytes
+    }
+
+    /// Decompress a point produced by [`Affine::to_compressed`].
+    ///
+    /// Recovers y from the curve equation, which requires an Fp8 square root.
+    /// Returns `None` for non-canonical encodings and x-coordinates not on the curve.
+    pub fn from_compressed(bytes: &[u8; 33]) -> Option<Self> {
+        let x_bytes: &[u8; 32] = bytes[..32].try_into().expect("33-byte encoding");
+        let flag = bytes[32];
+
+        if flag == COMPRESSED_INFINITY {
+            return x_bytes.iter().all(|&b| b == 0).then_some(Self::INFINITY);
+        }
+        if flag > 1 {
+            return None;
+        }
+
+        let x = from_canonical_bytes(x_bytes)?;
+        let rhs = x * x * x + Self::curve_a() * x + Self::curve_b();
+        let mut y = sqrt(rhs)?;
+        if sgn0(y) != (flag == 1) {
+            y = -y;
+        }
+
+        let point = Affine::new(x, y);
+        debug_assert!(point.is_on_curve());
+        Some(point)
+    }
+
+    /// Return `a` if `choice` is false and `b` if it is true, without branching.
+    ///
+    /// Every coefficient of both coordinates is masked. The `is_infinity` flag
+    /// is selected the same way: a `choice`-dependent branch on it alone would
+    /// leak the selection just as well as branching on the coordinates.
+    pub fn conditional_select(a: &Self, b: &Self, choice: bool) -> Self {
+        select_affine_ct(a, b, (choice as u32).wrapping_neg())
+    }
+
+    /// Replace `self` with `other` if `choice` is true, without branching.
+    ///
+    /// See [`Affine::conditional_select`].
+    pub fn conditional_assign(&mut self, other: &Self, choice: bool) {
+        *self = Self::conditional_select(self, other, choice);
+    }
+
+    /// Equality without branching on the coordinates or infinity flags.
+    ///
+    /// Agrees with `==`: all infinity values are equal whatever their leftover
+    /// coordinates, and finite points compare both coordinates. Every
+    /// coefficient is read, so the time does not reveal where two secret
+    /// points differ.
+    ///
+    /// Returns a `bool`, not a `subtle::Choice`: the comparison itself does not
+    /// branch, but the compiler may branch on the result wherever it is used.
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let (a_inf, b_inf) = (self.is_infinity as u32, other.is_infinity as u32);
+        let coords = base_eq_ct(self.x, other.x) & base_eq_ct(self.y, other.y);
+        ((a_inf & b_inf) | (((a_inf | b_inf) ^ 1) & coords)) != 0
+    }
+
+    /// Constant-time variable-base scalar multiplication.
+    ///
+    /// Recodes the scalar into 64 odd signed 4-bit digits in `[-15, 15]`, so every
+    /// window does four doublings and one addition of a non-identity point.
+    /// Table lookups scan all eight entries of `[P, 3P, ..., 15P]` and signs are
+    /// applied with masked selects; an even scalar `k` is computed as
+    /// `-((p - k) · P)`.
+    ///
+    /// The affine formulas are not complete, so an identity base and a zero
+    /// scalar (whose last addition cancels to the identity) still take
+    /// exceptional branches. Otherwise nothing branches on the scalar.
+    pub fn scalar_mul_ct(&self, scalar: &ScalarField) -> Self {
+        if self.is_infinity {
+            return Self::INFINITY;
+        }
+
+        // table[i] = (2i + 1) * self
+        let mut table = [*self; 8];
+        let double = self.double();
+        for i in 1..table.len() {
+            table[i] = table[i - 1] + double;
+        }
+
+        let (mut k, negate_result) = scalar.to_odd_limbs_ct();
+        let mut digits = [0i8; 64];
+        for digit in digits.iter_mut().take(63) {
+            // k is odd, so (k mod 32) - 16 is odd and k - digit = (k & !31) | 16.
+            *digit = (k[0] & 0x1f) as i8 - 16;
+            k[0] = (k[0] & !0x1f) | 0x10;
+            for i in 0..3 {
+                k[i] = (k[i] >> 4) | (k[i + 1] << 60);
+            }
+            k[3] >>= 4;
+        }
+        digits[63] = k[0] as i8;
+
+        let mut result = select_odd_multiple_ct(&table, digits[63]);
+        for &digit in digits[..63].iter().rev() {
+            for _ in 0..4 {
+                result = result.double();
+            }
+            result += select_odd_multiple_ct(&table, digit);
+        }
+
+        let mask = (negate_result as u32).wrapping_neg();
+        result.y = select_base_ct(result.y, -result.y, mask);
+        result
+    }
+
+    // GLV: not applicable because the curve has no cheap non-trivial endomorphism.
+    // a = 3u and b = 42639 are both non-zero, so j is neither 0 nor 1728 and the
+    // only automorphisms are ±1. The p-power Frobenius sends a = 3u to 3u^p != a
+    // (u generates Fp8 over Fp), so it maps E to a conjugate curve rather than to
+    // itself; the first power that fixes E is p^8, which is the identity on
+    // E(Fp8). Any remaining CM endomorphism has large degree.
+
+    /// Variable-base scalar multiplication over signed 2-bit digits.
+    ///
+    /// Recodes the scalar in radix 4 with digits in `{-1, 0, 1, 2}` (a digit of 3
+    /// becomes `-1` with a carry), so each step does two doublings and at most
+    /// one addition from the table `[P, 2P]`. Stands in for a GLV split, which
+    /// this curve does not admit. Not constant time.
+    pub fn scalar_mul_radix4(&self, scalar: &ScalarField) -> Self {
+        let limbs = scalar.to_u64_limbs();
+        // One extra limb absorbs the carry from negative digits.
+        let mut k = [limbs[0], limbs[1], limbs[2], limbs[3], 0];
+        let mut digits = Vec::with_capacity(129);
+        while k.iter().any(|&limb| limb != 0) {
+            let digit = match k[0] & 3 {
+                3 => {
+                    let mut carry = 1;
+                    for limb in k.iter_mut() {
+                        let (sum, overflow) = limb.overflowing_add(carry);
+                        *limb = sum;
+                        carry = overflow as u64;
+                    }
+                    -1
+                }
+                d => {
+                    k[0] -= d;
+                    d as i8
+                }
+            };
+            digits.push(digit);
+
+            for i in 0..4 {
+                k[i] = (k[i] >> 2) | (k[i + 1] << 62);
+            }
+            k[4] >>= 2;
+        }
+
+        let double = self.double();
+        let mut result = Self::INFINITY;
+        for &digit in digits.iter().rev() {
+            result = result.double().double();
+            match digit {
+                1 => result += *self,
+                2 => result += double,
+                -1 => result -= *self,
+                _ => {}
+            }
+        }
+
+        result
+    }
+
     /// Multiply the fixed generator using a precomputed table.
     pub fn mul_generator(scalar: &ScalarField) -> Self {
         mul_generator_affine(scalar)
@@ -200,6 +463,54 @@ impl Affine {
     }
 }
 
+/// Return `digit · P` from `table = [P, 3P, ..., 15P]` for an odd `digit` in
+/// `[-15, 15]`, reading every entry.
+fn select_odd_multiple_ct(table: &[Affine; 8], digit: i8) -> Affine {
+    let sign = (digit >> 7) as u8;
+    let abs = ((digit as u8) ^ sign).wrapping_sub(sign);
+    let index = (abs >> 1) as u32;
+
+    let mut selected = table[0];
+    for (i, entry) in table.iter().enumerate().skip(1) {
+        let diff = i as u32 ^ index;
+        let mask = (!(diff | diff.wrapping_neg()) >> 31).wrapping_neg();
+        selected = select_affine_ct(&selected, entry, mask);
+    }
+
+    let sign_mask = (sign as u32 & 1).wrapping_neg();
+    selected.y = select_base_ct(selected.y, -selected.y, sign_mask);
+    selected
+}
+
+/// Select `b` if `mask` is all ones and `a` if it is zero, including the
+/// infinity flag, without branching.
+fn select_affine_ct(a: &Affine, b: &Affine, mask: u32) -> Affine {
+    let (a_inf, b_inf) = (a.is_infinity as u32, b.is_infinity as u32);
+    Affine {
+        x: select_base_ct(a.x, b.x, mask),
+        y: select_base_ct(a.y, b.y, mask),
+        is_infinity: (a_inf ^ (mask & (a_inf ^ b_inf))) != 0,
+    }
+}
+
+/// 1 if `a == b` and 0 otherwise, reading every coefficient.
+fn base_eq_ct(a: BaseField, b: BaseField) -> u32 {
+    let (a, b) = (to_coeffs(a), to_coeffs(b));
+    let diff = a.iter().zip(&b).fold(0, |acc, (x, y)| {
+        acc | (x.as_canonical_u32() ^ y.as_canonical_u32())
+    });
+    ((diff | diff.wrapping_neg()) >> 31) ^ 1
+}
+
+/// Select `b` if `mask` is all ones and `a` if it is zero, without branching.
+fn select_base_ct(a: BaseField, b: BaseField, mask: u32) -> BaseField {
+    let (a, b) = (to_coeffs(a), to_coeffs(b));
+    from_coeffs(core::array::from_fn(|i| {
+        let (x, y) = (a[i].as_canonical_u32(), b[i].as_canonical_u32());
+        KoalaBear::new(x ^ (mask & (x ^ y)))
+    }))
+}
+
 impl Group for Affine {
     type Scalar = ScalarField;
 
@@ -232,6 +543,11 @@ impl Group for Affine {
     fn negate(&self) -> Self {
         Self::negate(self)
     }
+
+    #[cfg(feature = "parallel")]
+    fn msm_pippenger(points: &[Self], scalars: &[ScalarField]) -> Self {
+        crate::group::msm_pippenger_parallel(points, scalars)
+    }
 }
 
 // Implement addition for affine points
@@ -304,6 +620,45 @@ impl Neg for Affine {
     }
 }
 
+/// Sum of the points, [`Affine::INFINITY`] for an empty iterator.
+///
+/// Accumulates in projective coordinates, so the whole sum costs one inversion
+/// rather than one per addition.
+impl Sum for Affine {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Projective::INFINITY, |acc, p| acc + Projective::from(p))
+            .to_affine()
+    }
+}
+
+impl<'a> Sum<&'a Affine> for Affine {
+    fn sum<I: Iterator<Item = &'a Affine>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Canonical total order: the point at infinity first, then finite points
+/// ordered lexicographically by [`Affine::to_compressed`].
+///
+/// Compressed bytes determine a finite point and every infinity value is equal,
+/// so this agrees with `Eq`.
+impl Ord for Affine {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.is_infinity, other.is_infinity) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self.to_compressed().cmp(&other.to_compressed()),
+            (true, true) => Ordering::Equal,
+        }
+    }
+}
+
+impl PartialOrd for Affine {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 // Scalar multiplication
 impl Mul<ScalarField> for Affine {
     type Output = Self;
@@ -366,6 +721,34 @@ mod tests {
         assert!(!g.is_infinity());
     }
 
+    #[test]
+    fn test_from_coords_checked() {
+        let g = Affine::generator();
+        assert_eq!(Affine::from_coords_checked(g.x, g.y), Some(g));
+        assert_eq!(Affine::from_coords_checked(g.x, g.y + BaseField::ONE), None);
+        assert_eq!(Affine::from_coords_checked(g.x + BaseField::ONE, g.y), None);
+    }
+
+    #[test]
+    fn test_is_in_subgroup() {
+        let g = Affine::generator();
+        assert!(g.is_in_subgroup());
+        assert!(g.mul_u64(7).is_in_subgroup());
+        assert!(!Affine::INFINITY.is_in_subgroup());
+        assert!(!Affine::new(g.x, g.y + BaseField::ONE).is_in_subgroup());
+    }
+
+    #[test]
+    fn test_small_order_and_clear_cofactor() {
+        let g = Affine::generator();
+        assert!(!g.is_small_order());
+        assert!(!g.mul_u64(7).is_small_order());
+        assert!(Affine::INFINITY.is_small_order());
+
+        assert_eq!(g.clear_cofactor(), g);
+        assert_eq!(Affine::INFINITY.clear_cofactor(), Affine::INFINITY);
+    }
+
     #[test]
     fn test_point_addition_with_infinity() {
         let g = Affine::generator();
@@ -413,6 +796,11 @@ mod tests {
         let result = g.scalar_mul(&zero);
 
         assert_eq!(result, Affine::INFINITY);
+
+        // Early returns agree with the looped paths
+        assert_eq!(g.scalar_mul_windowed(&zero), result);
+        assert_eq!(g.scalar_mul_wnaf(&zero), result);
+        assert_eq!(g.mul_u64(0), result);
     }
 
     #[test]
@@ -422,6 +810,12 @@ mod tests {
         let result = g.scalar_mul(&one);
 
         assert_eq!(result, g);
+
+        // Early returns agree with the looped paths
+        assert_eq!(g.scalar_mul_windowed(&one), result);
+        assert_eq!(g.scalar_mul_wnaf(&one), result);
+        assert_eq!(g.mul_u64(1), result);
+        assert_eq!(g.scalar_mul_windowed(&(one + one)), g.mul_u64(2));
     }
 
     #[test]
@@ -499,6 +893,26 @@ mod tests {
         assert!(result1.is_on_curve());
     }
 
+    #[test]
+    fn test_sum() {
+        let g = Affine::generator();
+        assert_eq!([g, g, g].iter().copied().sum::<Affine>(), g.mul_u64(3));
+        assert_eq!([g, g, g].iter().sum::<Affine>(), g.mul_u64(3));
+        assert_eq!(
+            core::iter::empty::<Affine>().sum::<Affine>(),
+            Affine::INFINITY
+        );
+
+        // Infinity is skipped, and opposite points cancel
+        let h = g.mul_u64(5);
+        assert_eq!(
+            [Affine::INFINITY, h, g].iter().sum::<Affine>(),
+            g.mul_u64(6)
+        );
+        assert_eq!([g, h, -g].iter().sum::<Affine>(), h);
+        assert_eq!([h, -h].iter().sum::<Affine>(), Affine::INFINITY);
+    }
+
     #[test]
     fn test_identity() {
         let id = <Affine as Group>::identity();
@@ -527,4 +941,291 @@ mod tests {
         let neg_h = -h;
         assert_eq!(h + neg_h, Affine::INFINITY);
     }
+
+    #[test]
+    fn test_compression_roundtrip() {
+        let g = Affine::generator();
+        for k in 1..20u64 {
+            let p = g.mul_u64(k);
+            let compressed = p.to_compressed();
+            assert_eq!(Affine::from_compressed(&compressed), Some(p));
+
+            let neg = p.negate();
+            let neg_compressed = neg.to_compressed();
+            assert_ne!(compressed[32], neg_compressed[32]);
+            assert_eq!(p.has_odd_y(), compressed[32] == 1);
+            assert_ne!(p.has_odd_y(), neg.has_odd_y());
+            assert_eq!(Affine::from_compressed(&neg_compressed), Some(neg));
+        }
+
+        assert!(!Affine::INFINITY.has_odd_y());
+        let inf = Affine::INFINITY.to_compressed();
+        assert_eq!(Affine::from_compressed(&inf), Some(Affine::INFINITY));
+    }
+
+    #[test]
+    fn test_from_compressed_rejects_invalid() {
+        let mut bytes = Affine::generator().to_compressed();
+        bytes[32] = 0x03;
+        assert_eq!(Affine::from_compressed(&bytes), None);
+
+        // Non-canonical coefficient
+        let mut bytes = Affine::generator().to_compressed();
+        bytes[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(Affine::from_compressed(&bytes), None);
+
+        // Infinity flag with a non-zero x
+        let mut bytes = Affine::INFINITY.to_compressed();
+        bytes[0] = 1;
+        assert_eq!(Affine::from_compressed(&bytes), None);
+
+        // x = 3 gives a quadratic non-residue on the right-hand side
+        let mut bytes = [0u8; 33];
+        bytes[0] = 3;
+        assert_eq!(Affine::from_compressed(&bytes), None);
+    }
+
+    #[test]
+    fn test_ord_is_canonical() {
+        let g = Affine::generator();
+        let mut points: Vec<Affine> = (1..=16u64)
+            .map(|k| g.mul_u64(k))
+            .chain([Affine::INFINITY, -g, g, Affine::INFINITY])
+            .collect();
+        let mut reversed = points.clone();
+        reversed.reverse();
+
+        points.sort();
+        reversed.sort();
+        assert_eq!(points, reversed);
+        assert_eq!(points[0], Affine::INFINITY);
+        assert_eq!(points[1], Affine::INFINITY);
+        for pair in points.windows(2) {
+            assert!(pair[0].to_compressed() <= pair[1].to_compressed() || pair[0].is_infinity);
+            assert_eq!(pair[0] == pair[1], pair[0].cmp(&pair[1]) == Ordering::Equal);
+        }
+
+        assert_eq!(g.cmp(&(g + Affine::INFINITY)), Ordering::Equal);
+        assert_eq!(g.partial_cmp(&g.double()), Some(g.cmp(&g.double())));
+        assert_ne!(g.cmp(&-g), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_infinity_equality_ignores_coordinates() {
+        let g = Affine::generator();
+        let junk = Affine {
+            x: g.x,
+            y: g.y,
+            is_infinity: true,
+        };
+        assert_eq!(junk, Affine::INFINITY);
+        assert_eq!(junk.cmp(&Affine::INFINITY), Ordering::Equal);
+        assert_ne!(junk, g);
+        assert_ne!(g, junk);
+        assert_eq!(junk + g, g);
+
+        // Deserialization zeroes the coordinates of an infinity point
+        let decoded = Affine::from(RawAffine {
+            x: g.x,
+            y: g.y,
+            is_infinity: true,
+        });
+        assert_eq!(decoded.x, BaseField::ZERO);
+        assert_eq!(decoded.y, BaseField::ZERO);
+        assert!(decoded.is_infinity);
+        let decoded = Affine::from(RawAffine {
+            x: g.x,
+            y: g.y,
+            is_infinity: false,
+        });
+        assert_eq!(decoded, g);
+    }
+
+    #[test]
+    fn test_msm_pippenger_matches_naive() {
+        use crate::{RandomField, ScalarField};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(21);
+        for n in [1, 2, 31, 32, 64] {
+            let points: Vec<Affine> = (0..n)
+                .map(|_| Affine::generator().scalar_mul(&ScalarField::random(&mut rng)))
+                .collect();
+            let scalars: Vec<ScalarField> = (0..n).map(|_| ScalarField::random(&mut rng)).collect();
+
+            let naive = points
+                .iter()
+                .zip(scalars.iter())
+                .fold(Affine::INFINITY, |acc, (p, s)| acc + p.scalar_mul(s));
+            assert_eq!(Affine::msm_pippenger(&points, &scalars), naive, "n = {n}");
+            assert_eq!(
+                Affine::multi_scalar_mul(&points, &scalars),
+                naive,
+                "n = {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_ct_matches_scalar_mul() {
+        use crate::{RandomField, ScalarField};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(23);
+        let base = Affine::generator().scalar_mul(&ScalarField::random(&mut rng));
+        let mut scalars = vec![
+            ScalarField::ZERO,
+            ScalarField::ONE,
+            ScalarField::from_canonical_u64(2),
+            ScalarField::from_canonical_u64(16),
+            -ScalarField::ONE,
+            -ScalarField::from_canonical_u64(2),
+        ];
+        scalars.extend((0..16).map(|_| ScalarField::random(&mut rng)));
+
+        for s in scalars {
+            assert_eq!(base.scalar_mul_ct(&s), base.scalar_mul(&s));
+        }
+        assert_eq!(
+            Affine::INFINITY.scalar_mul_ct(&ScalarField::ONE),
+            Affine::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_conditional_select() {
+        let g = Affine::generator();
+        let h = Affine::generator_pedersen();
+
+        assert_eq!(Affine::conditional_select(&g, &h, false), g);
+        assert_eq!(Affine::conditional_select(&g, &h, true), h);
+
+        // The infinity flag follows the choice in both directions
+        let selected = Affine::conditional_select(&g, &Affine::INFINITY, true);
+        assert!(selected.is_infinity);
+        let selected = Affine::conditional_select(&Affine::INFINITY, &g, true);
+        assert!(!selected.is_infinity);
+        assert_eq!(selected, g);
+        assert!(Affine::conditional_select(&Affine::INFINITY, &g, false).is_infinity);
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let g = Affine::generator();
+        let h = Affine::generator_pedersen();
+        let leftover = Affine {
+            is_infinity: true,
+            ..g
+        };
+
+        let pairs = [
+            (g, g),
+            (g, h),
+            (g, -g),
+            (g, g.double()),
+            (Affine::INFINITY, Affine::INFINITY),
+            (Affine::INFINITY, leftover),
+            (Affine::INFINITY, g),
+            (g, leftover),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(a.ct_eq(&b), a == b, "{a:?} vs {b:?}");
+            assert_eq!(b.ct_eq(&a), a == b, "{b:?} vs {a:?}");
+        }
+    }
+
+    #[test]
+    fn test_conditional_assign() {
+        let g = Affine::generator();
+        let h = Affine::generator_pedersen();
+
+        let mut point = g;
+        point.conditional_assign(&h, false);
+        assert_eq!(point, g);
+        point.conditional_assign(&h, true);
+        assert_eq!(point, h);
+        point.conditional_assign(&Affine::INFINITY, true);
+        assert!(point.is_infinity);
+    }
+
+    #[test]
+    fn test_scalar_mul_wnaf_matches_scalar_mul() {
+        use crate::{RandomField, ScalarField};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(24);
+        let base = Affine::generator_pedersen();
+        let mut scalars = vec![
+            ScalarField::ZERO,
+            ScalarField::ONE,
+            ScalarField::from_canonical_u64(31),
+            -ScalarField::ONE,
+        ];
+        scalars.extend((0..16).map(|_| ScalarField::random(&mut rng)));
+
+        for s in scalars {
+            assert_eq!(base.scalar_mul_wnaf(&s), base.scalar_mul(&s));
+        }
+    }
+
+    #[test]
+    fn test_scalar_mul_radix4_matches_scalar_mul() {
+        use crate::{RandomField, ScalarField};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(37);
+        let base = Affine::generator();
+        let mut scalars = vec![
+            ScalarField::ZERO,
+            ScalarField::ONE,
+            ScalarField::from_canonical_u64(3),
+            ScalarField::from_canonical_u64(0xff),
+            -ScalarField::ONE,
+        ];
+        scalars.extend((0..16).map(|_| ScalarField::random(&mut rng)));
+
+        for s in scalars {
+            assert_eq!(base.scalar_mul_radix4(&s), base.scalar_mul(&s));
+        }
+        assert_eq!(
+            Affine::INFINITY.scalar_mul_radix4(&ScalarField::ONE),
+            Affine::INFINITY
+        );
+    }
+
+    #[test]
+    fn test_scalar_mul_skips_leading_zero_bits() {
+        use crate::{RandomField, ScalarField};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let base = Affine::generator_pedersen();
+        let cases = [
+            (ScalarField::ONE, 1),
+            (ScalarField::from_canonical_u64(u64::MAX), 64),
+            (ScalarField::from_canonical_u64(1 << 63), 64),
+            (-ScalarField::ONE, 248),
+        ];
+
+        for (s, bits) in cases {
+            assert_eq!(s.bit_length(), bits);
+            let expected = base.scalar_mul_ct(&s);
+            assert_eq!(base.scalar_mul(&s), expected);
+            assert_eq!(base.scalar_mul_windowed(&s), expected);
+        }
+        assert_eq!(base.scalar_mul(&ScalarField::ONE), base);
+        assert_eq!(base.mul_u64(u64::MAX), base.scalar_mul(&cases[1].0));
+
+        for _ in 0..8 {
+            let s = ScalarField::random(&mut rng);
+            let expected = base.scalar_mul_ct(&s);
+            assert_eq!(base.scalar_mul(&s), expected);
+            assert_eq!(base.scalar_mul_windowed(&s), expected);
+        }
+    }
 }
@@ -1,14 +1,23 @@
+use alloc::vec::Vec;
 use p3_field::extension::BinomialExtensionField;
-use p3_field::RawDataSerializable;
+use p3_field::{BasedVectorSpace, PrimeCharacteristicRing, PrimeField32, RawDataSerializable};
 use p3_koala_bear::KoalaBear;
 
 /// KoalaBear degree-8 extension field.
 pub type BaseField = BinomialExtensionField<KoalaBear, 8>;
 
-/// Helper function to construct a BaseField from its coefficients.
+/// Construct a BaseField from its coefficients, `u^0` first.
 #[inline]
 pub fn from_coeffs(coeffs: [KoalaBear; 8]) -> BaseField {
-    unsafe { core::mem::transmute(coeffs) }
+    BaseField::from_basis_coefficients_fn(|i| coeffs[i])
+}
+
+/// The coefficients of a BaseField element, `u^0` first. Inverse of [`from_coeffs`].
+#[inline]
+pub fn to_coeffs(elem: BaseField) -> [KoalaBear; 8] {
+    elem.as_basis_coefficients_slice()
+        .try_into()
+        .expect("degree-8 extension")
 }
 
 /// Serialize a BaseField element using the Plonky3 raw-data format.
@@ -22,3 +31,184 @@ pub fn to_u32s(elem: BaseField) -> [u32; <BaseField as RawDataSerializable>::NUM
     let words: Vec<u32> = BaseField::into_u32_stream([elem]).into_iter().collect();
     words.try_into().expect("basefield u32 length")
 }
+
+/// 2-adicity of the multiplicative group: `p^8 - 1 = 2^27 * t` with `t` odd.
+const TWO_ADICITY: u32 = 27;
+
+/// `(t - 1) / 2` as little-endian u64 limbs.
+const T_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0x653c806e41c0003f,
+    0xae174900568744cd,
+    0x82c2aa440f5f26a5,
+    0x000000000f06e446,
+];
+
+/// `u^t`, a primitive 2^27-th root of unity (`u` is a quadratic non-residue).
+#[inline]
+fn root_of_unity() -> BaseField {
+    let zero = KoalaBear::ZERO;
+    from_coeffs([
+        zero,
+        zero,
+        zero,
+        zero,
+        zero,
+        zero,
+        zero,
+        KoalaBear::new(2104826505),
+    ])
+}
+
+/// Variable-time exponentiation by a little-endian u64 limb exponent.
+fn pow_vartime(elem: BaseField, exp: [u64; 4]) -> BaseField {
+    let mut result = BaseField::ONE;
+    let mut base = elem;
+
+    for &limb in exp.iter() {
+        let mut remaining = limb;
+        for _ in 0..64 {
+            if remaining & 1 == 1 {
+                result *= base;
+            }
+            base = base.square();
+            remaining >>= 1;
+        }
+    }
+
+    result
+}
+
+/// Square root in Fp8 (Tonelli-Shanks), returning `None` for non-residues.
+///
+/// Either root may be returned; callers that need a specific one should
+/// select it by sign. Point decompression (`Affine::from_compressed`) uses
+/// this to recover `y` from `x`. Not constant time.
+pub fn sqrt(elem: BaseField) -> Option<BaseField> {
+    if elem == BaseField::ZERO {
+        return Some(BaseField::ZERO);
+    }
+
+    // x = a^{(t+1)/2}, b = a^t
+    let w = pow_vartime(elem, T_MINUS_ONE_DIV_TWO);
+    let mut x = elem * w;
+    let mut b = x * w;
+    let mut z = root_of_unity();
+    let mut v = TWO_ADICITY;
+
+    while b != BaseField::ONE {
+        // Find the least k with b^(2^k) = 1
+        let mut k = 0;
+        let mut b2k = b;
+        while b2k != BaseField::ONE {
+            b2k = b2k.square();
+            k += 1;
+            if k == v {
+                return None;
+            }
+        }
+
+        let mut w = z;
+        for _ in 0..(v - k - 1) {
+            w = w.square();
+        }
+        z = w.square();
+        b *= z;
+        x *= w;
+        v = k;
+    }
+
+    Some(x)
+}
+
+/// Parity of the first non-zero canonical coefficient (`sgn0`), starting at `u^0`.
+pub(crate) fn sgn0(elem: BaseField) -> bool {
+    elem.as_basis_coefficients_slice()
+        .iter()
+        .map(|c| c.as_canonical_u32())
+        .find(|&c| c != 0)
+        .is_some_and(|c| c & 1 == 1)
+}
+
+/// Encode a BaseField element as its canonical coefficients in little-endian order.
+pub fn to_canonical_bytes(elem: BaseField) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (chunk, coeff) in bytes
+        .chunks_exact_mut(4)
+        .zip(elem.as_basis_coefficients_slice())
+    {
+        chunk.copy_from_slice(&coeff.as_canonical_u32().to_le_bytes());
+    }
+    bytes
+}
+
+/// Decode a BaseField element, rejecting coefficients that are not fully reduced.
+pub fn from_canonical_bytes(bytes: &[u8; 32]) -> Option<BaseField> {
+    let mut coeffs = [KoalaBear::ZERO; 8];
+    for (coeff, chunk) in coeffs.iter_mut().zip(bytes.chunks_exact(4)) {
+        let value = u32::from_le_bytes(chunk.try_into().expect("4-byte chunk"));
+        if value >= KoalaBear::ORDER_U32 {
+            return None;
+        }
+        *coeff = KoalaBear::new(value);
+    }
+    Some(from_coeffs(coeffs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RandomField;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_sqrt_of_squares() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..32 {
+            let a = BaseField::random(&mut rng);
+            let root = sqrt(a.square()).expect("square has a root");
+            assert_eq!(root.square(), a.square());
+            assert!(root == a || root == -a);
+        }
+    }
+
+    #[test]
+    fn test_coeffs_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(53);
+        for _ in 0..32 {
+            let a = BaseField::random(&mut rng);
+            assert_eq!(from_coeffs(to_coeffs(a)), a);
+        }
+
+        let coeffs = core::array::from_fn(|i| KoalaBear::new(i as u32 + 1));
+        assert_eq!(to_coeffs(from_coeffs(coeffs)), coeffs);
+        assert_eq!(to_coeffs(BaseField::ONE)[0], KoalaBear::ONE);
+        assert_eq!(to_coeffs(BaseField::ONE)[1..], [KoalaBear::ZERO; 7]);
+    }
+
+    #[test]
+    fn test_sqrt_zero_and_one() {
+        assert_eq!(sqrt(BaseField::ZERO), Some(BaseField::ZERO));
+        let one = sqrt(BaseField::ONE).unwrap();
+        assert_eq!(one.square(), BaseField::ONE);
+    }
+
+    #[test]
+    fn test_sqrt_non_residue() {
+        // u is a quadratic non-residue in Fp8
+        let zero = KoalaBear::ZERO;
+        let u = from_coeffs([zero, KoalaBear::ONE, zero, zero, zero, zero, zero, zero]);
+        assert_eq!(sqrt(u), None);
+        assert_eq!(sqrt(u * BaseField::from(KoalaBear::new(9))), None);
+    }
+
+    #[test]
+    fn test_root_of_unity_order() {
+        let mut z = root_of_unity();
+        for _ in 0..TWO_ADICITY - 1 {
+            assert_ne!(z, BaseField::ONE);
+            z = z.square();
+        }
+        assert_eq!(z, -BaseField::ONE);
+    }
+}
@@ -0,0 +1,100 @@
+//! Compressed serde encoding for [`Affine`] points.
+//!
+//! The derived `Serialize` for [`Affine`] writes both Fp8 coordinates and the
+//! infinity flag, 65 bytes under bincode. [`CompressedAffine`] instead writes the
+//! 33 bytes of [`Affine::to_compressed`] as a fixed-size tuple, so compact
+//! formats add no length prefix. Decoding recovers `y` with an Fp8 square root,
+//! which costs far more than reading it, so the compressed form suits storage
+//! and transmission rather than hot in-memory paths.
+//!
+//! Fields of type [`Affine`] can opt in with `#[serde(with = "curve::compressed")]`:
+//!
+//! ```
+//! use curve::{Affine, Group};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Commitment {
+//!     #[serde(with = "curve::compressed")]
+//!     point: Affine,
+//! }
+//! # let _ = Commitment { point: Affine::generator() };
+//! ```
+
+use core::fmt;
+
+use serde::de::{self, SeqAccess, Visitor};
+use serde::ser::SerializeTuple;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::Affine;
+
+/// Length of [`Affine::to_compressed`] and of the serialized [`CompressedAffine`].
+pub const COMPRESSED_SIZE: usize = 33;
+
+/// An [`Affine`] point that serializes as its 33-byte compressed encoding.
+///
+/// Deserialization rejects non-canonical encodings and x-coordinates that are
+/// not on the curve, as [`Affine::from_compressed`] does.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CompressedAffine(pub Affine);
+
+impl From<Affine> for CompressedAffine {
+    fn from(point: Affine) -> Self {
+        Self(point)
+    }
+}
+
+impl From<CompressedAffine> for Affine {
+    fn from(point: CompressedAffine) -> Self {
+        point.0
+    }
+}
+
+impl Serialize for CompressedAffine {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CompressedAffine {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Self)
+    }
+}
+
+/// Serialize `point` as the 33-byte tuple of [`Affine::to_compressed`].
+pub fn serialize<S: Serializer>(point: &Affine, serializer: S) -> Result<S::Ok, S::Error> {
+    let bytes = point.to_compressed();
+    let mut tuple = serializer.serialize_tuple(COMPRESSED_SIZE)?;
+    for byte in &bytes {
+        tuple.serialize_element(byte)?;
+    }
+    tuple.end()
+}
+
+/// Deserialize a point written by [`serialize`].
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Affine, D::Error> {
+    deserializer.deserialize_tuple(COMPRESSED_SIZE, CompressedVisitor)
+}
+
+struct CompressedVisitor;
+
+impl<'de> Visitor<'de> for CompressedVisitor {
+    type Value = Affine;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{COMPRESSED_SIZE} bytes of a compressed curve point")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Affine, A::Error> {
+        let mut bytes = [0u8; COMPRESSED_SIZE];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))?;
+        }
+        Affine::from_compressed(&bytes)
+            .ok_or_else(|| de::Error::custom("invalid compressed curve point"))
+    }
+}
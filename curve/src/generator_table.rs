@@ -1,5 +1,8 @@
+#[cfg(feature = "std")]
 use std::sync::OnceLock;
 
+use alloc::vec::Vec;
+
 use crate::basefield::from_coeffs;
 use crate::{Affine, ScalarBits, ScalarField};
 use p3_koala_bear::KoalaBear;
@@ -1628,21 +1631,28 @@ static GENERATOR_Y_COEFFS: [[u32; 8]; 256] = [
     ],
 ];
 
+/// `i · G` for every 8-bit window `i`, cached after the first call.
+#[cfg(feature = "std")]
 pub(crate) fn affine_table() -> &'static [Affine; 256] {
     static TABLE: OnceLock<[Affine; 256]> = OnceLock::new();
-    TABLE.get_or_init(|| {
-        let mut table = [Affine::INFINITY; 256];
-        for i in 0..256 {
-            let x = from_coeffs(GENERATOR_X_COEFFS[i].map(KoalaBear::new));
-            let y = from_coeffs(GENERATOR_Y_COEFFS[i].map(KoalaBear::new));
-            table[i] = if i == 0 {
-                Affine::INFINITY
-            } else {
-                Affine::new(x, y)
-            };
-        }
-        table
-    })
+    TABLE.get_or_init(build_affine_table)
+}
+
+/// `i · G` for every 8-bit window `i`. Without `std` there is no `OnceLock`,
+/// so the table is rebuilt from the constants on every call.
+#[cfg(not(feature = "std"))]
+pub(crate) fn affine_table() -> [Affine; 256] {
+    build_affine_table()
+}
+
+fn build_affine_table() -> [Affine; 256] {
+    let mut table = [Affine::INFINITY; 256];
+    for i in 1..256 {
+        let x = from_coeffs(GENERATOR_X_COEFFS[i].map(KoalaBear::new));
+        let y = from_coeffs(GENERATOR_Y_COEFFS[i].map(KoalaBear::new));
+        table[i] = Affine::new(x, y);
+    }
+    table
 }
 
 fn windowed_mul_affine(table: &[Affine; 256], scalar_limbs: [u64; 4]) -> Affine {
@@ -1671,5 +1681,93 @@ fn windowed_mul_affine(table: &[Affine; 256], scalar_limbs: [u64; 4]) -> Affine
 
 /// Multiply the fixed generator using a precomputed table (affine output).
 pub fn mul_generator_affine(scalar: &ScalarField) -> Affine {
-    windowed_mul_affine(affine_table(), scalar.to_u64_limbs())
+    windowed_mul_affine(&affine_table(), scalar.to_u64_limbs())
+}
+
+/// Window width of a [`FixedBaseTable`].
+const FIXED_BASE_WINDOW_BITS: usize = 4;
+const FIXED_BASE_WINDOWS: usize = 256 / FIXED_BASE_WINDOW_BITS;
+
+/// Precomputed multiples of an arbitrary fixed base.
+///
+/// Window `i` stores `d · 16^i · B` for every 4-bit digit `d`, so a
+/// multiplication is at most 64 additions and no doublings. Building the table
+/// costs about 1000 additions; it pays off once a base is reused a handful of
+/// times (e.g. `Affine::generator_pedersen` for commitments).
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable {
+    windows: Vec<[Affine; 1 << FIXED_BASE_WINDOW_BITS]>,
+}
+
+impl FixedBaseTable {
+    /// Build the table for `base`.
+    pub fn new(base: &Affine) -> Self {
+        let mut windows = Vec::with_capacity(FIXED_BASE_WINDOWS);
+        let mut window_base = *base;
+
+        for _ in 0..FIXED_BASE_WINDOWS {
+            let mut entries = [Affine::INFINITY; 1 << FIXED_BASE_WINDOW_BITS];
+            for d in 1..entries.len() {
+                entries[d] = entries[d - 1] + window_base;
+            }
+            window_base = entries[entries.len() - 1] + window_base;
+            windows.push(entries);
+        }
+
+        Self { windows }
+    }
+
+    /// Multiply the fixed base by `scalar`.
+    pub fn mul(&self, scalar: &ScalarField) -> Affine {
+        let digits_per_limb = 64 / FIXED_BASE_WINDOW_BITS;
+        let mask = (1u64 << FIXED_BASE_WINDOW_BITS) - 1;
+        let mut result = Affine::INFINITY;
+
+        for (i, limb) in scalar.to_u64_limbs().into_iter().enumerate() {
+            for j in 0..digits_per_limb {
+                let digit = ((limb >> (j * FIXED_BASE_WINDOW_BITS)) & mask) as usize;
+                if digit != 0 {
+                    result += self.windows[i * digits_per_limb + j][digit];
+                }
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Group, RandomField};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_affine_table_is_cached() {
+        let cached = affine_table();
+        // Later calls hand out the same table instead of rebuilding it
+        assert!(core::ptr::eq(cached, affine_table()));
+        assert_eq!(*cached, build_affine_table());
+
+        let g = Affine::generator();
+        assert_eq!(cached[0], Affine::INFINITY);
+        assert_eq!(cached[1], g);
+        assert_eq!(cached[255], g.mul_u64(255));
+    }
+
+    #[test]
+    fn test_fixed_base_table_matches_scalar_mul() {
+        let mut rng = StdRng::seed_from_u64(22);
+        let base = Affine::generator_pedersen();
+        let table = FixedBaseTable::new(&base);
+
+        for _ in 0..8 {
+            let s = ScalarField::random(&mut rng);
+            assert_eq!(table.mul(&s), base.scalar_mul(&s));
+        }
+        assert_eq!(table.mul(&ScalarField::ZERO), Affine::INFINITY);
+        assert_eq!(table.mul(&-ScalarField::ONE), base.negate());
+    }
 }
@@ -1,8 +1,70 @@
+use alloc::vec;
+use alloc::vec::Vec;
 use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
 
+/// Batch size from which `multi_scalar_mul` switches to the bucket method.
+pub const PIPPENGER_THRESHOLD: usize = 32;
+
+/// Batch size from which `multi_scalar_mul` spreads Pippenger windows across
+/// rayon threads.
+#[cfg(feature = "parallel")]
+pub const PARALLEL_MSM_THRESHOLD: usize = 128;
+
+/// Window width used by [`Group::scalar_mul_wnaf`].
+pub const WNAF_WIDTH: usize = 5;
+
 /// A scalar type that can expose its canonical 256-bit representation.
 pub trait ScalarBits {
     fn to_u64_limbs(&self) -> [u64; 4];
+
+    /// Number of significant bits: one more than the index of the top set bit
+    /// of the canonical value, or 0 for zero.
+    fn bit_length(&self) -> usize {
+        limbs_bit_length(&self.to_u64_limbs())
+    }
+
+    /// Width-`w` non-adjacent form, least significant digit first.
+    ///
+    /// Non-zero digits are odd with `|d| < 2^(w-1)`, and any `w` consecutive
+    /// digits hold at most one non-zero, so the scalar is `sum(d_i * 2^i)`.
+    /// `w` must be in `2..=8`.
+    fn to_wnaf(&self, w: usize) -> Vec<i8> {
+        assert!((2..=8).contains(&w), "wNAF width must be in 2..=8");
+
+        let limbs = self.to_u64_limbs();
+        // One extra limb absorbs the carry from negative digits.
+        let mut k = [limbs[0], limbs[1], limbs[2], limbs[3], 0];
+        let width = 1u64 << w;
+        let mut digits = Vec::with_capacity(257);
+
+        while k.iter().any(|&limb| limb != 0) {
+            let mut digit = 0i8;
+            if k[0] & 1 == 1 {
+                let m = k[0] & (width - 1);
+                if m >= width / 2 {
+                    // digit = m - 2^w, so k - digit = k + (2^w - m)
+                    digit = (m as i64 - width as i64) as i8;
+                    let mut carry = width - m;
+                    for limb in k.iter_mut() {
+                        let (sum, overflow) = limb.overflowing_add(carry);
+                        *limb = sum;
+                        carry = overflow as u64;
+                    }
+                } else {
+                    digit = m as i8;
+                    k[0] -= m;
+                }
+            }
+            digits.push(digit);
+
+            for i in 0..4 {
+                k[i] = (k[i] >> 1) | (k[i + 1] << 63);
+            }
+            k[4] >>= 1;
+        }
+
+        digits
+    }
 }
 
 /// Basic additive group behavior for curve points.
@@ -27,24 +89,40 @@ pub trait Group:
     }
     /// Return 2 * self.
     fn double(&self) -> Self;
+    /// Replace self with 2 * self.
+    ///
+    /// Lets implementations update their coordinates in place; the default
+    /// goes through [`Group::double`].
+    #[inline]
+    fn double_in_place(&mut self) {
+        *self = self.double();
+    }
     /// Return -self.
     fn negate(&self) -> Self;
 
     /// Double-and-add scalar multiplication.
+    ///
+    /// Stops after the top set bit, so small scalars cost only as many
+    /// doublings as they have bits. Zero and one return the identity and
+    /// `self` directly.
     #[inline]
     fn scalar_mul(&self, scalar: &Self::Scalar) -> Self {
         let scalar_limbs = scalar.to_u64_limbs();
+        match scalar_limbs {
+            [0, 0, 0, 0] => return Self::identity(),
+            [1, 0, 0, 0] => return *self,
+            _ => {}
+        }
+        let num_bits = limbs_bit_length(&scalar_limbs);
         let mut result = Self::identity();
         let mut temp = *self;
 
-        for &limb in scalar_limbs.iter() {
-            let mut bits = limb;
-            for _ in 0..64 {
-                if bits & 1 == 1 {
-                    result += temp;
-                }
-                temp = temp.double();
-                bits >>= 1;
+        for bit in 0..num_bits {
+            if (scalar_limbs[bit / 64] >> (bit % 64)) & 1 == 1 {
+                result += temp;
+            }
+            if bit + 1 < num_bits {
+                temp.double_in_place();
             }
         }
 
@@ -58,10 +136,17 @@ pub trait Group:
     }
 
     /// Fixed-window (width = 4) scalar multiplication.
+    ///
+    /// Zero and one return the identity and `self` without building the table.
     fn scalar_mul_windowed(&self, scalar: &Self::Scalar) -> Self {
         if self.is_identity() {
             return Self::identity();
         }
+        match scalar.to_u64_limbs() {
+            [0, 0, 0, 0] => return Self::identity(),
+            [1, 0, 0, 0] => return *self,
+            _ => {}
+        }
 
         let mut table = [Self::identity(); 16];
         table[1] = *self;
@@ -74,20 +159,48 @@ pub trait Group:
             };
         }
 
+        // Leading all-zero windows would only double the identity
         let scalar_limbs = scalar.to_u64_limbs();
+        let num_windows = limbs_bit_length(&scalar_limbs).div_ceil(4);
         let mut result = Self::identity();
 
-        for &limb in scalar_limbs.iter().rev() {
-            for shift in (0..64).step_by(4).rev() {
-                result = result.double();
-                result = result.double();
-                result = result.double();
-                result = result.double();
+        for window_idx in (0..num_windows).rev() {
+            result = result.double();
+            result = result.double();
+            result = result.double();
+            result = result.double();
 
-                let window = ((limb >> shift) & 0xF) as usize;
-                if window != 0 {
-                    result += table[window];
-                }
+            let window = window_digit(&scalar_limbs, window_idx * 4, 4);
+            if window != 0 {
+                result += table[window];
+            }
+        }
+
+        result
+    }
+
+    /// Scalar multiplication over the width-[`WNAF_WIDTH`] NAF of the scalar.
+    ///
+    /// Precomputes the odd multiples `P, 3P, ..., (2^(w-1) - 1)P`; on average
+    /// only one in `w + 1` digits is non-zero, so far fewer additions are
+    /// needed than with plain double-and-add. Not constant time.
+    fn scalar_mul_wnaf(&self, scalar: &Self::Scalar) -> Self {
+        let digits = scalar.to_wnaf(WNAF_WIDTH);
+
+        // table[i] = (2i + 1) * self
+        let mut table = [*self; 1 << (WNAF_WIDTH - 2)];
+        let double = self.double();
+        for i in 1..table.len() {
+            table[i] = table[i - 1] + double;
+        }
+
+        let mut result = Self::identity();
+        for &digit in digits.iter().rev() {
+            result = result.double();
+            if digit > 0 {
+                result += table[(digit >> 1) as usize];
+            } else if digit < 0 {
+                result -= table[(-digit >> 1) as usize];
             }
         }
 
@@ -118,7 +231,10 @@ pub trait Group:
         result
     }
 
-    /// Naive multi-scalar multiplication.
+    /// Multi-scalar multiplication.
+    ///
+    /// Sums per-point scalar multiplications for small batches and switches to
+    /// [`Group::msm_pippenger`] from [`PIPPENGER_THRESHOLD`] points on.
     fn multi_scalar_mul(points: &[Self], scalars: &[Self::Scalar]) -> Self {
         assert_eq!(
             points.len(),
@@ -126,10 +242,171 @@ pub trait Group:
             "Points and scalars must have same length"
         );
 
+        if points.len() >= PIPPENGER_THRESHOLD {
+            return Self::msm_pippenger(points, scalars);
+        }
+
         let mut result = Self::identity();
         for (point, scalar) in points.iter().zip(scalars.iter()) {
             result += point.scalar_mul(scalar);
         }
         result
     }
+
+    /// Multi-scalar multiplication over `(point, scalar)` pairs.
+    ///
+    /// Taking pairs rules out the length mismatch that makes
+    /// [`Group::multi_scalar_mul`] panic, so verification pipelines can produce
+    /// the terms lazily from any iterator. The pairs are collected and then
+    /// dispatched like the slice version; no pairs give the identity.
+    fn multi_scalar_mul_iter<I>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (Self, Self::Scalar)>,
+    {
+        let (points, scalars): (Vec<Self>, Vec<Self::Scalar>) = pairs.into_iter().unzip();
+        Self::multi_scalar_mul(&points, &scalars)
+    }
+
+    /// Bucket-method (Pippenger) multi-scalar multiplication.
+    ///
+    /// Each `c`-bit window of every scalar drops its point into one of
+    /// `2^c - 1` buckets; buckets are combined with a running sum, so a window
+    /// costs about `n + 2^(c+1)` additions instead of `n` full scalar
+    /// multiplications. The window size grows with `log2(n)`.
+    ///
+    /// The default runs on one thread. With the `parallel` feature, the
+    /// curve's `Projective` and `Affine` override it to compute the per-window
+    /// sums on rayon threads for batches of at least `PARALLEL_MSM_THRESHOLD`
+    /// points. The windows are merged in the same order either way, so the
+    /// result is identical to the serial one.
+    fn msm_pippenger(points: &[Self], scalars: &[Self::Scalar]) -> Self {
+        pippenger(points, scalars)
+    }
+}
+
+/// [`Group::msm_pippenger`] for thread-safe groups: the per-window sums run on
+/// rayon threads from [`PARALLEL_MSM_THRESHOLD`] points on.
+///
+/// The `Send + Sync` bounds live here rather than on [`Group`], so enabling
+/// `parallel` does not change which types can implement the traits.
+#[cfg(feature = "parallel")]
+pub(crate) fn msm_pippenger_parallel<G>(points: &[G], scalars: &[G::Scalar]) -> G
+where
+    G: Group + Send + Sync,
+    G::Scalar: Send + Sync,
+{
+    if points.len() >= PARALLEL_MSM_THRESHOLD {
+        pippenger_parallel(points, scalars)
+    } else {
+        pippenger(points, scalars)
+    }
+}
+
+/// Pippenger MSM with the per-window bucket sums computed serially.
+pub(crate) fn pippenger<G: Group>(points: &[G], scalars: &[G::Scalar]) -> G {
+    let Some((c, limbs)) = pippenger_limbs(points, scalars) else {
+        return G::identity();
+    };
+    let window_sums = (0..256usize.div_ceil(c))
+        .map(|window| pippenger_window_sum(points, &limbs, window, c))
+        .collect();
+    merge_window_sums(window_sums, c)
+}
+
+/// Pippenger MSM with the per-window bucket sums computed on rayon threads.
+#[cfg(feature = "parallel")]
+pub(crate) fn pippenger_parallel<G>(points: &[G], scalars: &[G::Scalar]) -> G
+where
+    G: Group + Send + Sync,
+    G::Scalar: Send + Sync,
+{
+    use rayon::prelude::*;
+
+    let Some((c, limbs)) = pippenger_limbs(points, scalars) else {
+        return G::identity();
+    };
+    let window_sums = (0..256usize.div_ceil(c))
+        .into_par_iter()
+        .map(|window| pippenger_window_sum(points, &limbs, window, c))
+        .collect();
+    merge_window_sums(window_sums, c)
+}
+
+/// The window width and canonical limbs for a Pippenger batch, or `None` if it
+/// is empty.
+fn pippenger_limbs<G: Group>(
+    points: &[G],
+    scalars: &[G::Scalar],
+) -> Option<(usize, Vec<[u64; 4]>)> {
+    assert_eq!(
+        points.len(),
+        scalars.len(),
+        "Points and scalars must have same length"
+    );
+    if points.is_empty() {
+        return None;
+    }
+
+    let limbs = scalars.iter().map(|s| s.to_u64_limbs()).collect();
+    Some((pippenger_window(points.len()), limbs))
+}
+
+/// Combine the `c`-bit window sums, given lowest window first, by Horner's rule.
+fn merge_window_sums<G: Group>(window_sums: Vec<G>, c: usize) -> G {
+    let mut result = G::identity();
+    for window_sum in window_sums.into_iter().rev() {
+        for _ in 0..c {
+            result = result.double();
+        }
+        result += window_sum;
+    }
+    result
+}
+
+/// `sum_i digit_i * P_i` for the `c`-bit digits of one window.
+fn pippenger_window_sum<G: Group>(points: &[G], limbs: &[[u64; 4]], window: usize, c: usize) -> G {
+    let mut buckets = vec![G::identity(); (1 << c) - 1];
+    for (point, scalar) in points.iter().zip(limbs.iter()) {
+        let digit = window_digit(scalar, window * c, c);
+        if digit != 0 {
+            buckets[digit - 1] += *point;
+        }
+    }
+
+    // sum_d d * bucket[d] via running sums from the top bucket down
+    let mut running = G::identity();
+    let mut window_sum = G::identity();
+    for bucket in buckets.iter().rev() {
+        running += *bucket;
+        window_sum += running;
+    }
+    window_sum
+}
+
+/// Pippenger window width for `n` points, roughly `2/3 · log2(n) + 2`.
+fn pippenger_window(n: usize) -> usize {
+    (n.ilog2() as usize * 2 / 3 + 2).min(16)
+}
+
+/// Number of significant bits in a little-endian limb scalar.
+fn limbs_bit_length(limbs: &[u64; 4]) -> usize {
+    limbs
+        .iter()
+        .rposition(|&limb| limb != 0)
+        .map_or(0, |idx| idx * 64 + 64 - limbs[idx].leading_zeros() as usize)
+}
+
+/// Extract `width` bits of a little-endian limb scalar starting at bit `bit`.
+fn window_digit(limbs: &[u64; 4], bit: usize, width: usize) -> usize {
+    let idx = bit / 64;
+    let shift = bit % 64;
+    if idx >= 4 {
+        return 0;
+    }
+
+    let mut value = limbs[idx] >> shift;
+    if shift + width > 64 && idx + 1 < 4 {
+        value |= limbs[idx + 1] << (64 - shift);
+    }
+    (value & ((1 << width) - 1)) as usize
 }
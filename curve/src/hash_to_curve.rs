@@ -0,0 +1,185 @@
+//! Hashing arbitrary byte strings to curve points.
+//!
+//! Follows the structure of RFC 9380 `hash_to_curve`: the message is hashed to
+//! two Fp8 elements, each is mapped to the curve with the simplified SWU map,
+//! and the two points are added. The curve has cofactor 1, so no cofactor
+//! clearing is needed. Field elements come from a Poseidon2 sponge over
+//! KoalaBear instead of an `expand_message` construction, so outputs are not
+//! interchangeable with RFC 9380 suites.
+
+use alloc::vec::Vec;
+use p3_field::{Field, PrimeCharacteristicRing, PrimeField32};
+use p3_koala_bear::{default_koalabear_poseidon2_16, KoalaBear, Poseidon2KoalaBear};
+use p3_symmetric::{CryptographicHasher, PaddingFreeSponge};
+
+use crate::basefield::{from_coeffs, sgn0, sqrt, BaseField};
+use crate::Affine;
+
+/// SSWU constant `Z = 3u`, the first of `±k·u` (k = 1, 2, ...) meeting the
+/// RFC 9380 `find_z_sswu` criteria: a non-square, not `-1`, with `g(x) - Z`
+/// irreducible and `g(B / (Z·A))` square.
+fn sswu_z() -> BaseField {
+    let zero = KoalaBear::ZERO;
+    from_coeffs([zero, KoalaBear::new(3), zero, zero, zero, zero, zero, zero])
+}
+
+/// Hash `msg` to a curve point under the domain separation tag `domain`.
+///
+/// The output is computationally indistinguishable from a uniformly random
+/// point with unknown discrete log, as needed for independent Pedersen bases
+/// and VRF constructions. Not constant time.
+///
+/// # Panics
+///
+/// Panics if `domain` or `msg` is `2^31 - 2^24 + 1` bytes or longer, since
+/// its length is absorbed as a single KoalaBear element.
+pub fn hash_to_curve(domain: &[u8], msg: &[u8]) -> Affine {
+    let [u0, u1] = hash_to_field(domain, msg);
+    map_to_curve_sswu(u0) + map_to_curve_sswu(u1)
+}
+
+/// Simplified SWU map from an Fp8 element to a curve point (RFC 9380, 6.6.2).
+///
+/// The sign of `y` matches `sgn0(u)`. On its own the map is not uniform;
+/// [`hash_to_curve`] adds two independent images to fix that.
+pub fn map_to_curve_sswu(u: BaseField) -> Affine {
+    let a = Affine::curve_a();
+    let b = Affine::curve_b();
+    let z = sswu_z();
+    let g = |x: BaseField| x * x * x + a * x + b;
+
+    let z_u2 = z * u * u;
+    let den = z_u2 * z_u2 + z_u2;
+    let x1 = if den == BaseField::ZERO {
+        // Exceptional case: x1 = B / (Z·A), where g(x1) is square by choice of Z
+        b / (z * a)
+    } else {
+        -b / a * (BaseField::ONE + den.inverse())
+    };
+
+    // g(x2) = (Z·u²)³ · g(x1) and Z is a non-square, so one of them is square
+    let (x, mut y) = match sqrt(g(x1)) {
+        Some(y) => (x1, y),
+        None => {
+            let x2 = z_u2 * x1;
+            (x2, sqrt(g(x2)).expect("g(x2) is square when g(x1) is not"))
+        }
+    };
+    if sgn0(u) != sgn0(y) {
+        y = -y;
+    }
+
+    let point = Affine::new(x, y);
+    debug_assert!(point.is_on_curve());
+    point
+}
+
+/// Hash to two Fp8 elements, `u_i = H(domain || i || msg)` for `i = 0, 1`.
+///
+/// Byte strings are length-prefixed and packed 3 bytes per KoalaBear element.
+/// Each sponge output element is a full KoalaBear element, so the 8 elements of
+/// one digest form a uniform Fp8 coefficient vector.
+fn hash_to_field(domain: &[u8], msg: &[u8]) -> [BaseField; 2] {
+    let sponge = PaddingFreeSponge::<Poseidon2KoalaBear<16>, 16, 8, 8>::new(
+        default_koalabear_poseidon2_16(),
+    );
+    let domain = encode_bytes(domain);
+    let msg = encode_bytes(msg);
+
+    [0u32, 1].map(|counter| {
+        let input = domain
+            .iter()
+            .copied()
+            .chain([KoalaBear::from_u32(counter)])
+            .chain(msg.iter().copied());
+        from_coeffs(sponge.hash_iter(input))
+    })
+}
+
+/// Length prefix followed by 3 little-endian bytes per element, zero-padded.
+fn encode_bytes(bytes: &[u8]) -> Vec<KoalaBear> {
+    assert!(
+        bytes.len() < KoalaBear::ORDER_U32 as usize,
+        "input too long to hash to the curve"
+    );
+
+    let mut out = Vec::with_capacity(1 + bytes.len().div_ceil(3));
+    out.push(KoalaBear::from_u32(bytes.len() as u32));
+    out.extend(bytes.chunks(3).map(|chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        KoalaBear::from_u32(u32::from_le_bytes(word))
+    }));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fp8(coeffs: [u32; 8]) -> BaseField {
+        from_coeffs(coeffs.map(KoalaBear::new))
+    }
+
+    #[test]
+    fn test_map_to_curve_vectors() {
+        // Computed with an independent Python implementation of the same map
+        let cases = [
+            (
+                [1, 2, 3, 4, 5, 6, 7, 8],
+                [
+                    1640455678, 1360710152, 926724768, 1211978059, 1695799517, 407261184,
+                    1458870934, 513067575,
+                ],
+                [
+                    1558084665, 1926009801, 1449191645, 955328829, 1388721832, 1176380104,
+                    1442427791, 2087695055,
+                ],
+            ),
+            (
+                [0, 0, 0, 0, 0, 0, 0, 0],
+                [0, 0, 0, 0, 0, 0, 1893962853, 0],
+                [
+                    1860956654, 421098810, 705362643, 1758915356, 1853771474, 474392628, 579724357,
+                    796242914,
+                ],
+            ),
+            (
+                [0, 0, 0, 0, 0, 0, 0, 5],
+                [
+                    918832533, 774746685, 1561370278, 1310833342, 468391741, 479109609, 1334532906,
+                    651434816,
+                ],
+                [
+                    62719493, 21600017, 1078237056, 1192874167, 1895311138, 298334384, 1947875009,
+                    1406044199,
+                ],
+            ),
+        ];
+
+        for (u, x, y) in cases {
+            let point = map_to_curve_sswu(fp8(u));
+            assert_eq!(point, Affine::new(fp8(x), fp8(y)));
+            assert!(point.is_on_curve());
+        }
+    }
+
+    #[test]
+    fn test_hash_to_curve_on_curve() {
+        for i in 0u32..64 {
+            let msg = i.to_le_bytes();
+            let point = hash_to_curve(b"ZKM2-test", &msg);
+            assert!(point.is_in_subgroup(), "msg {i}");
+        }
+        assert!(hash_to_curve(b"", b"").is_on_curve());
+    }
+
+    #[test]
+    fn test_hash_to_curve_deterministic_and_separated() {
+        let point = hash_to_curve(b"ZKM2-test", b"message");
+        assert_eq!(point, hash_to_curve(b"ZKM2-test", b"message"));
+        assert_ne!(point, hash_to_curve(b"ZKM2-other", b"message"));
+        assert_ne!(point, hash_to_curve(b"ZKM2-test", b"message\0"));
+        assert_ne!(point, hash_to_curve(b"ZKM2-tes", b"tmessage"));
+    }
+}
@@ -72,6 +72,15 @@
 //! let point = mul_generator_affine(&scalar);
 //! ```
 //!
+//! Other fixed bases can build their own table once with [`FixedBaseTable`]:
+//!
+//! ```rust
+//! use curve::{Affine, FixedBaseTable, ScalarField, RandomField};
+//!
+//! let table = FixedBaseTable::new(&Affine::generator_pedersen());
+//! let point = table.mul(&ScalarField::random(&mut rand::rng()));
+//! ```
+//!
 //! ### Double Scalar Multiplication
 //!
 //! For computing `a·G + b·P` (common in signature verification):
@@ -106,6 +115,41 @@
 //! let result = base.scalar_mul_windowed(&scalar);
 //! ```
 //!
+//! ### Parallel MSM
+//!
+//! With the `parallel` feature, [`Group::multi_scalar_mul`] on [`Affine`] and
+//! [`Projective`] computes the Pippenger window sums on rayon threads once a
+//! batch reaches `PARALLEL_MSM_THRESHOLD` points. Results are identical to the
+//! single-threaded path. The feature adds no bounds to [`Group`] or
+//! [`ScalarBits`], so other implementations stay single-threaded.
+//!
+//! ### Hashing to the Curve
+//!
+//! [`hash_to_curve`] maps a domain tag and message to a point with unknown
+//! discrete log using the simplified SWU map:
+//!
+//! ```rust
+//! use curve::hash_to_curve;
+//!
+//! let base = hash_to_curve(b"my-protocol-v1", b"pedersen-h");
+//! assert!(base.is_on_curve());
+//! ```
+//!
+//! ### Secret Sharing
+//!
+//! [`secret_sharing`] splits a scalar into Shamir shares and recovers it with
+//! Lagrange interpolation, as used by t-of-n threshold signatures:
+//!
+//! ```rust
+//! use curve::secret_sharing::{reconstruct, split};
+//! use curve::{RandomField, ScalarField};
+//!
+//! let mut rng = rand::rng();
+//! let secret = ScalarField::random(&mut rng);
+//! let shares = split(secret, 2, 3, &mut rng);
+//! assert_eq!(reconstruct(&shares[1..]), secret);
+//! ```
+//!
 //! ## Field Arithmetic
 //!
 //! ### Scalar Field (Montgomery Form)
@@ -142,6 +186,24 @@
 //! ]);
 //! ```
 //!
+//! ## `no_std` Support
+//!
+//! The crate is `no_std` + `alloc` with the default `std` feature disabled:
+//!
+//! ```toml
+//! curve = { path = "...", default-features = false }
+//! ```
+//!
+//! Everything but the `parallel` feature is available. Without `std` there is
+//! no `OnceLock`, so the 256-entry generator table behind [`mul_generator_affine`]
+//! and [`double_scalar_mul_basepoint_affine`] is rebuilt from its constants on
+//! each call instead of being cached. `no_std_check/` is a `#![no_std]` crate
+//! that exercises the API; build it for a bare-metal target to check:
+//!
+//! ```text
+//! cargo build --manifest-path curve/no_std_check/Cargo.toml --target thumbv7em-none-eabihf
+//! ```
+//!
 //! ## Examples
 //!
 //! See the `examples/` directory for complete usage examples.
@@ -152,23 +214,39 @@
 //! - KoalaBear field specification
 //! - Montgomery arithmetic: Peter Montgomery (1985)
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 #[deny(missing_docs)]
 mod affine;
 mod basefield;
+pub mod compressed;
 mod generator_table;
 mod group;
+mod hash_to_curve;
 mod msm;
+mod params;
 mod projective;
 mod random;
 mod scalarfield;
+pub mod secret_sharing;
 
 pub use affine::Affine;
 pub use basefield::BaseField;
-pub use basefield::{to_bytes, to_u32s};
-pub use generator_table::mul_generator_affine;
-pub use group::{Group, ScalarBits};
+pub use basefield::{
+    from_canonical_bytes, from_coeffs, sqrt as sqrt_fp8, to_bytes, to_canonical_bytes, to_coeffs,
+    to_u32s,
+};
+pub use compressed::CompressedAffine;
+pub use generator_table::{mul_generator_affine, FixedBaseTable};
+#[cfg(feature = "parallel")]
+pub use group::PARALLEL_MSM_THRESHOLD;
+pub use group::{Group, ScalarBits, PIPPENGER_THRESHOLD, WNAF_WIDTH};
+pub use hash_to_curve::{hash_to_curve, map_to_curve_sswu};
 pub use msm::double_scalar_mul_basepoint_affine;
 pub use p3_koala_bear::KoalaBear;
+pub use params::{COFACTOR, CURVE_A, CURVE_B, SCALAR_FIELD_ORDER};
 pub use projective::Projective;
 pub use random::RandomField;
 pub use scalarfield::ScalarField;
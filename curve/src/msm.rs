@@ -1,43 +1,85 @@
 use crate::generator_table::affine_table;
-use crate::group::ScalarBits;
+use crate::group::{ScalarBits, WNAF_WIDTH};
 use crate::{Affine, ScalarField};
 
 /// Compute a * G + b * P using precomputed generator table and a point table.
+///
+/// `a` is consumed in 8-bit windows from the static 256-entry generator table.
+/// `b` is recoded in width-[`WNAF_WIDTH`] NAF, so the per-call table for `P`
+/// only holds the 8 odd multiples `P, 3P, ..., 15P`. Both scalars share one
+/// chain of doublings. Not constant time.
 pub fn double_scalar_mul_basepoint_affine(
     a: &ScalarField,
     b: &ScalarField,
     point: &Affine,
 ) -> Affine {
     let base_table = affine_table();
-    let mut point_table = [Affine::INFINITY; 256];
-    point_table[1] = *point;
-    for i in 2..256 {
-        point_table[i] = point_table[i - 1] + point_table[1];
+
+    // point_table[i] = (2i + 1) * point
+    let mut point_table = [*point; 1 << (WNAF_WIDTH - 2)];
+    let double = point.double();
+    for i in 1..point_table.len() {
+        point_table[i] = point_table[i - 1] + double;
     }
 
     let a_limbs = a.to_u64_limbs();
-    let b_limbs = b.to_u64_limbs();
+    let b_digits = b.to_wnaf(WNAF_WIDTH);
     let mut result = Affine::INFINITY;
 
-    for limb_idx in (0..4).rev() {
-        let a_limb = a_limbs[limb_idx];
-        let b_limb = b_limbs[limb_idx];
-        for shift in (0..64).step_by(8).rev() {
-            for _ in 0..8 {
-                result = result.double();
-            }
+    for bit in (0..b_digits.len().max(256)).rev() {
+        result = result.double();
 
-            let a_window = ((a_limb >> shift) & 0xFF) as usize;
+        if bit % 8 == 0 && bit < 256 {
+            let a_window = ((a_limbs[bit / 64] >> (bit % 64)) & 0xFF) as usize;
             if a_window != 0 {
                 result += base_table[a_window];
             }
+        }
 
-            let b_window = ((b_limb >> shift) & 0xFF) as usize;
-            if b_window != 0 {
-                result += point_table[b_window];
-            }
+        match b_digits.get(bit).copied().unwrap_or(0) {
+            0 => {}
+            digit if digit > 0 => result += point_table[(digit >> 1) as usize],
+            digit => result -= point_table[(-digit >> 1) as usize],
         }
     }
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Group, RandomField};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_double_scalar_mul_matches_separate_products() {
+        let mut rng = StdRng::seed_from_u64(36);
+
+        for _ in 0..8 {
+            let a = ScalarField::random(&mut rng);
+            let b = ScalarField::random(&mut rng);
+            let point = Affine::generator().scalar_mul(&ScalarField::random(&mut rng));
+
+            let expected = Affine::generator().scalar_mul(&a) + point.scalar_mul(&b);
+            assert_eq!(double_scalar_mul_basepoint_affine(&a, &b, &point), expected);
+        }
+
+        let point = Affine::generator_pedersen();
+        let a = ScalarField::random(&mut rng);
+        let b = -ScalarField::ONE;
+        assert_eq!(
+            double_scalar_mul_basepoint_affine(&a, &b, &point),
+            Affine::generator().scalar_mul(&a) - point
+        );
+        assert_eq!(
+            double_scalar_mul_basepoint_affine(&ScalarField::ZERO, &ScalarField::ZERO, &point),
+            Affine::INFINITY
+        );
+        assert_eq!(
+            double_scalar_mul_basepoint_affine(&a, &ScalarField::ZERO, &Affine::INFINITY),
+            Affine::generator().scalar_mul(&a)
+        );
+    }
+}
@@ -0,0 +1,49 @@
+//! Curve parameters for `y^2 = x^3 + a*x + b` over Fp8, exported so circuits
+//! and test vectors do not restate them.
+
+use p3_field::PrimeCharacteristicRing;
+use p3_koala_bear::KoalaBear;
+
+use crate::basefield::from_coeffs;
+use crate::BaseField;
+
+/// Order of the prime-order group, which is the scalar field modulus, as four
+/// little-endian 64-bit limbs.
+pub const SCALAR_FIELD_ORDER: [u64; 4] = crate::scalarfield::MODULUS;
+
+/// The curve has prime order, so its cofactor is 1.
+pub const COFACTOR: u64 = 1;
+
+/// The `a` coefficient `3u`, where `u` is the generator of Fp8 with `u^8 = 3`.
+#[allow(non_snake_case)]
+pub fn CURVE_A() -> BaseField {
+    let zero = KoalaBear::ZERO;
+    let three = KoalaBear::new(3);
+    from_coeffs([zero, three, zero, zero, zero, zero, zero, zero])
+}
+
+/// The `b` coefficient `42639`.
+#[allow(non_snake_case)]
+pub fn CURVE_B() -> BaseField {
+    let zero = KoalaBear::ZERO;
+    let b = KoalaBear::new(42639);
+    from_coeffs([b, zero, zero, zero, zero, zero, zero, zero])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Affine, Group, ScalarField};
+
+    #[test]
+    fn test_exported_parameters() {
+        let g = Affine::generator();
+        assert_eq!(g.y * g.y, g.x * g.x * g.x + CURVE_A() * g.x + CURVE_B());
+
+        // p - 1 is the largest canonical scalar
+        let mut order_minus_one = SCALAR_FIELD_ORDER;
+        order_minus_one[0] -= 1;
+        assert_eq!((-ScalarField::ONE).to_canonical_u64_vec(), order_minus_one);
+        assert_eq!(g.mul_u64(COFACTOR), g);
+    }
+}
@@ -1,15 +1,18 @@
 use crate::affine::Affine;
-use crate::basefield::{from_coeffs, BaseField};
+use crate::basefield::BaseField;
 use crate::{double_scalar_mul_basepoint_affine, mul_generator_affine, Group, ScalarField};
+use alloc::vec;
+use alloc::vec::Vec;
 use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 use p3_field::{Field, PrimeCharacteristicRing};
-use p3_koala_bear::KoalaBear;
 use serde::{Deserialize, Serialize};
 
 /// Projective point on the elliptic curve.
 /// Represents a point in projective coordinates (X:Y:Z) where (x,y) = (X/Z, Y/Z).
 /// The point at infinity is represented as (0:1:0).
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Equality compares the represented points, so `(X:Y:Z)` and `(λX:λY:λZ)` are equal.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
 pub struct Projective {
     pub x: BaseField,
     pub y: BaseField,
@@ -24,20 +27,13 @@ impl Projective {
     /// Get the 'a' coefficient: 3*u
     #[inline]
     fn curve_a() -> BaseField {
-        // Create 3*u: coefficients [0, 3, 0, 0, 0, 0, 0, 0]
-        // The second component (index 1) represents u^1
-        let zero = KoalaBear::ZERO;
-        let three = KoalaBear::new(3);
-        from_coeffs([zero, three, zero, zero, zero, zero, zero, zero])
+        crate::CURVE_A()
     }
 
     /// Get the 'b' coefficient: 42639
     #[inline]
     fn curve_b() -> BaseField {
-        // Create constant 42639 in the extension field
-        let zero = KoalaBear::ZERO;
-        let b = KoalaBear::new(42639);
-        from_coeffs([b, zero, zero, zero, zero, zero, zero, zero])
+        crate::CURVE_B()
     }
 
     /// The point at infinity (identity element): (0:1:0)
@@ -109,15 +105,79 @@ impl Projective {
     }
 
     /// Point doubling: 2*P using projective coordinates.
-    /// For simplicity and correctness, convert to affine, double, and convert back.
+    ///
+    /// Uses the inversion-free `dbl-2007-bl` formulas for `Y^2*Z = X^3 + a*X*Z^2 + b*Z^3`
+    /// (5M + 6S + 1 multiplication by `a`).
     pub fn double(&self) -> Self {
+        let mut point = *self;
+        point.double_in_place();
+        point
+    }
+
+    /// Replace `self` with `2 * self`, writing the coordinates directly instead
+    /// of building a new point. Same formulas as [`Projective::double`].
+    pub fn double_in_place(&mut self) {
+        if self.is_infinity() || self.y.is_zero() {
+            *self = Self::INFINITY;
+            return;
+        }
+
+        let xx = self.x.square();
+        let zz = self.z.square();
+        let w = Self::curve_a() * zz + xx.double() + xx;
+        let s = (self.y * self.z).double();
+        let ss = s.square();
+        let r = self.y * s;
+        let rr = r.square();
+        let b = (self.x + r).square() - xx - rr;
+        let h = w.square() - b.double();
+
+        self.x = h * s;
+        self.y = w * (b - h) - rr.double();
+        self.z = s * ss;
+    }
+
+    /// Replace `self` with `self + other`, writing the coordinates directly
+    /// instead of building a new point. Same formulas as `self + other`.
+    pub fn add_assign_projective(&mut self, other: &Projective) {
+        // Handle infinity cases
+        if other.is_infinity() {
+            return;
+        }
         if self.is_infinity() {
-            return *self;
+            *self = *other;
+            return;
         }
 
-        let affine = self.to_affine();
-        let doubled_affine = affine.double();
-        Self::from_affine(&doubled_affine)
+        // add-1998-cmo-2: 12M + 2S, no inversion
+        let y1z2 = self.y * other.z;
+        let x1z2 = self.x * other.z;
+        let z1z2 = self.z * other.z;
+        let u = other.y * self.z - y1z2;
+        let v = other.x * self.z - x1z2;
+
+        // v == 0 and u == 0 are the projective equality tests X1*Z2 == X2*Z1 and
+        // Y1*Z2 == Y2*Z1, so equal points are caught whatever their Z scaling,
+        // matching the affine `Add`
+        if v.is_zero() {
+            // Same x-coordinate: either P + P or P + (-P)
+            if u.is_zero() {
+                self.double_in_place();
+            } else {
+                *self = Self::INFINITY;
+            }
+            return;
+        }
+
+        let uu = u.square();
+        let vv = v.square();
+        let vvv = v * vv;
+        let r = vv * x1z2;
+        let a = uu * z1z2 - vvv - r.double();
+
+        self.x = v * a;
+        self.y = u * (r - a) - vvv * y1z2;
+        self.z = vvv * z1z2;
     }
 
     /// Negate a point.
@@ -140,9 +200,33 @@ impl Projective {
     }
 
     /// Batch normalization: convert multiple projective points to affine.
-    /// This is more efficient than converting them individually.
+    ///
+    /// Uses Montgomery's trick so the whole batch costs a single Fp8 inversion
+    /// plus `3(n - 1)` multiplications. Points at infinity map to `Affine::INFINITY`.
     pub fn batch_normalize(points: &[Self]) -> Vec<Affine> {
-        points.iter().map(|p| p.to_affine()).collect()
+        // Prefix products of the z-coordinates, substituting 1 for infinity
+        let mut prefix = Vec::with_capacity(points.len());
+        let mut acc = BaseField::ONE;
+        for point in points {
+            prefix.push(acc);
+            if !point.is_infinity() {
+                acc *= point.z;
+            }
+        }
+
+        // acc_inv = 1 / (z_0 * ... * z_{i}) while walking backwards
+        let mut acc_inv = acc.inverse();
+        let mut result = vec![Affine::INFINITY; points.len()];
+        for (i, point) in points.iter().enumerate().rev() {
+            if point.is_infinity() {
+                continue;
+            }
+            let z_inv = acc_inv * prefix[i];
+            acc_inv *= point.z;
+            result[i] = Affine::new(point.x * z_inv, point.y * z_inv);
+        }
+
+        result
     }
 }
 
@@ -174,36 +258,49 @@ impl Group for Projective {
         Self::double(self)
     }
 
+    #[inline]
+    fn double_in_place(&mut self) {
+        Self::double_in_place(self)
+    }
+
     #[inline]
     fn negate(&self) -> Self {
         Self::negate(self)
     }
+
+    #[cfg(feature = "parallel")]
+    fn msm_pippenger(points: &[Self], scalars: &[ScalarField]) -> Self {
+        crate::group::msm_pippenger_parallel(points, scalars)
+    }
 }
 
 // Implement addition for projective points
 impl Add for Projective {
     type Output = Self;
 
-    fn add(self, other: Self) -> Self {
-        // Handle infinity cases
-        if self.is_infinity() {
-            return other;
-        }
-        if other.is_infinity() {
-            return self;
-        }
+    fn add(mut self, other: Self) -> Self {
+        self.add_assign_projective(&other);
+        self
+    }
+}
 
-        // For simplicity and correctness, convert to affine, add, and convert back
-        let affine1 = self.to_affine();
-        let affine2 = other.to_affine();
-        let result_affine = affine1 + affine2;
-        Self::from_affine(&result_affine)
+impl PartialEq for Projective {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_infinity(), other.is_infinity()) {
+            (true, true) => true,
+            (false, false) => {
+                self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+            }
+            _ => false,
+        }
     }
 }
 
+impl Eq for Projective {}
+
 impl AddAssign for Projective {
     fn add_assign(&mut self, other: Self) {
-        *self = *self + other;
+        self.add_assign_projective(&other);
     }
 }
 
@@ -292,6 +389,7 @@ impl From<&Projective> for Affine {
 mod tests {
     use super::*;
     use crate::Group;
+    use p3_koala_bear::KoalaBear;
 
     #[test]
     fn test_infinity() {
@@ -373,6 +471,11 @@ mod tests {
         let result = g.scalar_mul(&zero);
 
         assert_eq!(result, Projective::INFINITY);
+
+        // Early returns agree with the looped paths
+        assert_eq!(g.scalar_mul_windowed(&zero), result);
+        assert_eq!(g.scalar_mul_wnaf(&zero), result);
+        assert_eq!(g.mul_u64(0), result);
     }
 
     #[test]
@@ -382,6 +485,12 @@ mod tests {
         let result = g.scalar_mul(&one);
 
         assert_eq!(result, g);
+
+        // Early returns agree with the looped paths
+        assert_eq!(g.scalar_mul_windowed(&one), result);
+        assert_eq!(g.scalar_mul_wnaf(&one), result);
+        assert_eq!(g.mul_u64(1), result);
+        assert_eq!(g.scalar_mul_windowed(&(one + one)), g.mul_u64(2));
     }
 
     #[test]
@@ -472,6 +581,58 @@ mod tests {
         assert!(result.is_on_curve());
     }
 
+    #[test]
+    fn test_multi_scalar_mul_iter() {
+        let g = Projective::generator();
+        let a = ScalarField::from_canonical_u64(7);
+
+        assert!(Projective::multi_scalar_mul_iter(core::iter::empty()).is_identity());
+        assert_eq!(
+            Projective::multi_scalar_mul_iter([(g, a)]),
+            g.scalar_mul(&a)
+        );
+
+        // Lazily built terms, past the Pippenger threshold
+        let n = crate::PIPPENGER_THRESHOLD as u64 + 3;
+        let pairs = (1..=n).map(|i| (g.mul_u64(i), ScalarField::from_canonical_u64(i + 1)));
+        let points: Vec<_> = (1..=n).map(|i| g.mul_u64(i)).collect();
+        let scalars: Vec<_> = (1..=n)
+            .map(|i| ScalarField::from_canonical_u64(i + 1))
+            .collect();
+        assert_eq!(
+            Projective::multi_scalar_mul_iter(pairs),
+            Projective::multi_scalar_mul(&points, &scalars)
+        );
+    }
+
+    #[test]
+    fn test_in_place_ops_match_by_value() {
+        let g = Projective::generator();
+        let h = Projective::generator_pedersen();
+        let inf = Projective::INFINITY;
+
+        for p in [g, h, g + h, inf] {
+            let mut doubled = p;
+            doubled.double_in_place();
+            assert_eq!(doubled, p.double());
+            assert_eq!(doubled.to_affine(), p.to_affine().double());
+        }
+
+        // Generic points, infinity on either side, P + P and P + (-P)
+        for (p, q) in [(g, h), (g, inf), (inf, h), (inf, inf), (g, g), (g, -g)] {
+            let mut sum = p;
+            sum.add_assign_projective(&q);
+            assert_eq!(sum, p + q);
+            assert_eq!(sum.to_affine(), p.to_affine() + q.to_affine());
+        }
+
+        // Equal points with different Z scaling still take the doubling branch
+        let scaled = Projective::new(g.x.double(), g.y.double(), g.z.double());
+        let mut sum = g;
+        sum.add_assign_projective(&scaled);
+        assert_eq!(sum, g.double());
+    }
+
     #[test]
     fn test_mul_u64() {
         let g = Projective::generator();
@@ -525,4 +686,126 @@ mod tests {
             assert_eq!(proj.to_affine(), *aff);
         }
     }
+
+    #[test]
+    fn test_projective_formulas_match_affine() {
+        use crate::RandomField;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(25);
+        for _ in 0..16 {
+            let p = Affine::generator().scalar_mul(&ScalarField::random(&mut rng));
+            let q = Affine::generator().scalar_mul(&ScalarField::random(&mut rng));
+
+            // Scale coordinates so Z != 1 going into the formulas
+            let lambda = BaseField::random(&mut rng);
+            let p_proj = Projective::new(p.x * lambda, p.y * lambda, lambda);
+            let q_proj = Projective::from_affine(&q);
+
+            let sum = p_proj + q_proj;
+            let doubled = p_proj.double();
+            assert!(sum.is_on_curve());
+            assert!(doubled.is_on_curve());
+            assert_eq!(sum.to_affine(), p + q);
+            assert_eq!(doubled.to_affine(), p.double());
+        }
+    }
+
+    #[test]
+    fn test_add_detects_equal_and_opposite_points() {
+        let g = Projective::generator();
+        let lambda = BaseField::from(KoalaBear::new(11));
+        let scaled = Projective::new(g.x * lambda, g.y * lambda, lambda);
+        let p = g.double() + g;
+        let p_scaled = Projective::new(p.x * lambda, p.y * lambda, p.z * lambda);
+
+        assert_eq!(g + g, g.double());
+        assert_eq!(g + scaled, g.double());
+        assert_eq!(scaled + g, g.double());
+        assert_eq!(p + p_scaled, p.double());
+        assert_eq!((g + scaled).to_affine(), Affine::generator().double());
+
+        assert_eq!(g + (-g), Projective::INFINITY);
+        assert_eq!(scaled + (-g), Projective::INFINITY);
+        assert_eq!(p_scaled + (-p), Projective::INFINITY);
+        assert!((g + (-scaled)).is_infinity());
+    }
+
+    #[test]
+    fn test_equality_ignores_scaling() {
+        let g = Projective::generator();
+        let lambda = BaseField::from(KoalaBear::new(7));
+        let scaled = Projective::new(g.x * lambda, g.y * lambda, lambda);
+
+        assert_eq!(g, scaled);
+        assert_ne!(g, g.double());
+        assert_ne!(g, Projective::INFINITY);
+        assert_eq!(
+            Projective::INFINITY,
+            Projective::new(BaseField::ONE, lambda, BaseField::ZERO)
+        );
+    }
+
+    #[test]
+    fn test_batch_normalize_random() {
+        use crate::RandomField;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(26);
+        let g = Projective::generator();
+        let mut points: Vec<Projective> = (0..64)
+            .map(|_| {
+                let p = g.scalar_mul(&ScalarField::random(&mut rng));
+                let lambda = BaseField::random(&mut rng);
+                Projective::new(p.x * lambda, p.y * lambda, p.z * lambda)
+            })
+            .collect();
+        points[0] = Projective::INFINITY;
+        points[17] = Projective::INFINITY;
+        points[63] = Projective::INFINITY;
+
+        let affine_points = Projective::batch_normalize(&points);
+        assert_eq!(affine_points.len(), points.len());
+        for (proj, aff) in points.iter().zip(affine_points.iter()) {
+            assert_eq!(proj.to_affine(), *aff);
+        }
+        assert!(Projective::batch_normalize(&[]).is_empty());
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_msm_matches_serial() {
+        use crate::group::{pippenger, pippenger_parallel, PARALLEL_MSM_THRESHOLD};
+        use crate::RandomField;
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(43);
+        let n = 256;
+        assert!(n >= PARALLEL_MSM_THRESHOLD);
+        let g = Projective::generator();
+        let points: Vec<Projective> = (0..n).map(|i| g.mul_u64(i as u64 + 1)).collect();
+        let scalars: Vec<ScalarField> = (0..n).map(|_| ScalarField::random(&mut rng)).collect();
+
+        let serial = pippenger(&points, &scalars);
+        let parallel = pippenger_parallel(&points, &scalars);
+        // Same coordinates, not just the same point up to scaling
+        assert_eq!(
+            (parallel.x, parallel.y, parallel.z),
+            (serial.x, serial.y, serial.z)
+        );
+        assert_eq!(
+            <Projective as Group>::multi_scalar_mul(&points, &scalars),
+            serial
+        );
+
+        let affine_points = Projective::batch_normalize(&points);
+        assert_eq!(
+            pippenger_parallel(&affine_points, &scalars),
+            pippenger(&affine_points, &scalars)
+        );
+        assert_eq!(serial.to_affine(), pippenger(&affine_points, &scalars));
+    }
 }
@@ -1,9 +1,9 @@
 use rand::distr::{Distribution, StandardUniform};
 use rand::Rng;
 
-use crate::{BaseField, ScalarField};
+use crate::{Affine, BaseField, ScalarField};
 
-/// Helper trait for sampling random field elements.
+/// Helper trait for sampling random field elements and curve points.
 pub trait RandomField: Sized {
     fn random<R: Rng + ?Sized>(rng: &mut R) -> Self;
 }
@@ -21,3 +21,39 @@ impl RandomField for ScalarField {
         StandardUniform.sample(rng)
     }
 }
+
+/// Samples `k * G` for a uniform nonzero scalar `k`.
+///
+/// The group has prime order, so this is uniform over the non-identity points
+/// and never returns infinity. Whoever controls `rng` learns the discrete log of
+/// the result, so these points suit tests rather than independent commitment
+/// bases, which should come from [`hash_to_curve`](crate::hash_to_curve).
+impl RandomField for Affine {
+    fn random<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        loop {
+            let k = ScalarField::random(rng);
+            if !k.is_zero() {
+                return Affine::mul_generator(&k);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_random_affine_is_on_curve() {
+        let mut rng = StdRng::seed_from_u64(78);
+        let first = Affine::random(&mut rng);
+        for _ in 0..64 {
+            let point = Affine::random(&mut rng);
+            assert!(point.is_on_curve());
+            assert!(!point.is_infinity());
+            assert_ne!(point, first);
+        }
+    }
+}
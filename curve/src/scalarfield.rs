@@ -3,8 +3,6 @@
 //! This implementation uses Montgomery form for efficient modular arithmetic.
 //! The field element is represented as [u64; 4] in little-endian order.
 
-extern crate alloc;
-
 use crate::ScalarBits;
 use alloc::vec::Vec;
 use core::fmt::{self, Debug, Display, Formatter};
@@ -16,18 +14,18 @@ use p3_field::integers::QuotientMap;
 use p3_field::{Field, Packable, PrimeCharacteristicRing, PrimeField, RawDataSerializable};
 use rand::distr::{Distribution, StandardUniform};
 use rand::Rng;
-use serde::{Deserialize, Serialize};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 /// Scalar field element for the curve
 /// Represented in Montgomery form with [u64; 4]
-#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize)]
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd)]
 pub struct ScalarField {
     /// Montgomery form: value * R mod p, where R = 2^256
     limbs: [u64; 4],
 }
 
 // Field modulus: p = 0xf06e44682c2aa440f5f26a5ae1748ff85ccc2efc3068faf2154ff8a2e94d81
-const MODULUS: [u64; 4] = [
+pub(crate) const MODULUS: [u64; 4] = [
     0xf2154ff8a2e94d81,
     0xf85ccc2efc3068fa,
     0x40f5f26a5ae1748f,
@@ -51,7 +49,6 @@ const R2: [u64; 4] = [
 ];
 
 // R^3 = 2^768 mod p (for efficient conversion)
-#[allow(dead_code)]
 const R3: [u64; 4] = [
     0xcfda2e5499aadbfe,
     0x3b9752a61aed3bc4,
@@ -62,6 +59,37 @@ const R3: [u64; 4] = [
 // -p^{-1} mod 2^64 (Montgomery parameter mu)
 const MU: u64 = 0x921d21f874d30d7f;
 
+// Two-adicity of p - 1, i.e. p - 1 = 2^7 * t with t odd
+const TWO_ADICITY: u32 = 7;
+
+// (t - 1) / 2 for the Tonelli-Shanks square root
+const T_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0xfaf2154ff8a2e94d,
+    0x8ff85ccc2efc3068,
+    0xa440f5f26a5ae174,
+    0x0000f06e44682c2a,
+];
+
+// (p - 1) / 2, the Euler criterion exponent
+const P_MINUS_ONE_DIV_TWO: [u64; 4] = [
+    0x790aa7fc5174a6c0,
+    0xfc2e66177e18347d,
+    0x207af9352d70ba47,
+    0x0078372234161552,
+];
+
+// 5^t, a primitive 2^7-th root of unity (Montgomery form); 5 is a quadratic non-residue
+const ROOT_OF_UNITY: [u64; 4] = [
+    0xaeb73f783314ffca,
+    0x80049c79db0dd4a9,
+    0xebfc4a71b117fc98,
+    0x009c19cc5edec229,
+];
+
+// Number of divsteps for constant-time inversion of inputs below 2^256
+// (Bernstein-Yang bound: floor((49 * 256 + 57) / 17)).
+const INVERSE_ITERATIONS: usize = 741;
+
 impl ScalarField {
     /// Zero element (in Montgomery form)
     pub const ZERO: Self = ScalarField {
@@ -71,6 +99,33 @@ impl ScalarField {
     /// One element (in Montgomery form: R mod p)
     pub const ONE: Self = ScalarField { limbs: R };
 
+    /// The largest `k` with `2^k` dividing `p - 1`; the field has primitive
+    /// `2^bits`-th roots of unity for every `bits <= TWO_ADICITY`.
+    pub const TWO_ADICITY: u32 = TWO_ADICITY;
+
+    /// A primitive `2^bits`-th root of unity, for FFTs over the scalar field of
+    /// size up to `2^TWO_ADICITY`.
+    ///
+    /// The roots are consistent across sizes: squaring the generator for `bits`
+    /// gives the generator for `bits - 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` exceeds [`ScalarField::TWO_ADICITY`].
+    pub fn two_adic_generator(bits: usize) -> Self {
+        assert!(
+            bits <= TWO_ADICITY as usize,
+            "no 2^{bits}-th root of unity: the two-adicity is {TWO_ADICITY}"
+        );
+        let mut root = ScalarField {
+            limbs: ROOT_OF_UNITY,
+        };
+        for _ in bits..TWO_ADICITY as usize {
+            root = root.square();
+        }
+        root
+    }
+
     /// Create a new scalar field element from a u64 value
     #[inline]
     pub fn from_canonical_u64(val: u64) -> Self {
@@ -91,10 +146,107 @@ impl ScalarField {
         montgomery_mul(*self, one).limbs
     }
 
-    /// Serialize this scalar using the Plonky3 raw-data format.
-    pub fn to_bytes(&self) -> [u8; <Self as RawDataSerializable>::NUM_BYTES] {
-        let bytes: Vec<u8> = (*self).into_bytes().into_iter().collect();
-        bytes.try_into().expect("scalarfield byte length")
+    /// Serialize this scalar as 32 canonical little-endian bytes.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        let canonical = self.to_canonical_u64_vec();
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(canonical.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Deserialize a scalar from 32 canonical little-endian bytes.
+    ///
+    /// Returns `None` if the encoded value is not below the field modulus.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let limbs: [u64; 4] = core::array::from_fn(|i| {
+            u64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap())
+        });
+
+        if is_canonical(limbs) {
+            Some(Self::from_canonical_limbs(limbs))
+        } else {
+            None
+        }
+    }
+
+    /// Interpret 32 little-endian bytes as an integer and reduce it modulo `p`.
+    ///
+    /// Unlike [`ScalarField::from_bytes`] every input is accepted. Since
+    /// `2^256` is not a multiple of `p` the result is not uniform for uniform
+    /// input; use a wide reduction where that matters.
+    pub fn from_bytes_mod_order(bytes: &[u8; 32]) -> Self {
+        let limbs: [u64; 4] = core::array::from_fn(|i| {
+            u64::from_le_bytes(bytes[i * 8..(i + 1) * 8].try_into().unwrap())
+        });
+        // The Montgomery product with R^2 is below 2p for any 256-bit input, so
+        // one conditional subtraction leaves the reduced value.
+        Self::from_canonical_limbs(limbs)
+    }
+
+    /// Serialize this scalar as 32 canonical big-endian bytes, the byte
+    /// reversal of [`ScalarField::to_bytes`].
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        let mut bytes = self.to_bytes();
+        bytes.reverse();
+        bytes
+    }
+
+    /// Deserialize a scalar from 32 canonical big-endian bytes.
+    ///
+    /// Returns `None` if the encoded value is not below the field modulus.
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let mut le = *bytes;
+        le.reverse();
+        Self::from_bytes(&le)
+    }
+
+    /// Check that the internal representation is fully reduced modulo `p`.
+    ///
+    /// Every safe constructor maintains this invariant; the check lets code that
+    /// receives scalars across a trust boundary (e.g. signature verification)
+    /// enforce it explicitly.
+    #[inline]
+    pub fn is_canonical(&self) -> bool {
+        is_canonical(self.limbs)
+    }
+
+    /// Number of significant bits of the canonical value: the index of the top
+    /// set bit plus one, or 0 for zero. At most 248 for this field.
+    #[inline]
+    pub fn bit_length(&self) -> usize {
+        <Self as ScalarBits>::bit_length(self)
+    }
+
+    /// Alias for [`ScalarField::bit_length`].
+    #[inline]
+    pub fn num_bits(&self) -> usize {
+        self.bit_length()
+    }
+
+    /// Whether the canonical value is odd.
+    #[inline]
+    pub fn is_odd(&self) -> bool {
+        self.to_canonical_u64_vec()[0] & 1 == 1
+    }
+
+    /// Bit `i` of the canonical value, least significant first. Bits at 256
+    /// and above are zero.
+    #[inline]
+    pub fn bit(&self, i: usize) -> bool {
+        i < 256 && (self.to_canonical_u64_vec()[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    /// All 256 bits of the canonical value, least significant first.
+    ///
+    /// Leaves Montgomery form once, where reading every bit through
+    /// [`ScalarField::bit`] would convert per bit.
+    pub fn to_bits_le(&self) -> Vec<bool> {
+        let limbs = self.to_canonical_u64_vec();
+        (0..256)
+            .map(|i| (limbs[i / 64] >> (i % 64)) & 1 == 1)
+            .collect()
     }
 
     /// Serialize this scalar into little-endian u32 words.
@@ -103,10 +255,70 @@ impl ScalarField {
         words.try_into().expect("scalarfield u32 length")
     }
 
+    /// Map an arbitrary-length little-endian byte string to a scalar by reducing it mod `p`.
+    ///
+    /// The input is read as one big integer, so for inputs of at least 48 bytes the
+    /// result is within `2^-136` of uniform when the bytes are. This is the intended
+    /// way to turn byte-oriented hash output into a scalar: a challenge derived from a
+    /// wide digest covers the whole field without the structure left by packing a few
+    /// digest words into fixed limbs.
+    pub fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        // 2^256 mod p in Montgomery form is R2
+        let shift = ScalarField { limbs: R2 };
+
+        // Horner over 256-bit chunks, most significant first
+        let mut acc = Self::ZERO;
+        for chunk in bytes.chunks(32).rev() {
+            let mut padded = [0u8; 32];
+            padded[..chunk.len()].copy_from_slice(chunk);
+            let limbs: [u64; 4] = core::array::from_fn(|i| {
+                u64::from_le_bytes(padded[i * 8..(i + 1) * 8].try_into().unwrap())
+            });
+            // Any 256-bit value is accepted: chunk * R2 < p * R keeps the reduction valid
+            acc = acc * shift + Self::from_canonical_limbs(limbs);
+        }
+        acc
+    }
+
+    /// Construct from little-endian `u64` limbs.
+    ///
+    /// Values of `p` or more are reduced rather than rejected, which the wide
+    /// reductions above rely on. Where the limbs are expected to be canonical
+    /// already and a larger value would be a bug, use
+    /// [`ScalarField::try_from_canonical_limbs`].
     #[inline]
     pub fn from_canonical_limbs(limbs: [u64; 4]) -> Self {
         montgomery_mul(ScalarField { limbs }, ScalarField { limbs: R2 })
     }
+
+    /// Construct from little-endian `u64` limbs, or `None` if they encode a
+    /// value of `p` or more.
+    #[inline]
+    pub fn try_from_canonical_limbs(limbs: [u64; 4]) -> Option<Self> {
+        is_canonical(limbs).then(|| Self::from_canonical_limbs(limbs))
+    }
+
+    /// Canonical limbs of `self` if it is odd, otherwise of `p - self`, with a
+    /// flag set in the second case.
+    ///
+    /// Branch-free; since `p` is odd the returned value is always odd, as
+    /// required by regular signed-window recodings.
+    pub(crate) fn to_odd_limbs_ct(&self) -> ([u64; 4], bool) {
+        let k = self.to_canonical_u64_vec();
+        let mut negated = [0u64; 4];
+        let mut borrow = false;
+        for i in 0..4 {
+            let (diff, b1) = MODULUS[i].overflowing_sub(k[i]);
+            let (diff, b2) = diff.overflowing_sub(borrow as u64);
+            negated[i] = diff;
+            borrow = b1 | b2;
+        }
+
+        let even = (k[0] & 1) ^ 1;
+        let mask = even.wrapping_neg();
+        let limbs = core::array::from_fn(|i| k[i] ^ (mask & (k[i] ^ negated[i])));
+        (limbs, even == 1)
+    }
 }
 
 impl ScalarBits for ScalarField {
@@ -192,10 +404,196 @@ const fn borrowing_sub(a: u64, b: u64, borrow: bool) -> (u64, bool) {
     (diff, overflow1 || overflow2)
 }
 
+/// Helper: Expand a single bit into an all-zeros or all-ones mask
+#[inline]
+const fn ct_mask(bit: u64) -> u64 {
+    0u64.wrapping_sub(bit)
+}
+
+/// Helper: Branch-free select, returns `b` when `mask` is all ones and `a` when it is zero
+#[inline]
+const fn ct_select<const N: usize>(a: [u64; N], b: [u64; N], mask: u64) -> [u64; N] {
+    let mut out = [0u64; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = (a[i] & !mask) | (b[i] & mask);
+        i += 1;
+    }
+    out
+}
+
+/// Helper: Constant-time addition mod p for canonical inputs
+#[inline]
+const fn add_mod_ct(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let (r0, carry) = a[0].overflowing_add(b[0]);
+    let (r1, carry) = carrying_add(a[1], b[1], carry);
+    let (r2, carry) = carrying_add(a[2], b[2], carry);
+    let (r3, _) = carrying_add(a[3], b[3], carry);
+
+    // p < 2^248, so the sum never overflows 256 bits
+    let (s0, borrow) = r0.overflowing_sub(MODULUS[0]);
+    let (s1, borrow) = borrowing_sub(r1, MODULUS[1], borrow);
+    let (s2, borrow) = borrowing_sub(r2, MODULUS[2], borrow);
+    let (s3, borrow) = borrowing_sub(r3, MODULUS[3], borrow);
+
+    ct_select([s0, s1, s2, s3], [r0, r1, r2, r3], ct_mask(borrow as u64))
+}
+
+/// Helper: Constant-time negation mod p for canonical inputs
+#[inline]
+const fn neg_mod_ct(a: [u64; 4]) -> [u64; 4] {
+    let (r0, borrow) = 0u64.overflowing_sub(a[0]);
+    let (r1, borrow) = borrowing_sub(0, a[1], borrow);
+    let (r2, borrow) = borrowing_sub(0, a[2], borrow);
+    let (r3, borrow) = borrowing_sub(0, a[3], borrow);
+
+    // Add p back unless a was zero
+    let mask = ct_mask(borrow as u64);
+    let (r0, carry) = r0.overflowing_add(MODULUS[0] & mask);
+    let (r1, carry) = carrying_add(r1, MODULUS[1] & mask, carry);
+    let (r2, carry) = carrying_add(r2, MODULUS[2] & mask, carry);
+    let (r3, _) = carrying_add(r3, MODULUS[3] & mask, carry);
+    [r0, r1, r2, r3]
+}
+
+/// Helper: Constant-time halving mod p for canonical inputs
+#[inline]
+const fn halve_mod_ct(a: [u64; 4]) -> [u64; 4] {
+    // Add p when a is odd so the shift is exact
+    let mask = ct_mask(a[0] & 1);
+    let (r0, carry) = a[0].overflowing_add(MODULUS[0] & mask);
+    let (r1, carry) = carrying_add(a[1], MODULUS[1] & mask, carry);
+    let (r2, carry) = carrying_add(a[2], MODULUS[2] & mask, carry);
+    let (r3, _) = carrying_add(a[3], MODULUS[3] & mask, carry);
+    [
+        (r0 >> 1) | (r1 << 63),
+        (r1 >> 1) | (r2 << 63),
+        (r2 >> 1) | (r3 << 63),
+        r3 >> 1,
+    ]
+}
+
+/// Helper: Wrapping addition of two signed 320-bit (two's complement) integers
+#[inline]
+const fn signed_add(a: [u64; 5], b: [u64; 5]) -> [u64; 5] {
+    let (r0, carry) = a[0].overflowing_add(b[0]);
+    let (r1, carry) = carrying_add(a[1], b[1], carry);
+    let (r2, carry) = carrying_add(a[2], b[2], carry);
+    let (r3, carry) = carrying_add(a[3], b[3], carry);
+    let (r4, _) = carrying_add(a[4], b[4], carry);
+    [r0, r1, r2, r3, r4]
+}
+
+/// Helper: Negate a signed 320-bit integer
+#[inline]
+const fn signed_neg(a: [u64; 5]) -> [u64; 5] {
+    signed_add([!a[0], !a[1], !a[2], !a[3], !a[4]], [1, 0, 0, 0, 0])
+}
+
+/// Helper: Arithmetic right shift by one of a signed 320-bit integer
+#[inline]
+const fn signed_shr1(a: [u64; 5]) -> [u64; 5] {
+    [
+        (a[0] >> 1) | (a[1] << 63),
+        (a[1] >> 1) | (a[2] << 63),
+        (a[2] >> 1) | (a[3] << 63),
+        (a[3] >> 1) | (a[4] << 63),
+        ((a[4] as i64) >> 1) as u64,
+    ]
+}
+
+/// Constant-time modular inverse of a canonical 256-bit value (Bernstein-Yang safegcd).
+///
+/// Runs a fixed number of divsteps on `(f, g) = (p, x)` while tracking `d, e` with
+/// `f = d * x` and `g = e * x (mod p)`. Once `g` reaches zero, `f = +-1` and the
+/// inverse is `+-d`. Every iteration touches the same data regardless of `x`.
+/// Returns zero for a zero input.
+fn inverse_limbs_ct(x: [u64; 4]) -> [u64; 4] {
+    let mut delta: i64 = 1;
+    let mut f = [MODULUS[0], MODULUS[1], MODULUS[2], MODULUS[3], 0];
+    let mut g = [x[0], x[1], x[2], x[3], 0];
+    let mut d = [0u64; 4];
+    let mut e = [1u64, 0, 0, 0];
+
+    for _ in 0..INVERSE_ITERATIONS {
+        let g_odd = g[0] & 1;
+        let delta_pos = (delta.wrapping_neg() as u64) >> 63;
+        let swap = ct_mask(g_odd & delta_pos);
+
+        // If delta > 0 and g is odd: (delta, f, g) <- (-delta, g, -f)
+        delta = (delta ^ swap as i64).wrapping_sub(swap as i64);
+        let (f_next, g_next) = (ct_select(f, g, swap), ct_select(g, signed_neg(f), swap));
+        let (d_next, e_next) = (ct_select(d, e, swap), ct_select(e, neg_mod_ct(d), swap));
+        f = f_next;
+        d = d_next;
+        delta += 1;
+
+        // g <- (g + [g odd] * f) / 2, mirrored on the coefficient side
+        let odd = ct_mask(g_odd);
+        g = signed_shr1(signed_add(g_next, ct_select([0; 5], f, odd)));
+        e = halve_mod_ct(add_mod_ct(e_next, ct_select([0; 4], d, odd)));
+    }
+
+    let f_neg = ct_mask(f[4] >> 63);
+    ct_select(d, neg_mod_ct(d), f_neg)
+}
+
 /// Montgomery multiplication: (a * b * R^{-1}) mod p
+///
+/// Coarsely integrated operand scanning (CIOS), unrolled over the four limbs:
+/// each round adds `a[i] * b` into a 5-limb accumulator and immediately
+/// reduces it by one limb, so the 512-bit product is never materialized and
+/// every loop bound is a constant. Bit-identical to multiplying out and
+/// calling [`montgomery_reduce`]; the accumulator stays below `2p < 2^256`
+/// because the top modulus limb leaves 8 bits of headroom.
 #[inline]
 fn montgomery_mul(a: ScalarField, b: ScalarField) -> ScalarField {
-    // Compute a * b
+    let b = b.limbs;
+    let t = cios_round([0; 5], a.limbs[0], &b);
+    let t = cios_round(t, a.limbs[1], &b);
+    let t = cios_round(t, a.limbs[2], &b);
+    let t = cios_round(t, a.limbs[3], &b);
+    debug_assert_eq!(t[4], 0);
+    reduce_once([t[0], t[1], t[2], t[3]])
+}
+
+/// One CIOS round: `(t + a_i * b + m * p) / 2^64` with `m` chosen to clear the
+/// low limb.
+#[inline(always)]
+fn cios_round(t: [u64; 5], a_i: u64, b: &[u64; 4]) -> [u64; 5] {
+    let (t0, c) = mac(a_i, b[0], t[0], 0);
+    let (t1, c) = mac(a_i, b[1], t[1], c);
+    let (t2, c) = mac(a_i, b[2], t[2], c);
+    let (t3, c) = mac(a_i, b[3], t[3], c);
+    let (t4, t5) = adc(t[4], c, 0);
+
+    let m = t0.wrapping_mul(MU);
+    let (_, c) = mac(m, MODULUS[0], t0, 0);
+    let (r0, c) = mac(m, MODULUS[1], t1, c);
+    let (r1, c) = mac(m, MODULUS[2], t2, c);
+    let (r2, c) = mac(m, MODULUS[3], t3, c);
+    let (r3, c) = adc(t4, c, 0);
+    [r0, r1, r2, r3, t5 + c]
+}
+
+/// `a * b + c + carry`, split into low and high limbs.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) * (b as u128) + (c as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// `a + b + carry`, split into low and high limbs.
+#[inline(always)]
+const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = (a as u128) + (b as u128) + (carry as u128);
+    (t as u64, (t >> 64) as u64)
+}
+
+/// Schoolbook product followed by [`montgomery_reduce`], the reference for
+/// [`montgomery_mul`].
+#[cfg(test)]
+fn montgomery_mul_schoolbook(a: ScalarField, b: ScalarField) -> ScalarField {
     let mut t = [0u64; 8];
 
     for i in 0..4 {
@@ -208,7 +606,53 @@ fn montgomery_mul(a: ScalarField, b: ScalarField) -> ScalarField {
         t[i + 4] = carry as u64;
     }
 
-    // Montgomery reduction
+    montgomery_reduce(t)
+}
+
+/// Montgomery squaring: (a * a * R^{-1}) mod p
+///
+/// Computes each off-diagonal product a[i] * a[j] (i < j) once, doubles them,
+/// then adds the diagonal squares: 10 limb multiplications instead of 16.
+#[inline]
+fn montgomery_square(a: ScalarField) -> ScalarField {
+    let a = a.limbs;
+    let mut t = [0u64; 8];
+
+    // Off-diagonal products
+    for i in 0..3 {
+        let mut carry = 0u128;
+        for j in (i + 1)..4 {
+            let product = (a[i] as u128) * (a[j] as u128) + (t[i + j] as u128) + carry;
+            t[i + j] = product as u64;
+            carry = product >> 64;
+        }
+        t[i + 4] = carry as u64;
+    }
+
+    // Double them
+    t[7] = t[6] >> 63;
+    for k in (2..7).rev() {
+        t[k] = (t[k] << 1) | (t[k - 1] >> 63);
+    }
+    t[1] <<= 1;
+
+    // Add the diagonal squares
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let square = (a[i] as u128) * (a[i] as u128);
+        let lo = (t[2 * i] as u128) + (square as u64 as u128) + carry;
+        t[2 * i] = lo as u64;
+        let hi = (t[2 * i + 1] as u128) + (square >> 64) + (lo >> 64);
+        t[2 * i + 1] = hi as u64;
+        carry = hi >> 64;
+    }
+
+    montgomery_reduce(t)
+}
+
+/// Montgomery reduction of a 512-bit product: (t * R^{-1}) mod p
+#[inline]
+fn montgomery_reduce(mut t: [u64; 8]) -> ScalarField {
     for i in 0..4 {
         let k = t[i].wrapping_mul(MU);
         let mut carry = 0u128;
@@ -227,8 +671,12 @@ fn montgomery_mul(a: ScalarField, b: ScalarField) -> ScalarField {
     }
 
     // Extract high half and conditionally subtract p
-    let result = [t[4], t[5], t[6], t[7]];
+    reduce_once([t[4], t[5], t[6], t[7]])
+}
 
+/// Subtracts `p` from a value below `2p` if it is at least `p`.
+#[inline]
+fn reduce_once(result: [u64; 4]) -> ScalarField {
     // Check if result >= p
     let (_, borrow) = result[0].overflowing_sub(MODULUS[0]);
     let (_, borrow) = borrowing_sub(result[1], MODULUS[1], borrow);
@@ -262,6 +710,11 @@ impl PrimeCharacteristicRing for ScalarField {
         elem
     }
 
+    #[inline]
+    fn double(&self) -> Self {
+        Self::double(self)
+    }
+
     #[inline]
     fn halve(&self) -> Self {
         // Compute (self + p) / 2 if odd, else self / 2
@@ -343,7 +796,7 @@ impl Field for ScalarField {
         if *self == Self::ZERO {
             None
         } else {
-            Some(self.inverse())
+            Some(self.inverse_ct())
         }
     }
 
@@ -606,7 +1059,7 @@ impl Div for ScalarField {
 
     #[inline]
     fn div(self, rhs: Self) -> Self {
-        self * rhs.inverse()
+        self * rhs.inverse_ct()
     }
 }
 
@@ -647,6 +1100,21 @@ impl Debug for ScalarField {
     }
 }
 
+// Serde uses the canonical byte encoding so deserialization can reject values >= p
+impl Serialize for ScalarField {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_bytes().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScalarField {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = <[u8; 32]>::deserialize(deserializer)?;
+        Self::from_bytes(&bytes)
+            .ok_or_else(|| de::Error::custom("non-canonical scalar field element"))
+    }
+}
+
 impl Hash for ScalarField {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.limbs.hash(state);
@@ -659,11 +1127,120 @@ impl ScalarField {
     pub fn inverse(&self) -> Self {
         // p - 2 for Fermat's little theorem
         let exp = sub_mod(MODULUS, [2, 0, 0, 0]);
-        self.pow_vartime(exp)
+        self.pow(&exp)
+    }
+
+    /// Compute multiplicative inverse in constant time (safegcd)
+    ///
+    /// Produces the same result as [`ScalarField::inverse`] without branching on the
+    /// operand. Returns zero for a zero input.
+    pub fn inverse_ct(&self) -> Self {
+        // limbs hold a*R, so the raw inverse is a^{-1}*R^{-1}; multiply by R^3 to get a^{-1}*R
+        let inv = inverse_limbs_ct(self.limbs);
+        montgomery_mul(ScalarField { limbs: inv }, ScalarField { limbs: R3 })
+    }
+
+    /// Invert every element of `elems` in place using Montgomery's trick.
+    ///
+    /// Costs a single field inversion plus `3(n-1)` multiplications. Zero elements
+    /// have no inverse; they are skipped and left as zero.
+    pub fn batch_inverse(elems: &mut [Self]) {
+        let mut prefix = Vec::with_capacity(elems.len());
+        let mut acc = Self::ONE;
+        for elem in elems.iter() {
+            prefix.push(acc);
+            if !elem.is_zero() {
+                acc *= *elem;
+            }
+        }
+
+        let mut inv = acc.inverse_ct();
+        for (elem, prefix) in elems.iter_mut().zip(prefix).rev() {
+            if elem.is_zero() {
+                continue;
+            }
+            let next_inv = inv * *elem;
+            *elem = inv * prefix;
+            inv = next_inv;
+        }
+    }
+
+    /// Compute a square root using Tonelli-Shanks.
+    ///
+    /// Returns the root with the smaller canonical value, or `None` if `self` is a
+    /// quadratic non-residue. Variable time.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.is_zero() {
+            return Some(Self::ZERO);
+        }
+
+        // x = a^{(t+1)/2}, b = a^t
+        let w = self.pow(&T_MINUS_ONE_DIV_TWO);
+        let mut x = *self * w;
+        let mut b = x * w;
+        let mut z = ScalarField {
+            limbs: ROOT_OF_UNITY,
+        };
+        let mut v = TWO_ADICITY;
+
+        while b != Self::ONE {
+            // Find the least k with b^(2^k) = 1
+            let mut k = 0;
+            let mut b2k = b;
+            while b2k != Self::ONE {
+                b2k = b2k.square();
+                k += 1;
+                if k == v {
+                    return None;
+                }
+            }
+
+            let mut w = z;
+            for _ in 0..(v - k - 1) {
+                w = w.square();
+            }
+            z = w.square();
+            b *= z;
+            x *= w;
+            v = k;
+        }
+
+        let neg = -x;
+        let x_canonical = x.to_canonical_u64_vec();
+        let neg_canonical = neg.to_canonical_u64_vec();
+        if neg_canonical.iter().rev().lt(x_canonical.iter().rev()) {
+            Some(neg)
+        } else {
+            Some(x)
+        }
+    }
+
+    /// Legendre symbol of this element: `0` for zero, `1` for a nonzero square
+    /// and `-1` for a non-residue.
+    ///
+    /// Computed with Euler's criterion `self^((p - 1) / 2)`, which is cheaper
+    /// than [`ScalarField::sqrt`] when only residuosity is needed. Variable time.
+    pub fn legendre(&self) -> i8 {
+        let symbol = self.pow(&P_MINUS_ONE_DIV_TWO);
+        if symbol.is_zero() {
+            0
+        } else if symbol == Self::ONE {
+            1
+        } else {
+            -1
+        }
     }
 
-    /// Variable-time exponentiation
-    fn pow_vartime(&self, exp: [u64; 4]) -> Self {
+    /// Raise this element to the power `exp`, given as four little-endian
+    /// 64-bit limbs of the canonical exponent.
+    ///
+    /// Variable time: the sequence of multiplications depends on the bits of
+    /// `exp`. Use [`ScalarField::pow_ct`] when the exponent is secret.
+    /// `x.pow(&[0; 4])` is one for every `x`, including zero.
+    pub fn pow(&self, exp: &[u64; 4]) -> Self {
+        if *exp == [0; 4] {
+            return Self::ONE;
+        }
         if self.is_zero() {
             return Self::ZERO;
         }
@@ -678,7 +1255,7 @@ impl ScalarField {
                 if remaining & 1 == 1 {
                     result *= base;
                 }
-                base = base * base;
+                base = base.square();
                 remaining >>= 1;
             }
         }
@@ -686,6 +1263,52 @@ impl ScalarField {
         result
     }
 
+    /// Constant-time counterpart of [`ScalarField::pow`].
+    ///
+    /// Squares and multiplies for all 256 exponent bits and keeps each product
+    /// with a masked select, so the operation sequence is independent of both
+    /// `self` and `exp`.
+    pub fn pow_ct(&self, exp: &[u64; 4]) -> Self {
+        let mut result = Self::ONE;
+        let mut base = *self;
+
+        for &limb in exp.iter() {
+            for i in 0..64 {
+                let product = result * base;
+                let mask = 0u64.wrapping_sub((limb >> i) & 1);
+                result = ScalarField {
+                    limbs: ct_select(result.limbs, product.limbs, mask),
+                };
+                base = base.square();
+            }
+        }
+
+        result
+    }
+
+    /// Square this element using the dedicated Montgomery squaring routine
+    #[inline]
+    pub fn square(&self) -> Self {
+        montgomery_square(*self)
+    }
+
+    /// Double this element with a single modular addition
+    #[inline]
+    pub fn double(&self) -> Self {
+        ScalarField {
+            limbs: add_mod(self.limbs, self.limbs),
+        }
+    }
+
+    /// Equality without early exit, for comparing secret scalars.
+    ///
+    /// Agrees with `==`; every limb is read whatever the first difference.
+    #[inline]
+    pub fn ct_eq(&self, other: &Self) -> bool {
+        let diff = (0..4).fold(0u64, |acc, i| acc | (self.limbs[i] ^ other.limbs[i]));
+        (diff | diff.wrapping_neg()) >> 63 == 0
+    }
+
     /// Check if this field element is zero
     #[inline]
     pub fn is_zero(&self) -> bool {
@@ -696,6 +1319,8 @@ impl ScalarField {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
 
     #[test]
     fn test_zero_one() {
@@ -742,4 +1367,455 @@ mod tests {
         let a_inv = a.inverse();
         assert_eq!(a * a_inv, ScalarField::ONE);
     }
+
+    #[test]
+    fn test_inverse_ct_matches_fermat() {
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..256 {
+            let a: ScalarField = rng.random();
+            if a.is_zero() {
+                continue;
+            }
+            assert_eq!(a.inverse_ct(), a.inverse());
+            assert_eq!(a * a.inverse_ct(), ScalarField::ONE);
+        }
+
+        let neg_one = -ScalarField::ONE;
+        assert_eq!(neg_one.inverse_ct(), neg_one.inverse());
+        assert_eq!(ScalarField::ONE.inverse_ct(), ScalarField::ONE);
+    }
+
+    #[test]
+    fn test_inverse_ct_zero() {
+        assert_eq!(ScalarField::ZERO.inverse_ct(), ScalarField::ZERO);
+        assert_eq!(ScalarField::ZERO.try_inverse(), None);
+    }
+
+    #[test]
+    fn test_division_uses_inverse() {
+        let a = ScalarField::from_canonical_u64(42);
+        let b = ScalarField::from_canonical_u64(6);
+        assert_eq!(a / b, ScalarField::from_canonical_u64(7));
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..64 {
+            let a: ScalarField = rng.random();
+            assert_eq!(ScalarField::from_bytes(&a.to_bytes()), Some(a));
+        }
+
+        let bytes = ScalarField::from_canonical_u64(5).to_bytes();
+        assert_eq!(bytes[0], 5);
+        assert!(bytes[1..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_non_canonical() {
+        let mut modulus = [0u8; 32];
+        for (chunk, limb) in modulus.chunks_exact_mut(8).zip(MODULUS.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        assert_eq!(ScalarField::from_bytes(&modulus), None);
+
+        let mut modulus_plus_one = modulus;
+        modulus_plus_one[0] += 1;
+        assert_eq!(ScalarField::from_bytes(&modulus_plus_one), None);
+
+        assert_eq!(ScalarField::from_bytes(&[0xff; 32]), None);
+    }
+
+    #[test]
+    fn test_be_bytes_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(64);
+        for _ in 0..64 {
+            let a: ScalarField = rng.random();
+            assert_eq!(ScalarField::from_be_bytes(&a.to_be_bytes()), Some(a));
+        }
+
+        let bytes = ScalarField::from_canonical_u64(0x0102).to_be_bytes();
+        assert_eq!(bytes[30..], [1, 2]);
+        assert!(bytes[..30].iter().all(|&b| b == 0));
+
+        let mut modulus = [0u8; 32];
+        for (chunk, limb) in modulus.chunks_exact_mut(8).zip(MODULUS.iter().rev()) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+        assert_eq!(ScalarField::from_be_bytes(&modulus), None);
+        modulus[31] -= 1;
+        assert!(ScalarField::from_be_bytes(&modulus).is_some());
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        let mut rng = StdRng::seed_from_u64(101);
+        for _ in 0..64 {
+            let a: ScalarField = rng.random();
+            let b: ScalarField = rng.random();
+            assert!(a.ct_eq(&a));
+            assert_eq!(a.ct_eq(&b), a == b);
+        }
+
+        // A difference in any single limb is detected
+        let a = -ScalarField::ONE;
+        for i in 0..4 {
+            let mut b = a;
+            b.limbs[i] ^= 1 << 17;
+            assert!(!a.ct_eq(&b));
+            assert!(!b.ct_eq(&a));
+        }
+        assert!(ScalarField::ZERO.ct_eq(&ScalarField::ZERO));
+        assert!(!ScalarField::ZERO.ct_eq(&ScalarField::ONE));
+    }
+
+    #[test]
+    fn test_try_from_canonical_limbs() {
+        let mut rng = StdRng::seed_from_u64(99);
+        for _ in 0..64 {
+            let a: ScalarField = rng.random();
+            assert_eq!(
+                ScalarField::try_from_canonical_limbs(a.to_canonical_u64_vec()),
+                Some(a)
+            );
+        }
+
+        let mut limbs = MODULUS;
+        assert_eq!(ScalarField::try_from_canonical_limbs(limbs), None);
+        assert_eq!(ScalarField::try_from_canonical_limbs([u64::MAX; 4]), None);
+        // The unchecked constructor silently reduces p to zero
+        assert_eq!(ScalarField::from_canonical_limbs(limbs), ScalarField::ZERO);
+
+        limbs[0] -= 1;
+        assert_eq!(
+            ScalarField::try_from_canonical_limbs(limbs),
+            Some(-ScalarField::ONE)
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_mod_order() {
+        let mut rng = StdRng::seed_from_u64(85);
+        for _ in 0..64 {
+            let a: ScalarField = rng.random();
+            assert_eq!(ScalarField::from_bytes_mod_order(&a.to_bytes()), a);
+        }
+
+        // p + 5 reduces to 5
+        let mut limbs = MODULUS;
+        limbs[0] += 5;
+        let mut bytes = [0u8; 32];
+        for (chunk, limb) in bytes.chunks_exact_mut(8).zip(limbs.iter()) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        assert_eq!(ScalarField::from_bytes(&bytes), None);
+        let reduced = ScalarField::from_bytes_mod_order(&bytes);
+        assert!(reduced.is_canonical());
+        assert_eq!(reduced, ScalarField::from_canonical_u64(5));
+
+        // 2^256 - 1 = sum of (2^64 - 1) * 2^(64 i)
+        let word = ScalarField::from_canonical_u64(u64::MAX);
+        let shift = ScalarField::from_canonical_u64(1 << 32).square();
+        let expected = (0..4).fold(ScalarField::ZERO, |acc, _| acc * shift + word);
+        let reduced = ScalarField::from_bytes_mod_order(&[0xff; 32]);
+        assert!(reduced.is_canonical());
+        assert_eq!(reduced, expected);
+    }
+
+    #[test]
+    fn test_montgomery_mul_matches_schoolbook() {
+        let mut rng = StdRng::seed_from_u64(66);
+        let edge = [
+            ScalarField::ZERO,
+            ScalarField::ONE,
+            -ScalarField::ONE,
+            ScalarField {
+                limbs: [u64::MAX, u64::MAX, u64::MAX, 0],
+            },
+        ];
+        for a in edge {
+            for b in edge {
+                assert_eq!(montgomery_mul(a, b), montgomery_mul_schoolbook(a, b));
+            }
+        }
+        for _ in 0..1000 {
+            let a: ScalarField = rng.random();
+            let b: ScalarField = rng.random();
+            assert_eq!(montgomery_mul(a, b), montgomery_mul_schoolbook(a, b));
+        }
+    }
+
+    #[test]
+    fn test_pow_and_pow_ct() {
+        let mut rng = StdRng::seed_from_u64(67);
+        let p_minus_two = sub_mod(MODULUS, [2, 0, 0, 0]);
+        let k = [5, 0, 0, 0];
+
+        for _ in 0..16 {
+            let a: ScalarField = rng.random();
+            assert_eq!(a.pow(&p_minus_two), a.inverse());
+            assert_eq!(a.pow_ct(&p_minus_two), a.inverse());
+            assert_eq!(a.pow(&[0; 4]), ScalarField::ONE);
+            assert_eq!(a.pow_ct(&[0; 4]), ScalarField::ONE);
+            assert_eq!(a.pow(&k), a * a * a * a * a);
+            assert_eq!(a.pow_ct(&k), a.pow(&k));
+
+            let e: [u64; 4] = rng.random::<ScalarField>().to_canonical_u64_vec();
+            assert_eq!(a.pow_ct(&e), a.pow(&e));
+        }
+
+        assert_eq!(ScalarField::ZERO.pow(&[0; 4]), ScalarField::ONE);
+        assert_eq!(ScalarField::ZERO.pow_ct(&[0; 4]), ScalarField::ONE);
+        assert_eq!(ScalarField::ZERO.pow(&k), ScalarField::ZERO);
+        assert_eq!(ScalarField::ZERO.pow_ct(&k), ScalarField::ZERO);
+    }
+
+    #[test]
+    fn test_legendre() {
+        assert_eq!(ScalarField::ZERO.legendre(), 0);
+        assert_eq!(ScalarField::ONE.legendre(), 1);
+        assert_eq!(ScalarField::from_canonical_u64(5).legendre(), -1);
+        // (p - 1) / 2 is even, so -1 is a square and negation keeps residuosity
+        assert_eq!(P_MINUS_ONE_DIV_TWO[0] & 1, 0);
+        assert_eq!((-ScalarField::ONE).legendre(), 1);
+
+        let mut rng = StdRng::seed_from_u64(68);
+        for _ in 0..32 {
+            let a: ScalarField = rng.random();
+            let square = a * a;
+            assert_eq!(square.legendre(), 1);
+            assert_eq!((-square).legendre(), 1);
+            assert_eq!(a.legendre() == 1, a.sqrt().is_some());
+            assert_eq!((-a).legendre(), a.legendre());
+        }
+    }
+
+    #[test]
+    fn test_two_adic_generator() {
+        assert_eq!(ScalarField::TWO_ADICITY, 7);
+        assert_eq!(ScalarField::two_adic_generator(0), ScalarField::ONE);
+        assert_eq!(ScalarField::two_adic_generator(1), -ScalarField::ONE);
+
+        for bits in 1..=7 {
+            let g = ScalarField::two_adic_generator(bits);
+            let half = (0..bits - 1).fold(g, |acc, _| acc.square());
+            assert_ne!(half, ScalarField::ONE);
+            assert_eq!(half.square(), ScalarField::ONE);
+            assert_eq!(g.square(), ScalarField::two_adic_generator(bits - 1));
+        }
+        assert!(std::panic::catch_unwind(|| ScalarField::two_adic_generator(8)).is_err());
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let elems: Vec<ScalarField> = (0..32).map(|_| rng.random()).collect();
+        let mut batch = elems.clone();
+        ScalarField::batch_inverse(&mut batch);
+
+        for (a, a_inv) in elems.iter().zip(batch.iter()) {
+            assert_eq!(*a_inv, a.inverse());
+        }
+    }
+
+    #[test]
+    fn test_batch_inverse_skips_zero() {
+        let a = ScalarField::from_canonical_u64(3);
+        let b = ScalarField::from_canonical_u64(11);
+        let mut elems = [a, ScalarField::ZERO, b, ScalarField::ZERO];
+        ScalarField::batch_inverse(&mut elems);
+
+        assert_eq!(
+            elems,
+            [
+                a.inverse(),
+                ScalarField::ZERO,
+                b.inverse(),
+                ScalarField::ZERO
+            ]
+        );
+
+        let mut empty: [ScalarField; 0] = [];
+        ScalarField::batch_inverse(&mut empty);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let mut rng = StdRng::seed_from_u64(11);
+        for _ in 0..64 {
+            let a: ScalarField = rng.random();
+            let square = a * a;
+            let root = square.sqrt().expect("square has a root");
+            assert_eq!(root * root, square);
+            assert!(root == a || root == -a);
+        }
+
+        assert_eq!(ScalarField::ZERO.sqrt(), Some(ScalarField::ZERO));
+        assert_eq!(
+            ScalarField::from_canonical_u64(4).sqrt(),
+            Some(ScalarField::from_canonical_u64(2))
+        );
+    }
+
+    #[test]
+    fn test_sqrt_non_residue() {
+        // 5 generates the multiplicative group, so it is not a square
+        assert_eq!(ScalarField::from_canonical_u64(5).sqrt(), None);
+        assert_eq!(ScalarField::GENERATOR.sqrt(), None);
+    }
+
+    #[test]
+    fn test_square_matches_mul() {
+        let mut rng = StdRng::seed_from_u64(5);
+        for _ in 0..256 {
+            let a: ScalarField = rng.random();
+            assert_eq!(a.square(), a * a);
+        }
+
+        let neg_one = -ScalarField::ONE;
+        assert_eq!(neg_one.square(), ScalarField::ONE);
+        assert_eq!(ScalarField::ZERO.square(), ScalarField::ZERO);
+    }
+
+    #[test]
+    fn test_double_matches_add() {
+        let mut rng = StdRng::seed_from_u64(95);
+        for _ in 0..256 {
+            let a: ScalarField = rng.random();
+            assert_eq!(a.double(), a + a);
+        }
+
+        // Wraps around the modulus
+        let neg_one = -ScalarField::ONE;
+        assert_eq!(neg_one.double(), -ScalarField::TWO);
+        assert_eq!(ScalarField::ONE.double(), ScalarField::TWO);
+        assert_eq!(ScalarField::ZERO.double(), ScalarField::ZERO);
+    }
+
+    #[test]
+    fn test_wnaf_reconstructs_scalar() {
+        let mut rng = StdRng::seed_from_u64(24);
+        let mut scalars: Vec<ScalarField> = (0..32).map(|_| rng.random()).collect();
+        scalars.extend([ScalarField::ZERO, ScalarField::ONE, -ScalarField::ONE]);
+
+        for w in 2..=8 {
+            for s in &scalars {
+                let digits = s.to_wnaf(w);
+                let bound = 1i16 << (w - 1);
+                let mut acc = ScalarField::ZERO;
+                for (i, &d) in digits.iter().enumerate().rev() {
+                    assert!(d == 0 || (d % 2 != 0 && (d as i16).abs() < bound));
+                    if d != 0 {
+                        let window = &digits[i + 1..digits.len().min(i + w)];
+                        assert!(window.iter().all(|&x| x == 0));
+                    }
+                    let magnitude = ScalarField::from_canonical_u64(d.unsigned_abs() as u64);
+                    acc = acc.double() + if d < 0 { -magnitude } else { magnitude };
+                }
+                assert_eq!(acc, *s, "w = {w}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_from_uniform_bytes_matches_integer_reduction() {
+        let mut rng = StdRng::seed_from_u64(27);
+
+        // Short canonical input is read as-is
+        let x: ScalarField = rng.random();
+        assert_eq!(ScalarField::from_uniform_bytes(&x.to_bytes()), x);
+        assert_eq!(ScalarField::from_uniform_bytes(&[]), ScalarField::ZERO);
+        assert_eq!(
+            ScalarField::from_uniform_bytes(&[5]),
+            ScalarField::from_canonical_u64(5)
+        );
+
+        // 64 bytes: lo + hi * 2^256
+        let mut bytes = [0u8; 64];
+        rng.fill(&mut bytes[..]);
+        let limbs = |b: &[u8]| -> [u64; 4] {
+            core::array::from_fn(|i| u64::from_le_bytes(b[i * 8..(i + 1) * 8].try_into().unwrap()))
+        };
+        let lo = ScalarField::from_canonical_limbs(limbs(&bytes[..32]));
+        let hi = ScalarField::from_canonical_limbs(limbs(&bytes[32..]));
+        let two_128 = ScalarField::from_canonical_limbs([0, 0, 1, 0]);
+        let expected = lo + hi * two_128 * two_128;
+        assert_eq!(ScalarField::from_uniform_bytes(&bytes), expected);
+
+        // All-ones input is 2^512 - 1
+        let expected = ScalarField { limbs: R2 } * ScalarField { limbs: R2 } - ScalarField::ONE;
+        assert_eq!(ScalarField::from_uniform_bytes(&[0xff; 64]), expected);
+    }
+
+    #[test]
+    fn test_from_uniform_bytes_distribution() {
+        const SAMPLES: usize = 4096;
+        const BUCKETS: usize = 16;
+
+        let mut rng = StdRng::seed_from_u64(2727);
+        let mut counts = [0usize; BUCKETS];
+        let mut odd = 0usize;
+        for _ in 0..SAMPLES {
+            let mut bytes = [0u8; 48];
+            rng.fill(&mut bytes[..]);
+            let limbs = ScalarField::from_uniform_bytes(&bytes).to_canonical_u64_vec();
+            // Bucket by position within [0, p) using the top limb
+            let bucket = (limbs[3] as u128 * BUCKETS as u128 / (MODULUS[3] as u128 + 1)) as usize;
+            counts[bucket] += 1;
+            odd += (limbs[0] & 1) as usize;
+        }
+
+        // Chi-squared with 15 degrees of freedom; 50 is far beyond the 0.9999 quantile
+        let expected = (SAMPLES / BUCKETS) as f64;
+        let chi2: f64 = counts
+            .iter()
+            .map(|&c| (c as f64 - expected).powi(2) / expected)
+            .sum();
+        assert!(chi2 < 50.0, "chi2 = {chi2}, counts = {counts:?}");
+        assert!((SAMPLES / 2).abs_diff(odd) < 256, "odd = {odd}");
+    }
+
+    #[test]
+    fn test_bit_length() {
+        assert_eq!(ScalarField::ZERO.bit_length(), 0);
+        assert_eq!(ScalarField::ONE.bit_length(), 1);
+        assert_eq!(ScalarField::from_canonical_u64(u64::MAX).bit_length(), 64);
+        assert_eq!(
+            ScalarField::from_canonical_limbs([0, 1, 0, 0]).bit_length(),
+            65
+        );
+        assert_eq!((-ScalarField::ONE).bit_length(), 248);
+    }
+
+    #[test]
+    fn test_parity_and_bits() {
+        let five = ScalarField::from_canonical_u64(5);
+        assert!(five.is_odd());
+        assert!(five.bit(0) && !five.bit(1) && five.bit(2));
+        assert_eq!(five.num_bits(), 3);
+
+        let four = ScalarField::from_canonical_u64(4);
+        assert!(!four.is_odd());
+        assert!(!four.bit(0) && !four.bit(1) && four.bit(2));
+        assert_eq!(four.num_bits(), 3);
+
+        assert!(!ScalarField::ZERO.is_odd());
+        assert_eq!(ScalarField::ZERO.num_bits(), 0);
+
+        // p - 1 is even; p is odd
+        let minus_one = -ScalarField::ONE;
+        assert!(!minus_one.is_odd());
+        assert!(minus_one.bit(247));
+        assert!(!minus_one.bit(248));
+        assert!(!minus_one.bit(256));
+
+        let high = ScalarField::from_canonical_limbs([0, 1, 0, 0]);
+        assert!(high.bit(64) && !high.bit(0) && !high.bit(63));
+
+        for x in [five, four, minus_one, high] {
+            let bits = x.to_bits_le();
+            assert_eq!(bits.len(), 256);
+            assert!(bits.iter().enumerate().all(|(i, &b)| b == x.bit(i)));
+        }
+    }
 }
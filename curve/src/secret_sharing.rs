@@ -0,0 +1,153 @@
+//! Shamir secret sharing and Lagrange interpolation over the scalar field.
+//!
+//! A secret `s` is split with a random polynomial `f` of degree `t - 1` with
+//! `f(0) = s`; share `i` is `(i, f(i))` for `i = 1..=n`. Any `t` shares recover
+//! `s = sum(lambda_i * f(i))` with the Lagrange coefficients at zero, while
+//! `t - 1` shares reveal nothing about it. These are the building blocks for
+//! t-of-n threshold Schnorr, where the same coefficients combine partial
+//! signatures or public key shares.
+
+use alloc::vec::Vec;
+use p3_field::Field;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::{RandomField, ScalarField};
+
+/// One share `(index, f(index))` of a secret split with [`split`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Share {
+    /// The nonzero evaluation point
+    pub index: u64,
+    /// The polynomial evaluated at `index`
+    pub value: ScalarField,
+}
+
+/// Lagrange coefficient of `index` for interpolating at zero over `indices`:
+/// `prod(x_j / (x_j - x_i))` over every `x_j` in `indices` other than `index`.
+///
+/// `indices` may contain `index` itself, which is skipped.
+///
+/// # Panics
+///
+/// Panics if `indices` contains a value twice.
+pub fn lagrange_coefficient(index: u64, indices: &[u64]) -> ScalarField {
+    let x_i = ScalarField::from_canonical_u64(index);
+    let mut num = ScalarField::ONE;
+    let mut den = ScalarField::ONE;
+    for &other in indices.iter().filter(|&&other| other != index) {
+        let x_j = ScalarField::from_canonical_u64(other);
+        num *= x_j;
+        den *= x_j - x_i;
+    }
+    num * den
+        .try_inverse()
+        .expect("interpolation indices must be distinct")
+}
+
+/// Evaluates `coeffs[0] + coeffs[1]·x + ...` at `x` with Horner's rule.
+///
+/// An empty slice is the zero polynomial.
+pub fn evaluate_polynomial(coeffs: &[ScalarField], x: ScalarField) -> ScalarField {
+    coeffs
+        .iter()
+        .rev()
+        .fold(ScalarField::ZERO, |acc, &c| acc * x + c)
+}
+
+/// Splits `secret` into `n` shares, any `threshold` of which recover it.
+///
+/// # Panics
+///
+/// Panics if `threshold` is zero or greater than `n`.
+pub fn split<R: Rng + ?Sized>(
+    secret: ScalarField,
+    threshold: usize,
+    n: usize,
+    rng: &mut R,
+) -> Vec<Share> {
+    assert!(
+        (1..=n).contains(&threshold),
+        "threshold must be between 1 and the number of shares"
+    );
+
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(secret);
+    coeffs.extend((1..threshold).map(|_| ScalarField::random(rng)));
+
+    (1..=n as u64)
+        .map(|index| Share {
+            index,
+            value: evaluate_polynomial(&coeffs, ScalarField::from_canonical_u64(index)),
+        })
+        .collect()
+}
+
+/// Interpolates `shares` at zero.
+///
+/// Returns the secret if `shares` holds at least the threshold number of
+/// distinct shares of it; fewer shares give an unrelated scalar.
+///
+/// # Panics
+///
+/// Panics if two shares have the same index.
+pub fn reconstruct(shares: &[Share]) -> ScalarField {
+    let indices: Vec<u64> = shares.iter().map(|share| share.index).collect();
+    shares
+        .iter()
+        .map(|share| lagrange_coefficient(share.index, &indices) * share.value)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_reconstruct_from_threshold_shares() {
+        let mut rng = StdRng::seed_from_u64(59);
+        let secret = ScalarField::random(&mut rng);
+        let shares = split(secret, 3, 5, &mut rng);
+        assert_eq!(shares.len(), 5);
+
+        assert_eq!(reconstruct(&shares[..3]), secret);
+        assert_eq!(reconstruct(&shares[2..]), secret);
+        assert_eq!(reconstruct(&[shares[4], shares[0], shares[2]]), secret);
+        assert_eq!(reconstruct(&shares), secret);
+
+        assert_ne!(reconstruct(&shares[..2]), secret);
+        assert_ne!(reconstruct(&[shares[1], shares[3]]), secret);
+    }
+
+    #[test]
+    fn test_polynomial_and_lagrange_helpers() {
+        let c = [2, 3, 5].map(ScalarField::from_canonical_u64);
+        // 2 + 3·4 + 5·16
+        assert_eq!(
+            evaluate_polynomial(&c, ScalarField::from_canonical_u64(4)),
+            ScalarField::from_canonical_u64(94)
+        );
+        assert_eq!(
+            evaluate_polynomial(&[], ScalarField::ONE),
+            ScalarField::ZERO
+        );
+
+        let indices = [1, 2, 3];
+        let sum: ScalarField = indices
+            .iter()
+            .map(|&i| lagrange_coefficient(i, &indices))
+            .sum();
+        assert_eq!(sum, ScalarField::ONE);
+        // lambda_1 over {1, 2} is 2 / (2 - 1)
+        assert_eq!(
+            lagrange_coefficient(1, &[1, 2]),
+            ScalarField::from_canonical_u64(2)
+        );
+
+        let secret = ScalarField::from_canonical_u64(42);
+        let shares = split(secret, 1, 3, &mut StdRng::seed_from_u64(0));
+        assert!(shares.iter().all(|share| share.value == secret));
+    }
+}
@@ -3,7 +3,7 @@ use p3_baby_bear::BabyBear;
 use p3_field::PrimeCharacteristicRing;
 use rand::SeedableRng;
 use rand::rngs::StdRng;
-use schnorr::{SigningKey, VerifyingKey};
+use schnorr::{Signature, SigningKey, VerifyingKey};
 
 fn bench_sign(c: &mut Criterion) {
     let mut rng = StdRng::seed_from_u64(42);
@@ -41,5 +41,41 @@ fn bench_verify(c: &mut Criterion) {
     });
 }
 
-criterion_group!(benches, bench_sign, bench_verify);
+fn bench_verify_batch(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let msg = [
+        BabyBear::from_u32(1),
+        BabyBear::from_u32(2),
+        BabyBear::from_u32(3),
+    ];
+    let signed: Vec<(VerifyingKey, Signature)> = (0..16)
+        .map(|_| {
+            let sk = SigningKey::random(&mut rng);
+            (sk.verifying_key(), sk.sign(&mut rng, &msg).expect("sign"))
+        })
+        .collect();
+    let items: Vec<(VerifyingKey, &[BabyBear], &Signature)> = signed
+        .iter()
+        .map(|(vk, sig)| (*vk, &msg[..], sig))
+        .collect();
+
+    let mut group = c.benchmark_group("schnorr_verify_16");
+    group.bench_function("looped", |bencher| {
+        bencher.iter(|| {
+            let ok = items
+                .iter()
+                .all(|(vk, msg, sig)| vk.verify(msg, sig).expect("verify"));
+            black_box(ok);
+        })
+    });
+    group.bench_function("batch", |bencher| {
+        bencher.iter(|| {
+            let ok = VerifyingKey::verify_batch(black_box(&items)).expect("verify");
+            black_box(ok);
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_sign, bench_verify, bench_verify_batch);
 criterion_main!(benches);
@@ -0,0 +1,37 @@
+//! Serde `with` module that encodes a [`VerifyingKey`] as its 33-byte
+//! compressed point instead of the full 65-byte form.
+//!
+//! Decoding takes an Fp8 square root, so the compact form trades CPU time for
+//! size; keep the default encoding where keys are read often.
+//!
+//! ```
+//! use schnorr::{SigningKey, VerifyingKey};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Account {
+//!     #[serde(with = "schnorr::compressed_key")]
+//!     key: VerifyingKey,
+//! }
+//!
+//! let key = SigningKey::random(&mut rand::thread_rng()).verifying_key();
+//! let bytes = bincode::serialize(&Account { key }).unwrap();
+//! assert_eq!(bytes.len(), curve::compressed::COMPRESSED_SIZE);
+//! ```
+
+use curve::CompressedAffine;
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::keys::VerifyingKey;
+
+/// Serialize `key` as a [`CompressedAffine`].
+pub fn serialize<S: Serializer>(key: &VerifyingKey, serializer: S) -> Result<S::Ok, S::Error> {
+    CompressedAffine(key.as_affine()).serialize(serializer)
+}
+
+/// Deserialize a key written by [`serialize`], rejecting the point at infinity.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<VerifyingKey, D::Error> {
+    let CompressedAffine(point) = CompressedAffine::deserialize(deserializer)?;
+    VerifyingKey::from_affine(point)
+        .map_err(|_| de::Error::custom("verifying key is not a valid curve point"))
+}
@@ -3,8 +3,9 @@
 /// Size of a serialized public verifying key in bytes.
 ///
 /// A verifying key is a point on the KoalaBear curve (Fp8 extension),
-/// requiring 40 bytes when serialized.
-pub const PK_SIZE: usize = 40;
+/// encoded as its `x` then `y` coordinate, each as 8 canonical
+/// little-endian `u32` coefficients: 64 bytes.
+pub const PK_SIZE: usize = 64;
 
 /// Size of a serialized secret signing key in bytes.
 ///
@@ -15,10 +16,10 @@ pub const SK_SIZE: usize = 32;
 /// Size of a serialized signature in bytes.
 ///
 /// A signature consists of:
-/// - A point R (40 bytes)
+/// - A point R (64 bytes, encoded like a verifying key)
 /// - A scalar s (32 bytes)
-///   Total: 72 bytes
-pub const SIG_SIZE: usize = 72;
+///   Total: 96 bytes
+pub const SIG_SIZE: usize = PK_SIZE + SK_SIZE;
 
 /// Width parameter for the Poseidon2 permutation.
 ///
@@ -35,3 +36,52 @@ pub(crate) const POSEIDON2_RATE: usize = 8;
 ///
 /// This is the number of field elements in the hash digest.
 pub(crate) const POSEIDON2_OUT: usize = 8;
+
+/// Number of sponge output elements reduced into a Fiat-Shamir challenge.
+///
+/// Two digest blocks (~494 bits) so the challenge is statistically uniform
+/// over the ~248-bit scalar field.
+pub const CHALLENGE_DIGEST_LEN: usize = 2 * POSEIDON2_OUT;
+
+/// Domain separation tag prepended to the input of deterministic nonce derivation.
+///
+/// Keeps nonce hashes distinct from challenge hashes over the same data.
+pub(crate) const NONCE_DOMAIN_TAG: u32 = 0x4e4f4e43; // "NONC"
+
+/// Domain separation tag prepended to the input of seed-based key derivation.
+pub(crate) const KEYGEN_DOMAIN_TAG: u32 = 0x4b455947; // "KEYG"
+
+/// Domain separation tag prepended to the challenge input of context-bound signatures.
+pub(crate) const CONTEXT_DOMAIN_TAG: u32 = 0x43545854; // "CTXT"
+
+/// Scheme identifier absorbed first by the x-only challenge (`SignatureScheme::XOnly`).
+pub(crate) const XONLY_SCHEME_TAG: u32 = 0x584f4e4c; // "XONL"
+
+/// Scheme identifier absorbed first by the recoverable challenge (`SignatureScheme::Recoverable`).
+pub(crate) const RECOVERABLE_SCHEME_TAG: u32 = 0x52435652; // "RCVR"
+
+/// Ordering identifier absorbed first by the message-first challenge
+/// (`ChallengeOrder::MessageFirst`).
+pub(crate) const MESSAGE_FIRST_ORDER_TAG: u32 = 0x4d534746; // "MSGF"
+
+/// Domain separation tag prepended to the challenge input of multi-message
+/// signatures (`SigningKey::sign_many`).
+pub(crate) const MULTI_MESSAGE_TAG: u32 = 0x4d4d5347; // "MMSG"
+
+/// Domain separation tag for hashing VRF inputs to the curve.
+pub(crate) const VRF_HASH_DOMAIN: &[u8] = b"schnorr-vrf-v1";
+
+/// Domain separation tag prepended to the VRF DLEQ challenge input.
+pub(crate) const VRF_CHALLENGE_TAG: u32 = 0x56524643; // "VRFC"
+
+/// Domain separation tag prepended to the VRF output hash input.
+pub(crate) const VRF_OUTPUT_TAG: u32 = 0x5652464f; // "VRFO"
+
+/// Domain separation tag prepended to the MuSig key-list hash `L`.
+pub(crate) const MUSIG_KEY_LIST_TAG: u32 = 0x4b4c5354; // "KLST"
+
+/// Domain separation tag prepended to the MuSig key-aggregation coefficient input.
+pub(crate) const MUSIG_COEFFICIENT_TAG: u32 = 0x4b434f45; // "KCOE"
+
+/// Domain separation tag prepended to MuSig nonce commitments.
+pub(crate) const MUSIG_NONCE_TAG: u32 = 0x4e434d54; // "NCMT"
@@ -1,5 +1,7 @@
 //! Error types for the Schnorr signature scheme.
 
+use core::fmt;
+
 /// Errors that can occur during signing and verification operations.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum SchnorrError {
@@ -12,5 +14,80 @@ pub enum SchnorrError {
     ///
     /// In practice, this error should be extremely rare for randomly generated keys
     /// and nonces, as the probability of generating the point at infinity is negligible.
-    InvalidPoint,
+    PointAtInfinity,
+
+    /// A point does not satisfy the curve equation.
+    ///
+    /// Returned for verifying keys and commitment points built from arbitrary
+    /// coordinates, and for byte encodings whose coefficients are not canonical
+    /// KoalaBear elements.
+    NotOnCurve,
+
+    /// A scalar was not the canonical encoding of a field element.
+    ///
+    /// Returned when the encoded integer is not below the scalar field order,
+    /// for example an `s` component of `p` or larger in a serialized signature.
+    InvalidScalar,
+
+    /// The input could not be encoded for hashing.
+    ///
+    /// Byte strings are length-prefixed with a single BabyBear element, so
+    /// inputs of `2^31 - 2^27 + 1` bytes or more are rejected.
+    HashError,
+
+    /// A byte slice did not have the length of the encoding it was parsed as.
+    ///
+    /// Returned by the `TryFrom<&[u8]>` conversions, which require exactly
+    /// `SK_SIZE`, `PK_SIZE` or `SIG_SIZE` bytes, and by hex parsers given a
+    /// string that is not exactly twice that many characters.
+    InvalidLength,
+
+    /// A hex string contained a character other than `0-9`, `a-f` or `A-F`.
+    InvalidHex,
+
+    /// A revealed MuSig nonce did not match its first-round commitment, or the
+    /// number of nonces differed from the number of commitments.
+    NonceCommitmentMismatch,
+
+    /// A MuSig partial signature was missing or did not verify for its key.
+    ///
+    /// Also returned when signing for a key list that does not contain the
+    /// signer's own key.
+    InvalidPartialSignature,
+
+    /// A signature commitment `R` had an odd y-coordinate.
+    ///
+    /// Signatures always carry the even-parity `R`, so that each has a single
+    /// encoding. Returned by `SigningKey::sign_prehashed` when `nonce * G` is
+    /// odd; negate the nonce and recompute the challenge.
+    OddCommitment,
+}
+
+impl SchnorrError {
+    /// Former catch-all for invalid points, kept for source compatibility.
+    ///
+    /// Equal to [`SchnorrError::PointAtInfinity`]; off-curve points are now
+    /// reported as [`SchnorrError::NotOnCurve`].
+    #[deprecated(note = "match on `PointAtInfinity` or `NotOnCurve` instead")]
+    #[allow(non_upper_case_globals)]
+    pub const InvalidPoint: Self = Self::PointAtInfinity;
 }
+
+impl fmt::Display for SchnorrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            Self::PointAtInfinity => "unexpected point at infinity",
+            Self::NotOnCurve => "point is not on the curve",
+            Self::InvalidScalar => "scalar is not canonically encoded",
+            Self::HashError => "input cannot be encoded for hashing",
+            Self::InvalidLength => "byte slice has the wrong length",
+            Self::InvalidHex => "string is not valid hex",
+            Self::NonceCommitmentMismatch => "nonce does not match its commitment",
+            Self::InvalidPartialSignature => "partial signature is missing or invalid",
+            Self::OddCommitment => "commitment point has an odd y-coordinate",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl std::error::Error for SchnorrError {}
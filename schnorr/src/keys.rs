@@ -1,12 +1,22 @@
 //! Signing and verifying keys for the Schnorr signature scheme.
 
+use std::collections::HashMap;
+
 use curve::{Affine, Group, RandomField, ScalarField};
 use p3_baby_bear::BabyBear;
 use rand::Rng;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, de};
 
+use crate::constants::{PK_SIZE, SK_SIZE};
 use crate::errors::SchnorrError;
-use crate::signatures::{Signature, hash_challenge};
+use crate::musig::{self, MusigNonce, PartialSignature};
+use crate::signatures::{
+    ChallengeHash, ChallengeHasher, HasherRole, MultiMessageChallenge, Poseidon2Challenge,
+    Signature, SignatureScheme, check_point, context_prefix, derive_nonce, derive_secret_scalar,
+    encode_bytes, even_commitment, hash_challenge, hash_challenge_with_prefix, point_from_bytes,
+    point_to_bytes,
+};
+use crate::vrf::{self, VrfOutput, VrfProof};
 
 /// A secret signing key for creating Schnorr signatures.
 ///
@@ -22,7 +32,12 @@ use crate::signatures::{Signature, hash_challenge};
 /// let mut rng = thread_rng();
 /// let signing_key = SigningKey::random(&mut rng);
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// With the `zeroize` feature enabled, the secret scalar is overwritten with
+/// zeros when the key is dropped, and the key is not `Copy` so it cannot be
+/// duplicated implicitly.
+#[cfg_attr(not(feature = "zeroize"), derive(Copy))]
+#[derive(Clone, Debug, Eq, Serialize, Deserialize)]
 pub struct SigningKey {
     scalar: ScalarField,
 }
@@ -42,7 +57,7 @@ pub struct SigningKey {
 /// let signing_key = SigningKey::random(&mut rng);
 /// let verifying_key = signing_key.verifying_key();
 /// ```
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct VerifyingKey {
     point: Affine,
 }
@@ -69,6 +84,74 @@ impl SigningKey {
         }
     }
 
+    /// Encodes the secret scalar as 32 canonical little-endian bytes.
+    ///
+    /// The returned array is a copy of the secret and is not zeroized on drop.
+    pub fn to_bytes(&self) -> [u8; SK_SIZE] {
+        self.scalar.to_bytes()
+    }
+
+    /// [`SigningKey::to_bytes`] as a `Vec`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    /// Decodes a key produced by [`SigningKey::to_bytes`].
+    ///
+    /// Returns `Err(SchnorrError::InvalidScalar)` if the little-endian value is
+    /// not below the scalar field order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use rand::thread_rng;
+    ///
+    /// let signing_key = SigningKey::random(&mut thread_rng());
+    /// let imported = SigningKey::from_bytes(&signing_key.to_bytes()).unwrap();
+    /// assert_eq!(imported.verifying_key(), signing_key.verifying_key());
+    /// ```
+    pub fn from_bytes(bytes: &[u8; SK_SIZE]) -> Result<Self, SchnorrError> {
+        let scalar = ScalarField::from_bytes(bytes).ok_or(SchnorrError::InvalidScalar)?;
+        Ok(Self { scalar })
+    }
+
+    /// Imports 32 arbitrary bytes as a key by reducing their little-endian value
+    /// modulo the scalar field order.
+    ///
+    /// For key material from other systems that is not guaranteed to be in
+    /// range. The reduction is slightly biased, so prefer
+    /// [`SigningKey::random`] or [`SigningKey::from_seed`] for new keys. An input
+    /// that is a multiple of the order gives the zero key, whose verifying key
+    /// is the point at infinity and which cannot sign.
+    pub fn from_bytes_mod_order(bytes: &[u8; SK_SIZE]) -> Self {
+        Self {
+            scalar: ScalarField::from_bytes_mod_order(bytes),
+        }
+    }
+
+    /// Derives a signing key deterministically from seed bytes.
+    ///
+    /// The seed is packed into BabyBear elements, hashed with Poseidon2 and
+    /// reduced into the scalar field from ~494 bits of digest, so the resulting
+    /// scalar is statistically indistinguishable from uniform and suitable for key
+    /// generation. The key is only as strong as the seed: use at least 32 bytes of
+    /// secret, high-entropy input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    ///
+    /// let signing_key = SigningKey::from_seed(b"an example seed, do not reuse it");
+    /// assert_eq!(signing_key, SigningKey::from_seed(b"an example seed, do not reuse it"));
+    /// ```
+    pub fn from_seed(seed: &[u8]) -> Self {
+        Self {
+            scalar: derive_secret_scalar(seed),
+        }
+    }
+
     /// Derives the public verifying key from this signing key.
     ///
     /// The verifying key is computed as `G * sk` where `G` is the generator
@@ -94,15 +177,17 @@ impl SigningKey {
     ///
     /// The signature is computed using the Schnorr signature algorithm:
     /// 1. Generate a random nonce `k`
-    /// 2. Compute `R = G * k`
-    /// 3. Compute challenge `e = H(R || pk || msg)` using Poseidon2
+    /// 2. Compute `R = G * k`, negating `k` and `R` if `R` has an odd y-coordinate
+    /// 3. Compute challenge `e = H(R || pk || len(msg) || msg)` using Poseidon2
     /// 4. Compute `s = k + e * sk`
     /// 5. Return signature `(R, s)`
     ///
     /// # Arguments
     ///
     /// * `rng` - A cryptographically secure random number generator for the nonce
-    /// * `msg` - The message to sign, encoded as KoalaBear field elements
+    /// * `msg` - The message to sign, encoded as KoalaBear field elements. An empty
+    ///   message is valid: the challenge still binds `R`, `pk` and the length
+    ///   `0`, so the signature verifies for the empty message and nothing else.
     ///
     /// # Returns
     ///
@@ -128,11 +213,393 @@ impl SigningKey {
         rng: &mut R,
         msg: &[BabyBear],
     ) -> Result<Signature, SchnorrError> {
+        self.sign_with_hash(&Poseidon2Challenge, rng, msg)
+    }
+
+    /// Signs `msg` with the challenge computed by `hasher` instead of
+    /// [`hash_challenge`].
+    ///
+    /// [`SigningKey::sign`] is this with [`Poseidon2Challenge`]. The signature only
+    /// verifies with [`VerifyingKey::verify_with_hash`] under the same hash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::{Poseidon2Challenge, SigningKey};
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let message = [BabyBear::from_u32(1)];
+    /// let signature = signing_key
+    ///     .sign_with_hash(&Poseidon2Challenge, &mut rng, &message)
+    ///     .unwrap();
+    /// assert!(signing_key.verifying_key().verify(&message, &signature).unwrap());
+    /// ```
+    pub fn sign_with_hash<H: ChallengeHash + ?Sized, R: Rng + ?Sized>(
+        &self,
+        hasher: &H,
+        rng: &mut R,
+        msg: &[BabyBear],
+    ) -> Result<Signature, SchnorrError> {
+        let (nonce, r) = even_commitment(ScalarField::random(rng));
+        let e = hasher.hash(&r, &self.verifying_key().point, msg)?;
+        Ok(Signature {
+            r,
+            s: nonce + e * self.scalar,
+        })
+    }
+
+    /// Signs several messages at once, with one signature binding all of them.
+    ///
+    /// Each message is absorbed behind its own length and the number of messages
+    /// is committed up front, so fields signed this way cannot be reordered,
+    /// merged or split without invalidating the signature. Verify with
+    /// [`VerifyingKey::verify_many`]; the result does not verify as a signature
+    /// on any single message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let sender = [BabyBear::from_u32(1)];
+    /// let amount = [BabyBear::from_u32(2), BabyBear::from_u32(3)];
+    /// let signature = signing_key.sign_many(&mut rng, &[&sender, &amount]).unwrap();
+    /// assert!(signing_key
+    ///     .verifying_key()
+    ///     .verify_many(&[&sender, &amount], &signature)
+    ///     .unwrap());
+    /// ```
+    pub fn sign_many<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        messages: &[&[BabyBear]],
+    ) -> Result<Signature, SchnorrError> {
+        self.sign_with_hash(&MultiMessageChallenge(messages), rng, &[])
+    }
+
+    /// Signs a message with a nonce derived deterministically from the key and message.
+    ///
+    /// The nonce is `k = H(sk || msg)` computed with Poseidon2 and reduced into the
+    /// scalar field, so no random number generator is needed and a faulty RNG cannot
+    /// leak the signing key. Signing the same message twice yields the same signature.
+    /// The result verifies with [`VerifyingKey::verify`] like any other signature.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let message = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    /// let signature = signing_key.sign_deterministic(&message).expect("signing failed");
+    /// assert_eq!(signature, signing_key.sign_deterministic(&message).unwrap());
+    /// ```
+    pub fn sign_deterministic(&self, msg: &[BabyBear]) -> Result<Signature, SchnorrError> {
+        let nonce = derive_nonce(&self.scalar, msg);
+        self.sign_with_nonce(SignatureScheme::Legacy, nonce, &[], msg)
+    }
+
+    /// Signs an arbitrary byte string.
+    ///
+    /// The bytes are packed 3 per BabyBear element after a length prefix, so
+    /// inputs that differ only in trailing zero bytes yield different challenges.
+    /// The result is a signature over that element encoding and is checked with
+    /// [`VerifyingKey::verify_bytes`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let signature = signing_key.sign_bytes(&mut rng, b"hello schnorr").expect("signing failed");
+    /// let verifying_key = signing_key.verifying_key();
+    /// assert!(verifying_key.verify_bytes(b"hello schnorr", &signature).unwrap());
+    /// ```
+    pub fn sign_bytes<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        msg: &[u8],
+    ) -> Result<Signature, SchnorrError> {
+        self.sign(rng, &encode_bytes(msg)?)
+    }
+
+    /// Signs a message bound to an application context string.
+    ///
+    /// The context is length-prefixed, packed into BabyBear elements and
+    /// absorbed before `R || pk || len(msg) || msg` in the challenge hash, so a
+    /// signature made under one context does not verify under any other context
+    /// or with plain [`VerifyingKey::verify`]. Verify with
+    /// [`VerifyingKey::verify_with_context`] using the same context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let message = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    /// let signature = signing_key
+    ///     .sign_with_context(&mut rng, b"my-app/v1", &message)
+    ///     .expect("signing failed");
+    /// let verifying_key = signing_key.verifying_key();
+    /// assert!(verifying_key.verify_with_context(b"my-app/v1", &message, &signature).unwrap());
+    /// ```
+    pub fn sign_with_context<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        context: &[u8],
+        msg: &[BabyBear],
+    ) -> Result<Signature, SchnorrError> {
+        let nonce = ScalarField::random(rng);
+        self.sign_with_nonce(
+            SignatureScheme::Legacy,
+            nonce,
+            &context_prefix(context)?,
+            msg,
+        )
+    }
+
+    /// Starts signing a message that will be supplied in chunks.
+    ///
+    /// Samples the nonce and commits to `R || pk` and the total message length
+    /// `msg_len` immediately; absorb exactly `msg_len` elements with
+    /// [`ChallengeHasher::update`] and finish with
+    /// [`ChallengeHasher::finalize_sign`]. The signature equals the one
+    /// [`SigningKey::sign`] would produce for the concatenated message with the
+    /// same nonce.
+    pub fn challenge_hasher<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        msg_len: usize,
+    ) -> Result<ChallengeHasher, SchnorrError> {
+        let (nonce, r) = even_commitment(ScalarField::random(rng));
+        let pk = self.verifying_key();
+        let role = HasherRole::Sign {
+            r,
+            nonce,
+            secret: self.scalar,
+        };
+        ChallengeHasher::new(&r, &pk.point, msg_len, role)
+    }
+
+    /// Signs a message under the selected challenge binding.
+    ///
+    /// [`SignatureScheme::Legacy`] gives the same result as [`SigningKey::sign`];
+    /// [`SignatureScheme::XOnly`] commits only to the `x`-coordinates of `R` and
+    /// `pk` behind a scheme tag. Verify with [`VerifyingKey::verify_with_scheme`]
+    /// and the same scheme.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::{SignatureScheme, SigningKey};
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let message = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    /// let signature = signing_key
+    ///     .sign_with_scheme(&mut rng, &message, SignatureScheme::XOnly)
+    ///     .expect("signing failed");
+    /// assert!(signing_key
+    ///     .verifying_key()
+    ///     .verify_with_scheme(&message, &signature, SignatureScheme::XOnly)
+    ///     .expect("verification failed"));
+    /// ```
+    pub fn sign_with_scheme<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        msg: &[BabyBear],
+        scheme: SignatureScheme,
+    ) -> Result<Signature, SchnorrError> {
+        let nonce = ScalarField::random(rng);
+        self.sign_with_nonce(scheme, nonce, &[], msg)
+    }
+
+    /// Evaluates the verifiable random function on `input`.
+    ///
+    /// Returns the pseudorandom output, which is unique for this key and input,
+    /// and a proof that anyone holding the verifying key can check with
+    /// [`VerifyingKey::vrf_verify`]. The rng only supplies the proof nonce.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let input = [BabyBear::from_u32(7)];
+    /// let (output, proof) = signing_key.vrf_prove(&mut rng, &input);
+    /// assert!(signing_key
+    ///     .verifying_key()
+    ///     .vrf_verify(&input, &output, &proof)
+    ///     .expect("well-formed proof"));
+    /// ```
+    pub fn vrf_prove<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        input: &[BabyBear],
+    ) -> (VrfOutput, VrfProof) {
         let nonce = ScalarField::random(rng);
+        vrf::prove(&self.scalar, &self.verifying_key().point, &nonce, input)
+    }
+
+    /// Computes this signer's share of a MuSig signature over `msg`.
+    ///
+    /// `nonce` is the [`MusigNonce`] whose commitment and public nonce this signer
+    /// sent, and `aggregated_nonce` is the result of
+    /// [`musig::aggregate_nonces`] over every signer's. The nonce is consumed so
+    /// it cannot be used for a second message. See the [`musig`] module docs for
+    /// the full protocol.
+    ///
+    /// Returns `Err(SchnorrError::InvalidPartialSignature)` if `keys` does not
+    /// contain this key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::SigningKey;
+    /// use schnorr::musig::{aggregate_keys, aggregate_nonces, aggregate_signatures, commit_nonce};
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signers = [SigningKey::random(&mut rng), SigningKey::random(&mut rng)];
+    /// let keys: Vec<_> = signers.iter().map(SigningKey::verifying_key).collect();
+    /// let message = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    ///
+    /// let (nonces, commitments): (Vec<_>, Vec<_>) =
+    ///     signers.iter().map(|_| commit_nonce(&mut rng)).unzip();
+    /// let public_nonces: Vec<_> = nonces.iter().map(|n| n.public_nonce()).collect();
+    /// let r = aggregate_nonces(&commitments, &public_nonces).unwrap();
+    ///
+    /// let partials: Vec<_> = signers
+    ///     .iter()
+    ///     .zip(nonces)
+    ///     .map(|(signer, nonce)| signer.musig_partial_sign(nonce, &keys, &r, &message).unwrap())
+    ///     .collect();
+    /// let signature = aggregate_signatures(&keys, &message, &partials).unwrap();
+    /// assert!(aggregate_keys(&keys).unwrap().verify(&message, &signature).unwrap());
+    /// ```
+    pub fn musig_partial_sign(
+        &self,
+        nonce: MusigNonce,
+        keys: &[VerifyingKey],
+        aggregated_nonce: &Affine,
+        msg: &[BabyBear],
+    ) -> Result<PartialSignature, SchnorrError> {
+        musig::partial_sign(
+            &self.scalar,
+            &self.verifying_key(),
+            nonce,
+            keys,
+            aggregated_nonce,
+            msg,
+        )
+    }
+
+    /// Signs with an externally computed challenge, returning `(k * G, k + e * sk)`.
+    ///
+    /// For protocols that derive the Fiat-Shamir challenge `e` themselves, for
+    /// example inside a larger transcript. The caller computes `R = nonce * G`,
+    /// hashes it into `e`, and passes both here; the result checks with
+    /// [`VerifyingKey::verify_prehashed`].
+    ///
+    /// # Security
+    ///
+    /// The challenge must commit to both `R` and the public key, or signatures
+    /// can be forged or transplanted between keys. The nonce must be uniformly
+    /// random and used only once; reusing it with two challenges leaks the key.
+    ///
+    /// # Errors
+    ///
+    /// - `SchnorrError::PointAtInfinity` if `nonce` is zero
+    /// - `SchnorrError::OddCommitment` if `nonce * G` has an odd y-coordinate;
+    ///   negate the nonce (see [`Affine::has_odd_y`]) before hashing `R`
+    /// - `SchnorrError::InvalidScalar` if `nonce` or `challenge` is not fully reduced
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use curve::{Affine, Group, RandomField, ScalarField};
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    /// use schnorr::{SigningKey, hash_challenge};
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let verifying_key = signing_key.verifying_key();
+    /// let message = [BabyBear::from_u32(7)];
+    ///
+    /// let mut nonce = ScalarField::random(&mut rng);
+    /// let mut r = <Affine as Group>::mul_generator(&nonce);
+    /// if r.has_odd_y() {
+    ///     (nonce, r) = (-nonce, -r);
+    /// }
+    /// let e = hash_challenge(&r, &verifying_key.as_affine(), &message).unwrap();
+    /// let signature = signing_key.sign_prehashed(nonce, e).unwrap();
+    /// assert!(verifying_key.verify_prehashed(e, &signature).unwrap());
+    /// assert!(verifying_key.verify(&message, &signature).unwrap());
+    /// ```
+    pub fn sign_prehashed(
+        &self,
+        nonce: ScalarField,
+        challenge: ScalarField,
+    ) -> Result<Signature, SchnorrError> {
+        if !nonce.is_canonical() || !challenge.is_canonical() {
+            return Err(SchnorrError::InvalidScalar);
+        }
         let r = <Affine as Group>::mul_generator(&nonce);
+        check_point(&r)?;
+        if r.has_odd_y() {
+            return Err(SchnorrError::OddCommitment);
+        }
+
+        Ok(Signature {
+            r,
+            s: nonce + challenge * self.scalar,
+        })
+    }
+
+    fn sign_with_nonce(
+        &self,
+        scheme: SignatureScheme,
+        nonce: ScalarField,
+        prefix: &[BabyBear],
+        msg: &[BabyBear],
+    ) -> Result<Signature, SchnorrError> {
+        let (nonce, r) = even_commitment(nonce);
         let pk = self.verifying_key();
 
-        let e = hash_challenge(&r, &pk.point, msg)?;
+        let e = hash_challenge_with_prefix(scheme, prefix, &r, &pk.point, msg)?;
         let s = nonce + e * self.scalar;
 
         Ok(Signature { r, s })
@@ -140,11 +607,73 @@ impl SigningKey {
 }
 
 impl VerifyingKey {
+    /// Constructs a verifying key from a curve point.
+    ///
+    /// # Returns
+    ///
+    /// Returns `Err(SchnorrError::PointAtInfinity)` for the point at infinity and
+    /// `Err(SchnorrError::NotOnCurve)` if the coordinates do not satisfy the curve
+    /// equation (see [`Affine::is_in_subgroup`]).
+    pub fn from_affine(point: Affine) -> Result<Self, SchnorrError> {
+        check_point(&point)?;
+        let point =
+            Affine::from_coords_checked(point.x, point.y).ok_or(SchnorrError::NotOnCurve)?;
+        Ok(Self { point })
+    }
+
     /// Returns the underlying affine point representing this verifying key.
     pub fn as_affine(&self) -> Affine {
         self.point
     }
 
+    /// Encodes this key as `x || y`, each coordinate as 8 canonical little-endian
+    /// `u32` coefficients (`u^0` first).
+    pub fn to_bytes(&self) -> [u8; PK_SIZE] {
+        point_to_bytes(&self.point)
+    }
+
+    /// Parses the hex form written by this key's `Display` impl: the
+    /// [`VerifyingKey::to_bytes`] encoding as `2 * PK_SIZE` hex digits, in
+    /// either case and without a `0x` prefix.
+    ///
+    /// # Errors
+    ///
+    /// - `SchnorrError::InvalidLength` if the string is not `2 * PK_SIZE` characters
+    /// - `SchnorrError::InvalidHex` if a character is not a hex digit
+    /// - The errors of [`VerifyingKey::from_bytes`] for an invalid point
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::{SigningKey, VerifyingKey};
+    /// use rand::thread_rng;
+    ///
+    /// let verifying_key = SigningKey::random(&mut thread_rng()).verifying_key();
+    /// let hex = verifying_key.to_string();
+    /// assert_eq!(VerifyingKey::from_hex(&hex), Ok(verifying_key));
+    /// ```
+    pub fn from_hex(hex: &str) -> Result<Self, SchnorrError> {
+        Self::from_bytes(&decode_hex(hex)?)
+    }
+
+    /// [`VerifyingKey::to_bytes`] as a `Vec`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    /// Decodes a key produced by [`VerifyingKey::to_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// Returns `Err(SchnorrError::NotOnCurve)` if a coefficient is not below the
+    /// KoalaBear modulus or the coordinates are not a point on the curve. The point
+    /// at infinity has no encoding and is never accepted.
+    pub fn from_bytes(bytes: &[u8; PK_SIZE]) -> Result<Self, SchnorrError> {
+        Ok(Self {
+            point: point_from_bytes(bytes)?,
+        })
+    }
+
     /// Verifies a signature on a message using this verifying key.
     ///
     /// The verification checks whether the signature equation holds:
@@ -153,7 +682,7 @@ impl VerifyingKey {
     /// - `s` is the signature scalar
     /// - `R` is the signature point
     /// - `pk` is this verifying key
-    /// - `e = H(R || pk || msg)` is the challenge hash
+    /// - `e = H(R || pk || len(msg) || msg)` is the challenge hash
     ///
     /// # Arguments
     ///
@@ -164,8 +693,11 @@ impl VerifyingKey {
     ///
     /// Returns a `Result` containing:
     /// - `Ok(true)` if the signature is valid
-    /// - `Ok(false)` if the signature is invalid
-    /// - `Err(SchnorrError::InvalidPoint)` if the verifying key or signature contains an invalid point
+    /// - `Ok(false)` if the signature is invalid, including when `R` has an odd
+    ///   y-coordinate (signing always produces the even one)
+    /// - `Err(SchnorrError::PointAtInfinity)` or `Err(SchnorrError::NotOnCurve)` if the
+    ///   verifying key or signature contains an invalid point
+    /// - `Err(SchnorrError::InvalidScalar)` if the signature's `s` is not fully reduced
     ///
     /// # Example
     ///
@@ -185,18 +717,424 @@ impl VerifyingKey {
     /// assert!(is_valid);
     /// ```
     pub fn verify(&self, msg: &[BabyBear], sig: &Signature) -> Result<bool, SchnorrError> {
-        if self.point.is_infinity() || sig.r.is_infinity() {
-            return Err(SchnorrError::InvalidPoint);
+        self.verify_with_hash(&Poseidon2Challenge, msg, sig)
+    }
+
+    /// Verifies a signature made with [`SigningKey::sign_with_hash`] under the
+    /// same `hasher`.
+    ///
+    /// [`VerifyingKey::verify`] is this with [`Poseidon2Challenge`]. Errors are the
+    /// same as for [`VerifyingKey::verify`], plus any error `hasher` returns.
+    ///
+    /// `s`, the points and the parity of `R` are checked first, so a malformed
+    /// signature is rejected without calling `hasher` or multiplying any point.
+    pub fn verify_with_hash<H: ChallengeHash + ?Sized>(
+        &self,
+        hasher: &H,
+        msg: &[BabyBear],
+        sig: &Signature,
+    ) -> Result<bool, SchnorrError> {
+        if !sig.s.is_canonical() {
+            return Err(SchnorrError::InvalidScalar);
         }
-        if !self.point.is_on_curve() || !sig.r.is_on_curve() {
-            return Err(SchnorrError::InvalidPoint);
+        check_point(&self.point)?;
+        check_point(&sig.r)?;
+        if sig.r.has_odd_y() {
+            return Ok(false);
         }
 
-        let e = hash_challenge(&sig.r, &self.point, msg)?;
+        let e = hasher.hash(&sig.r, &self.point, msg)?;
+        let lhs = Affine::double_scalar_mul_basepoint(&sig.s, &-e, &self.point);
+        Ok(lhs == sig.r)
+    }
+
+    /// Verifies a signature made with [`SigningKey::sign_many`] over the same
+    /// messages in the same order.
+    ///
+    /// Errors are the same as for [`VerifyingKey::verify`].
+    pub fn verify_many(
+        &self,
+        messages: &[&[BabyBear]],
+        sig: &Signature,
+    ) -> Result<bool, SchnorrError> {
+        self.verify_with_hash(&MultiMessageChallenge(messages), &[], sig)
+    }
+
+    /// Verifies a signature made with [`SigningKey::sign_bytes`].
+    ///
+    /// Errors are the same as for [`VerifyingKey::verify`].
+    pub fn verify_bytes(&self, msg: &[u8], sig: &Signature) -> Result<bool, SchnorrError> {
+        self.verify(&encode_bytes(msg)?, sig)
+    }
+
+    /// Verifies a signature made with [`SigningKey::sign_with_context`].
+    ///
+    /// Returns `Ok(false)` if the signature was made under a different context
+    /// or without one. Errors are the same as for [`VerifyingKey::verify`].
+    pub fn verify_with_context(
+        &self,
+        context: &[u8],
+        msg: &[BabyBear],
+        sig: &Signature,
+    ) -> Result<bool, SchnorrError> {
+        self.verify_with_prefix(SignatureScheme::Legacy, &context_prefix(context)?, msg, sig)
+    }
+
+    /// Starts verifying a signature on a message of `msg_len` elements that will
+    /// be supplied in chunks.
+    ///
+    /// Absorb the message with [`ChallengeHasher::update`] and finish with
+    /// [`ChallengeHasher::finalize_verify`]. Errors are the same as for
+    /// [`VerifyingKey::verify`].
+    pub fn challenge_hasher(
+        &self,
+        sig: &Signature,
+        msg_len: usize,
+    ) -> Result<ChallengeHasher, SchnorrError> {
+        if !sig.s.is_canonical() {
+            return Err(SchnorrError::InvalidScalar);
+        }
+        check_point(&self.point)?;
+        check_point(&sig.r)?;
+
+        let role = HasherRole::Verify {
+            r: sig.r,
+            pk: self.point,
+            s: sig.s,
+        };
+        ChallengeHasher::new(&sig.r, &self.point, msg_len, role)
+    }
+
+    /// Verifies a signature made with [`SigningKey::sign_with_scheme`].
+    ///
+    /// Returns `Ok(false)` if the signature was made under the other scheme.
+    /// Errors are the same as for [`VerifyingKey::verify`].
+    pub fn verify_with_scheme(
+        &self,
+        msg: &[BabyBear],
+        sig: &Signature,
+        scheme: SignatureScheme,
+    ) -> Result<bool, SchnorrError> {
+        self.verify_with_prefix(scheme, &[], msg, sig)
+    }
+
+    /// Verifies a VRF output and proof made with [`SigningKey::vrf_prove`].
+    ///
+    /// Returns `Ok(false)` if the proof does not check out or the output does
+    /// not match it.
+    ///
+    /// # Errors
+    ///
+    /// - `SchnorrError::InvalidScalar` if a proof scalar is not canonical
+    /// - `SchnorrError::PointAtInfinity` or `SchnorrError::NotOnCurve` if the key
+    ///   or `proof.gamma` is not a valid point
+    pub fn vrf_verify(
+        &self,
+        input: &[BabyBear],
+        output: &VrfOutput,
+        proof: &VrfProof,
+    ) -> Result<bool, SchnorrError> {
+        vrf::verify(&self.point, input, output, proof)
+    }
+
+    /// Verifies `sig` against an externally computed challenge by checking
+    /// `s * G - e * pk == R`, without hashing a message.
+    ///
+    /// Counterpart of [`SigningKey::sign_prehashed`]. `verify_prehashed(e, sig)`
+    /// with `e = hash_challenge(&sig.r, &pk, msg)` agrees with
+    /// [`VerifyingKey::verify`] on `msg`.
+    ///
+    /// # Security
+    ///
+    /// The caller is responsible for deriving `challenge` from a transcript that
+    /// binds this key and `sig.r`. A challenge chosen independently of `R` lets
+    /// anyone forge a signature for it.
+    ///
+    /// Errors are the same as for [`VerifyingKey::verify`], plus
+    /// `SchnorrError::InvalidScalar` if `challenge` is not fully reduced.
+    pub fn verify_prehashed(
+        &self,
+        challenge: ScalarField,
+        sig: &Signature,
+    ) -> Result<bool, SchnorrError> {
+        if !sig.s.is_canonical() || !challenge.is_canonical() {
+            return Err(SchnorrError::InvalidScalar);
+        }
+        check_point(&self.point)?;
+        check_point(&sig.r)?;
+        if sig.r.has_odd_y() {
+            return Ok(false);
+        }
+
+        let lhs = Affine::double_scalar_mul_basepoint(&sig.s, &-challenge, &self.point);
+        Ok(lhs == sig.r)
+    }
+
+    fn verify_with_prefix(
+        &self,
+        scheme: SignatureScheme,
+        prefix: &[BabyBear],
+        msg: &[BabyBear],
+        sig: &Signature,
+    ) -> Result<bool, SchnorrError> {
+        if !sig.s.is_canonical() {
+            return Err(SchnorrError::InvalidScalar);
+        }
+        check_point(&self.point)?;
+        check_point(&sig.r)?;
+        if sig.r.has_odd_y() {
+            return Ok(false);
+        }
+
+        let e = hash_challenge_with_prefix(scheme, prefix, &sig.r, &self.point, msg)?;
         let lhs = Affine::double_scalar_mul_basepoint(&sig.s, &-e, &self.point);
 
         Ok(lhs == sig.r)
     }
+
+    /// Verifies a batch of signatures with a single multi-scalar multiplication.
+    ///
+    /// Random weights `z_i` are sampled and the aggregated equation
+    /// `Σ z_i·s_i·G == Σ z_i·R_i + Σ z_i·e_i·pk_i` is checked, which holds for
+    /// all valid batches and fails except with negligible probability if any
+    /// signature is invalid. An empty batch is accepted.
+    ///
+    /// # Returns
+    ///
+    /// Returns a `Result` containing:
+    /// - `Ok(true)` if every signature in the batch is valid
+    /// - `Ok(false)` if at least one signature is invalid
+    /// - `Err(SchnorrError::PointAtInfinity)` or `Err(SchnorrError::NotOnCurve)` if any
+    ///   verifying key or signature contains an invalid point
+    /// - `Err(SchnorrError::InvalidScalar)` if any signature's `s` is not fully reduced
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::{SigningKey, VerifyingKey};
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let message = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    /// let signature = signing_key.sign(&mut rng, &message).expect("signing failed");
+    ///
+    /// let items = [(signing_key.verifying_key(), &message[..], &signature)];
+    /// assert!(VerifyingKey::verify_batch(&items).expect("verification failed"));
+    /// ```
+    pub fn verify_batch(
+        items: &[(VerifyingKey, &[BabyBear], &Signature)],
+    ) -> Result<bool, SchnorrError> {
+        let mut rng = rand::rng();
+        let mut points = Vec::with_capacity(2 * items.len() + 1);
+        let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+        let mut generator_scalar = ScalarField::ZERO;
+
+        for (pk, msg, sig) in items {
+            if !sig.s.is_canonical() {
+                return Err(SchnorrError::InvalidScalar);
+            }
+            check_point(&pk.point)?;
+            check_point(&sig.r)?;
+            if sig.r.has_odd_y() {
+                return Ok(false);
+            }
+
+            let e = hash_challenge(&sig.r, &pk.point, msg)?;
+            let z = ScalarField::random(&mut rng);
+            generator_scalar += z * sig.s;
+
+            points.push(sig.r);
+            scalars.push(-z);
+            points.push(pk.point);
+            scalars.push(-(z * e));
+        }
+
+        points.push(<Affine as Group>::generator());
+        scalars.push(generator_scalar);
+
+        Ok(<Affine as Group>::multi_scalar_mul(&points, &scalars).is_identity())
+    }
+}
+
+impl<'de> Deserialize<'de> for VerifyingKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Raw {
+            point: Affine,
+        }
+
+        let raw = Raw::deserialize(deserializer)?;
+        VerifyingKey::from_affine(raw.point)
+            .map_err(|_| de::Error::custom("verifying key is not a valid curve point"))
+    }
+}
+
+/// Compares the secret scalars in constant time.
+impl PartialEq for SigningKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.scalar.ct_eq(&other.scalar)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        // SAFETY: `ScalarField` is plain limb data with no `Drop` impl, and the
+        // all-zero bit pattern is the valid element zero.
+        unsafe { zeroize::zeroize_flat_type(&mut self.scalar) };
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SigningKey {}
+
+impl core::fmt::Display for VerifyingKey {
+    /// Writes [`VerifyingKey::to_bytes`] as lowercase hex, parsed back by
+    /// [`VerifyingKey::from_hex`].
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.to_bytes()
+            .iter()
+            .try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Verifies a published test vector given as hex strings.
+///
+/// `pk_hex` is the [`VerifyingKey::from_hex`] encoding and `sig_hex` the
+/// [`Signature::to_bytes`] encoding as `2 * SIG_SIZE` hex digits. Pinning vectors
+/// this way fixes the wire format and the challenge derivation together, so
+/// another implementation, or a refactor of this one, can check that it
+/// produces and accepts the same signatures.
+///
+/// # Errors
+///
+/// - `SchnorrError::InvalidLength` or `SchnorrError::InvalidHex` for malformed hex
+/// - The errors of [`VerifyingKey::from_bytes`], [`Signature::from_bytes`] and
+///   [`VerifyingKey::verify`]
+pub fn verify_test_vector(
+    pk_hex: &str,
+    msg: &[BabyBear],
+    sig_hex: &str,
+) -> Result<bool, SchnorrError> {
+    let pk = VerifyingKey::from_hex(pk_hex)?;
+    let sig = Signature::from_bytes(&decode_hex(sig_hex)?)?;
+    pk.verify(msg, &sig)
+}
+
+/// Finds two signatures that share the commitment `R`, returning their indices.
+///
+/// Two signatures by one key with the same `R` used the same nonce, which leaks
+/// the key (see [`recover_key_from_reuse`]). The first repeated `R` is reported
+/// as `(earlier, later)`; `None` means every commitment is distinct. Intended
+/// for auditing a set of signatures from a single key; signatures by different
+/// keys sharing `R` are reported as well.
+///
+/// # Example
+///
+/// ```
+/// use schnorr::{SigningKey, detect_nonce_reuse};
+/// use p3_baby_bear::BabyBear;
+/// use p3_field::PrimeCharacteristicRing;
+/// use rand::thread_rng;
+///
+/// let mut rng = thread_rng();
+/// let signing_key = SigningKey::random(&mut rng);
+/// let sigs = [
+///     signing_key.sign(&mut rng, &[BabyBear::ONE]).unwrap(),
+///     signing_key.sign(&mut rng, &[BabyBear::TWO]).unwrap(),
+/// ];
+/// assert_eq!(detect_nonce_reuse(&sigs), None);
+/// ```
+pub fn detect_nonce_reuse(sigs: &[Signature]) -> Option<(usize, usize)> {
+    let mut seen = HashMap::with_capacity(sigs.len());
+    sigs.iter().enumerate().find_map(|(i, sig)| {
+        seen.insert(sig.r.to_compressed(), i)
+            .map(|earlier| (earlier, i))
+    })
+}
+
+/// Recovers the signing key from two signatures that reused a nonce.
+///
+/// With `s1 = k + e1 * sk` and `s2 = k + e2 * sk` for the same `k`, the key is
+/// `sk = (s1 - s2) / (e1 - e2)`. The challenges are recomputed with
+/// [`hash_challenge`], so this applies to signatures from [`SigningKey::sign`]
+/// and the other default-hash signing paths. Meant for audits and teaching.
+///
+/// Returns `Ok(None)` when the commitments differ, when both challenges are
+/// equal (the same message signed twice), or when the recovered scalar is not
+/// the key behind `pk`.
+///
+/// # Errors
+///
+/// The errors of [`hash_challenge`] for either signature.
+pub fn recover_key_from_reuse(
+    sig1: &Signature,
+    msg1: &[BabyBear],
+    sig2: &Signature,
+    msg2: &[BabyBear],
+    pk: &VerifyingKey,
+) -> Result<Option<SigningKey>, SchnorrError> {
+    if sig1.r != sig2.r {
+        return Ok(None);
+    }
+    let e1 = hash_challenge(&sig1.r, &pk.point, msg1)?;
+    let e2 = hash_challenge(&sig2.r, &pk.point, msg2)?;
+    let de = e1 - e2;
+    if de.is_zero() {
+        return Ok(None);
+    }
+
+    let recovered = SigningKey {
+        scalar: (sig1.s - sig2.s) * de.inverse(),
+    };
+    Ok((recovered.verifying_key() == *pk).then_some(recovered))
+}
+
+/// Decodes exactly `N` bytes from `2 * N` hex digits in either case.
+fn decode_hex<const N: usize>(hex: &str) -> Result<[u8; N], SchnorrError> {
+    let hex = hex.as_bytes();
+    if hex.len() != 2 * N {
+        return Err(SchnorrError::InvalidLength);
+    }
+
+    let mut bytes = [0u8; N];
+    for (byte, pair) in bytes.iter_mut().zip(hex.chunks_exact(2)) {
+        *byte = (hex_digit(pair[0])? << 4) | hex_digit(pair[1])?;
+    }
+    Ok(bytes)
+}
+
+fn hex_digit(c: u8) -> Result<u8, SchnorrError> {
+    match c {
+        b'0'..=b'9' => Ok(c - b'0'),
+        b'a'..=b'f' => Ok(c - b'a' + 10),
+        b'A'..=b'F' => Ok(c - b'A' + 10),
+        _ => Err(SchnorrError::InvalidHex),
+    }
+}
+
+impl TryFrom<&[u8]> for SigningKey {
+    type Error = SchnorrError;
+
+    /// Same as [`SigningKey::from_bytes`], but returns
+    /// `Err(SchnorrError::InvalidLength)` unless `bytes` has exactly `SK_SIZE` bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        let bytes = bytes.try_into().map_err(|_| SchnorrError::InvalidLength)?;
+        Self::from_bytes(bytes)
+    }
+}
+
+impl TryFrom<&[u8]> for VerifyingKey {
+    type Error = SchnorrError;
+
+    /// Same as [`VerifyingKey::from_bytes`], but returns
+    /// `Err(SchnorrError::InvalidLength)` unless `bytes` has exactly `PK_SIZE` bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes.try_into().map_err(|_| SchnorrError::InvalidLength)?)
+    }
 }
 
 impl From<&SigningKey> for VerifyingKey {
@@ -229,7 +1167,7 @@ mod tests {
         assert!(!sig.r.is_on_curve());
 
         let err = pk.verify(&msg, &sig).expect_err("invalid point");
-        assert_eq!(err, SchnorrError::InvalidPoint);
+        assert_eq!(err, SchnorrError::NotOnCurve);
     }
 
     #[test]
@@ -245,6 +1183,160 @@ mod tests {
         assert!(!bad_pk.point.is_on_curve());
 
         let err = bad_pk.verify(&msg, &sig).expect_err("invalid point");
-        assert_eq!(err, SchnorrError::InvalidPoint);
+        assert_eq!(err, SchnorrError::NotOnCurve);
+    }
+
+    #[test]
+    fn test_from_affine_validates_point() {
+        let g = Affine::generator();
+        assert_eq!(
+            VerifyingKey::from_affine(g).expect("on curve").as_affine(),
+            g
+        );
+
+        let off_curve = Affine::new(g.x, g.y + BaseField::ONE);
+        assert_eq!(
+            VerifyingKey::from_affine(off_curve),
+            Err(SchnorrError::NotOnCurve)
+        );
+        assert_eq!(
+            VerifyingKey::from_affine(Affine::INFINITY),
+            Err(SchnorrError::PointAtInfinity)
+        );
+    }
+
+    #[test]
+    fn test_deserialize_rejects_off_curve_public_key() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let pk = SigningKey::random(&mut rng).verifying_key();
+        let bytes = bincode::serialize(&pk).expect("serialize");
+        let recovered: VerifyingKey = bincode::deserialize(&bytes).expect("deserialize");
+        assert_eq!(recovered, pk);
+
+        let point = pk.as_affine();
+        let bad_pk = VerifyingKey {
+            point: Affine::new(point.x, point.y + BaseField::ONE),
+        };
+        let bytes = bincode::serialize(&bad_pk).expect("serialize");
+        assert!(bincode::deserialize::<VerifyingKey>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_deserialize_rejects_infinity_public_key() {
+        let bad_pk = VerifyingKey {
+            point: Affine::INFINITY,
+        };
+        let bytes = bincode::serialize(&bad_pk).expect("serialize");
+        assert!(bincode::deserialize::<VerifyingKey>(&bytes).is_err());
+
+        // Valid coordinates cannot be smuggled in with the infinity flag set.
+        let mut point = Affine::generator();
+        point.is_infinity = true;
+        let bytes = bincode::serialize(&VerifyingKey { point }).expect("serialize");
+        assert!(bincode::deserialize::<VerifyingKey>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_verify_rejects_non_canonical_s() {
+        let mut rng = StdRng::seed_from_u64(32);
+        let sk = SigningKey::random(&mut rng);
+        let pk = sk.verifying_key();
+        let msg = [BabyBear::from_u32(3), BabyBear::from_u32(2)];
+
+        let sig = sk.sign(&mut rng, &msg).expect("sign");
+        assert!(pk.verify(&msg, &sig).expect("verify"));
+
+        // `s` equal to the modulus, the smallest non-canonical encoding
+        const MODULUS_LE: [u64; 4] = [
+            0xf2154ff8a2e94d81,
+            0xf85ccc2efc3068fa,
+            0x40f5f26a5ae1748f,
+            0x00f06e44682c2aa4,
+        ];
+        let mut bytes = sig.to_bytes();
+        for (chunk, limb) in bytes[PK_SIZE..].chunks_exact_mut(8).zip(MODULUS_LE) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        assert_eq!(
+            Signature::from_bytes(&bytes),
+            Err(SchnorrError::InvalidScalar)
+        );
+        assert_eq!(
+            Signature::try_from(&bytes[..]),
+            Err(SchnorrError::InvalidScalar)
+        );
+
+        // The serde path decodes `s` the same way
+        let mut encoded = bincode::serialize(&sig).expect("serialize");
+        let s_start = encoded.len() - 32;
+        encoded[s_start..].copy_from_slice(&bytes[PK_SIZE..]);
+        assert!(bincode::deserialize::<Signature>(&encoded).is_err());
+    }
+
+    #[test]
+    fn test_verifying_key_bytes_roundtrip() {
+        let mut rng = StdRng::seed_from_u64(30);
+        let pk = SigningKey::random(&mut rng).verifying_key();
+        let bytes = pk.to_bytes();
+        assert_eq!(VerifyingKey::from_bytes(&bytes), Ok(pk));
+    }
+
+    #[test]
+    fn test_verifying_key_from_bytes_rejects_invalid() {
+        let mut rng = StdRng::seed_from_u64(30);
+        let pk = SigningKey::random(&mut rng).verifying_key();
+
+        // Off-curve: perturb the low byte of y
+        let mut off_curve = pk.to_bytes();
+        off_curve[32] ^= 1;
+        assert_eq!(
+            VerifyingKey::from_bytes(&off_curve),
+            Err(SchnorrError::NotOnCurve)
+        );
+
+        // All zeros is (0, 0), which is not on the curve; infinity has no encoding
+        assert_eq!(
+            VerifyingKey::from_bytes(&[0u8; PK_SIZE]),
+            Err(SchnorrError::NotOnCurve)
+        );
+
+        // Non-canonical coefficient
+        let mut non_canonical = pk.to_bytes();
+        non_canonical[..4].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(
+            VerifyingKey::from_bytes(&non_canonical),
+            Err(SchnorrError::NotOnCurve)
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_signing_key_zeroized_on_drop() {
+        use core::mem::ManuallyDrop;
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let mut sk = ManuallyDrop::new(SigningKey::random(&mut rng));
+        let ptr = &sk.scalar as *const ScalarField;
+        assert_ne!(unsafe { core::ptr::read_volatile(ptr) }, ScalarField::ZERO);
+
+        // SAFETY: `sk` is dropped exactly once and its storage stays alive,
+        // so reading the limbs afterwards only observes what `Drop` left behind.
+        unsafe {
+            ManuallyDrop::drop(&mut sk);
+            assert_eq!(core::ptr::read_volatile(ptr), ScalarField::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_deserialize_rejects_non_canonical_scalar() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let sk = SigningKey::random(&mut rng);
+        let bytes = bincode::serialize(&sk).expect("serialize");
+        assert_eq!(bytes.len(), crate::SK_SIZE);
+        let recovered: SigningKey = bincode::deserialize(&bytes).expect("deserialize");
+        assert_eq!(recovered, sk);
+
+        let bad = [0xffu8; crate::SK_SIZE];
+        assert!(bincode::deserialize::<SigningKey>(&bad).is_err());
     }
 }
@@ -17,7 +17,7 @@
 //!
 //! 1. Generate random nonce: `k ← ScalarField`
 //! 2. Compute commitment: `R = G × k`
-//! 3. Compute challenge: `e = Poseidon2(R || pk || m)`
+//! 3. Compute challenge: `e = Poseidon2(R || pk || len(m) || m)`
 //! 4. Compute response: `s = k + e × sk`
 //! 5. Return signature `σ = (R, s)`
 //!
@@ -25,7 +25,7 @@
 //!
 //! Given a message `m`, signature `σ = (R, s)`, and public key `pk`:
 //!
-//! 1. Recompute challenge: `e = Poseidon2(R || pk || m)`
+//! 1. Recompute challenge: `e = Poseidon2(R || pk || len(m) || m)`
 //! 2. Check equation: `G × s = R + pk × e`
 //! 3. Accept if equation holds, reject otherwise
 //!
@@ -72,8 +72,8 @@
 //!
 //! // Serialization sizes
 //! assert_eq!(SK_SIZE, 32);  // Secret key: 32 bytes
-//! assert_eq!(PK_SIZE, 40);  // Public key: 40 bytes (2×Fp8 coordinates)
-//! assert_eq!(SIG_SIZE, 72); // Signature: 72 bytes (R point + s scalar)
+//! assert_eq!(PK_SIZE, 64);  // Public key: 64 bytes (2×Fp8 coordinates)
+//! assert_eq!(SIG_SIZE, 96); // Signature: 96 bytes (R point + s scalar)
 //!
 //! // Serialize signing key
 //! let sk_bytes = bincode::serialize(&signing_key).unwrap();
@@ -83,9 +83,10 @@
 //! let recovered: SigningKey = bincode::deserialize(&sk_bytes).unwrap();
 //! assert_eq!(signing_key, recovered);
 //!
-//! // Same for verifying keys and signatures
-//! let vk_bytes = bincode::serialize(&signing_key.verifying_key()).unwrap();
+//! // Verifying keys have a fixed-size encoding that validates the point
+//! let vk_bytes = signing_key.verifying_key().to_bytes();
 //! assert_eq!(vk_bytes.len(), PK_SIZE);
+//! let vk = VerifyingKey::from_bytes(&vk_bytes).unwrap();
 //! ```
 //!
 //! ## Key Types
@@ -100,24 +101,43 @@
 //! - `to_bytes()` - Serialize to 32-byte array
 //! - `verifying_key()` - Derive corresponding public key
 //! - `sign(rng, message)` - Sign a message
+//! - `challenge_hasher(rng)` - Sign a message supplied in chunks ([`ChallengeHasher`])
 //!
 //! ### [`VerifyingKey`]
 //!
-//! Public verification key (40 bytes: 2×Fp8 coordinates).
+//! Public verification key (64 bytes: 2×Fp8 coordinates).
 //!
 //! **Methods**:
 //! - `verify(message, signature)` - Verify a signature
+//! - `challenge_hasher(signature)` - Verify a message supplied in chunks
 //! - `from_affine(point)` - Construct from curve point
+//! - `to_bytes()` / `from_bytes(bytes)` - 64-byte encoding, validated on decode
 //! - `to_affine()` - Convert to curve point
 //!
 //! ### [`Signature`]
 //!
-//! Schnorr signature (72 bytes: R point + s scalar).
+//! Schnorr signature (96 bytes: R point + s scalar).
 //!
 //! **Fields**:
-//! - `r: Affine` - Commitment point (40 bytes)
+//! - `r: Affine` - Commitment point (64 bytes)
 //! - `s: ScalarField` - Response scalar (32 bytes)
 //!
+//! **Methods**:
+//! - `to_bytes()` / `from_bytes(bytes)` - 96-byte encoding; rejects off-curve `R` and non-canonical `s`
+//!
+//! ### [`VrfOutput`] and [`VrfProof`]
+//!
+//! `SigningKey::vrf_prove(rng, input)` evaluates a verifiable random function:
+//! the output is Poseidon2 of `sk * HashToCurve(input)`, and the proof is a
+//! Chaum-Pedersen DLEQ proof checked by `VerifyingKey::vrf_verify`.
+//!
+//! ### [`musig`]
+//!
+//! MuSig-style multi-signatures: [`musig::aggregate_keys`] combines several
+//! verifying keys into one, and after two rounds of nonce commitment and reveal
+//! the signers' `SigningKey::musig_partial_sign` shares combine with
+//! [`musig::aggregate_signatures`] into an ordinary [`Signature`] under that key.
+//!
 //! ## Poseidon2 Hash Function
 //!
 //! The Fiat-Shamir challenge is computed using Poseidon2:
@@ -126,18 +146,23 @@
 //! Input Encoding:
 //! - R (commitment): 16 BabyBear elements (2 Fp8 coordinates)
 //! - pk (public key): 16 BabyBear elements (2 Fp8 coordinates)
+//! - len(msg): 1 BabyBear element, the message length
 //! - msg (message): Variable-length BabyBear elements
 //!
 //! Hash Configuration:
 //! - Width: 16 field elements
 //! - Rate: 8 elements
 //! - Capacity: 8 elements
-//! - Output: 8 elements (squeezed to 5 elements for scalar)
+//! - Output: 16 elements (digest plus one extra squeeze)
 //!
-//! Output: Challenge scalar (252 bits)
+//! Output: Challenge scalar, the 16 elements reduced mod the field order
 //! ```
 //!
-//! See [`hash_challenge`] for implementation details.
+//! See [`hash_challenge`] for implementation details. [`SignatureScheme::XOnly`]
+//! selects a compact BIP340-style binding `H(tag || R.x || pk.x || len(m) || m)` instead;
+//! sign and verify with `sign_with_scheme` / `verify_with_scheme`.
+//! [`SignatureScheme::Recoverable`] drops `pk` from the challenge so that
+//! [`Signature::recover`] can compute the key from `(R, s, msg)`.
 //!
 //! ## Security Properties
 //!
@@ -165,7 +190,8 @@
 //!
 //! 2. **Key Management**
 //!    - Protect signing keys from unauthorized access
-//!    - Zero memory when disposing of keys
+//!    - Zero memory when disposing of keys (enable the `zeroize` feature to
+//!      clear [`SigningKey`] on drop)
 //!    - Use hardware security modules (HSMs) for high-value keys
 //!
 //! 3. **Message Encoding**
@@ -196,7 +222,8 @@
 //! // Signing can fail (though unlikely with proper RNG)
 //! match signing_key.sign(&mut rng, &[]) {
 //!     Ok(signature) => println!("Signature: {:?}", signature),
-//!     Err(SchnorrError::InvalidPoint) => eprintln!("Error: Invalid point encountered"),
+//!     Err(SchnorrError::PointAtInfinity) => eprintln!("Error: point at infinity encountered"),
+//!     Err(err) => eprintln!("Error: {err}"),
 //! }
 //! ```
 //!
@@ -241,16 +268,27 @@
 //! - Poseidon2: <https://eprint.iacr.org/2023/323>
 //! - Plonky3 framework: <https://github.com/Plonky3/Plonky3>
 
+pub mod compressed_key;
 #[deny(missing_docs)]
 mod constants;
 mod errors;
 mod keys;
+pub mod musig;
 mod signatures;
+mod transcript;
+mod vrf;
 
 #[cfg(test)]
 mod tests;
 
-pub use constants::{PK_SIZE, SIG_SIZE, SK_SIZE};
+pub use constants::{CHALLENGE_DIGEST_LEN, PK_SIZE, SIG_SIZE, SK_SIZE};
 pub use errors::SchnorrError;
-pub use keys::{SigningKey, VerifyingKey};
-pub use signatures::{Signature, hash_challenge};
+pub use keys::{
+    SigningKey, VerifyingKey, detect_nonce_reuse, recover_key_from_reuse, verify_test_vector,
+};
+pub use signatures::{
+    ChallengeHash, ChallengeHasher, ChallengeOrder, Poseidon2Challenge, Signature, SignatureScheme,
+    hash_challenge, hash_challenge_ordered, pack_digest_to_scalar, poseidon2_hash,
+};
+pub use transcript::Transcript;
+pub use vrf::{VrfOutput, VrfProof};
@@ -0,0 +1,262 @@
+//! MuSig-style multi-signatures: `n` signers jointly produce one [`Signature`]
+//! that verifies under an aggregated key with [`VerifyingKey::verify`].
+//!
+//! For keys `X_1..X_n` in an order agreed by all signers, let
+//! `L = H(tag || X_1 || ... || X_n)`. Each key gets the coefficient
+//! `a_i = H(tag || L || X_i)` and the aggregated key is `X = sum(a_i * X_i)`. The
+//! coefficients stop a signer from choosing its key as a function of the others'
+//! to cancel them out (a rogue-key attack).
+//!
+//! Nonces are exchanged in two rounds:
+//!
+//! 1. Each signer draws `k_i` with [`commit_nonce`] and sends the commitment
+//!    `H(tag || R_i)` for `R_i = k_i * G`.
+//! 2. Once every commitment has arrived, each signer reveals `R_i`.
+//!    [`aggregate_nonces`] checks the reveals against the commitments and returns
+//!    `R = sum(R_i)`.
+//!
+//! Committing first keeps a signer from picking its `R_i` after seeing the
+//! others'. Each signer then sends `s_i = k_i + e * a_i * x_i` from
+//! [`SigningKey::musig_partial_sign`](crate::SigningKey::musig_partial_sign), with
+//! the usual challenge `e = H(R || X || len(msg) || msg)`, and
+//! [`aggregate_signatures`] checks every partial and returns `(R, sum(s_i))`.
+//! If `R` has an odd y-coordinate, every signer uses `-k_i` and the signature
+//! carries `-R`, so the result has the even `R` that verification requires.
+//!
+//! A [`MusigNonce`] must be used for exactly one signing session; signing twice
+//! with the same nonce leaks the secret key.
+
+use curve::{Affine, Group, RandomField, ScalarField};
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeCharacteristicRing;
+use p3_symmetric::CryptographicHasher;
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{MUSIG_COEFFICIENT_TAG, MUSIG_KEY_LIST_TAG, MUSIG_NONCE_TAG, POSEIDON2_OUT};
+use crate::errors::SchnorrError;
+use crate::keys::VerifyingKey;
+use crate::signatures::{
+    Signature, StreamingSponge, check_point, encode_point, hash_challenge, pack_digest_to_scalar,
+    sponge,
+};
+
+/// A signer's secret nonce `k_i` and its public point `R_i = k_i * G`.
+///
+/// Not `Clone`: [`SigningKey::musig_partial_sign`](crate::SigningKey::musig_partial_sign)
+/// consumes the nonce so it cannot be reused by accident.
+pub struct MusigNonce {
+    k: ScalarField,
+    r: Affine,
+}
+
+impl MusigNonce {
+    /// The public nonce `R_i`, revealed in the second round.
+    pub fn public_nonce(&self) -> Affine {
+        self.r
+    }
+
+    /// The commitment to [`MusigNonce::public_nonce`] sent in the first round.
+    pub fn commitment(&self) -> NonceCommitment {
+        NonceCommitment::new(&self.r)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for MusigNonce {
+    fn drop(&mut self) {
+        // SAFETY: `ScalarField` is plain limb data with no `Drop` impl, and the
+        // all-zero bit pattern is the valid element zero.
+        unsafe { zeroize::zeroize_flat_type(&mut self.k) };
+    }
+}
+
+impl core::fmt::Debug for MusigNonce {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Never print the secret nonce
+        f.debug_struct("MusigNonce")
+            .field("r", &self.r)
+            .finish_non_exhaustive()
+    }
+}
+
+/// First-round commitment `Poseidon2(tag || R_i)` to a signer's public nonce.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct NonceCommitment(pub [BabyBear; POSEIDON2_OUT]);
+
+impl NonceCommitment {
+    fn new(r: &Affine) -> Self {
+        let input = [BabyBear::from_u32(MUSIG_NONCE_TAG)]
+            .into_iter()
+            .chain(encode_point(r));
+        Self(sponge().hash_iter(input))
+    }
+}
+
+/// One signer's share of a MuSig signature.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialSignature {
+    /// The signer's public nonce `R_i`
+    pub r: Affine,
+    /// The partial response `s_i = k_i + e * a_i * x_i`
+    pub s: ScalarField,
+}
+
+/// Draws a fresh signing nonce and its first-round commitment.
+pub fn commit_nonce<R: Rng + ?Sized>(rng: &mut R) -> (MusigNonce, NonceCommitment) {
+    let k = ScalarField::random(rng);
+    let nonce = MusigNonce {
+        k,
+        r: <Affine as Group>::mul_generator(&k),
+    };
+    let commitment = nonce.commitment();
+    (nonce, commitment)
+}
+
+/// Aggregates `keys` into the single key `sum(a_i * X_i)` that MuSig signatures
+/// over them verify under.
+///
+/// The result depends on the order of `keys`, so all signers must agree on it
+/// (for example by sorting the encoded keys).
+///
+/// Returns `Err(SchnorrError::PointAtInfinity)` if `keys` is empty or the
+/// weighted sum is the identity.
+pub fn aggregate_keys(keys: &[VerifyingKey]) -> Result<VerifyingKey, SchnorrError> {
+    let (_, key) = aggregate(keys)?;
+    Ok(key)
+}
+
+/// Checks the revealed public nonces against their first-round commitments and
+/// sums them into the signature's `R`.
+///
+/// `commitments[i]` must be the commitment received from the signer that
+/// revealed `nonces[i]`.
+///
+/// # Errors
+///
+/// - `SchnorrError::NonceCommitmentMismatch` if the slices differ in length or a
+///   nonce does not match its commitment
+/// - `SchnorrError::PointAtInfinity` or `SchnorrError::NotOnCurve` for an invalid
+///   nonce, or if the nonces sum to the identity
+pub fn aggregate_nonces(
+    commitments: &[NonceCommitment],
+    nonces: &[Affine],
+) -> Result<Affine, SchnorrError> {
+    if commitments.len() != nonces.len() {
+        return Err(SchnorrError::NonceCommitmentMismatch);
+    }
+    for (commitment, nonce) in commitments.iter().zip(nonces) {
+        check_point(nonce)?;
+        if NonceCommitment::new(nonce) != *commitment {
+            return Err(SchnorrError::NonceCommitmentMismatch);
+        }
+    }
+
+    let r = nonces
+        .iter()
+        .fold(Affine::INFINITY, |acc, nonce| acc + *nonce);
+    check_point(&r)?;
+    Ok(r)
+}
+
+/// Checks one partial signature per key and combines them into a [`Signature`]
+/// that verifies under [`aggregate_keys`]`(keys)`.
+///
+/// `partials[i]` must come from the holder of `keys[i]`.
+///
+/// # Errors
+///
+/// - `SchnorrError::InvalidPartialSignature` if a partial signature is missing
+///   or does not verify for its key
+/// - The errors of [`aggregate_keys`] and [`aggregate_nonces`] for invalid keys
+///   or nonces
+pub fn aggregate_signatures(
+    keys: &[VerifyingKey],
+    msg: &[BabyBear],
+    partials: &[PartialSignature],
+) -> Result<Signature, SchnorrError> {
+    if partials.len() != keys.len() {
+        return Err(SchnorrError::InvalidPartialSignature);
+    }
+    let (list_hash, aggregated) = aggregate(keys)?;
+
+    let mut r = Affine::INFINITY;
+    for partial in partials {
+        check_point(&partial.r)?;
+        r += partial.r;
+    }
+    let negate = r.has_odd_y();
+    if negate {
+        r = -r;
+    }
+    let e = hash_challenge(&r, &aggregated.as_affine(), msg)?;
+
+    let mut s = ScalarField::ZERO;
+    for (key, partial) in keys.iter().zip(partials) {
+        if !partial.s.is_canonical() {
+            return Err(SchnorrError::InvalidScalar);
+        }
+        // s_i * G == ±R_i + (e * a_i) * X_i
+        let weight = e * key_coefficient(&list_hash, key);
+        let lhs = Affine::double_scalar_mul_basepoint(&partial.s, &-weight, &key.as_affine());
+        let expected = if negate { -partial.r } else { partial.r };
+        if lhs != expected {
+            return Err(SchnorrError::InvalidPartialSignature);
+        }
+        s += partial.s;
+    }
+
+    Ok(Signature { r, s })
+}
+
+/// Computes `s_i = k_i + e * a_i * sk` for the signer holding `sk`, with `k_i`
+/// negated when the aggregated nonce has an odd y-coordinate.
+pub(crate) fn partial_sign(
+    sk: &ScalarField,
+    pk: &VerifyingKey,
+    nonce: MusigNonce,
+    keys: &[VerifyingKey],
+    aggregated_nonce: &Affine,
+    msg: &[BabyBear],
+) -> Result<PartialSignature, SchnorrError> {
+    if !keys.contains(pk) {
+        return Err(SchnorrError::InvalidPartialSignature);
+    }
+    let (list_hash, aggregated) = aggregate(keys)?;
+    let (k, r) = if aggregated_nonce.has_odd_y() {
+        (-nonce.k, -*aggregated_nonce)
+    } else {
+        (nonce.k, *aggregated_nonce)
+    };
+    let e = hash_challenge(&r, &aggregated.as_affine(), msg)?;
+
+    let s = k + e * key_coefficient(&list_hash, pk) * *sk;
+    Ok(PartialSignature { r: nonce.r, s })
+}
+
+/// Returns `L = H(tag || X_1 || ... || X_n)` and the aggregated key.
+fn aggregate(
+    keys: &[VerifyingKey],
+) -> Result<([BabyBear; POSEIDON2_OUT], VerifyingKey), SchnorrError> {
+    let input = [BabyBear::from_u32(MUSIG_KEY_LIST_TAG)]
+        .into_iter()
+        .chain(keys.iter().flat_map(|key| encode_point(&key.as_affine())));
+    let list_hash = sponge().hash_iter(input);
+
+    let points: Vec<Affine> = keys.iter().map(VerifyingKey::as_affine).collect();
+    let coefficients: Vec<ScalarField> = keys
+        .iter()
+        .map(|key| key_coefficient(&list_hash, key))
+        .collect();
+    let aggregated = <Affine as Group>::multi_scalar_mul(&points, &coefficients);
+    Ok((list_hash, VerifyingKey::from_affine(aggregated)?))
+}
+
+/// The key-aggregation coefficient `a_i = H(tag || L || X_i)`.
+fn key_coefficient(list_hash: &[BabyBear; POSEIDON2_OUT], key: &VerifyingKey) -> ScalarField {
+    let mut sponge = StreamingSponge::new();
+    sponge.absorb(&[BabyBear::from_u32(MUSIG_COEFFICIENT_TAG)]);
+    sponge.absorb(list_hash);
+    sponge.absorb(&encode_point(&key.as_affine()));
+    pack_digest_to_scalar(&sponge.squeeze_wide())
+}
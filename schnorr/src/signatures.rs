@@ -1,13 +1,19 @@
 //! Signature types and challenge hashing for the Schnorr signature scheme.
 
-use curve::{Affine, KoalaBear, ScalarField};
+use curve::{Affine, Group, ScalarField, from_canonical_bytes, to_canonical_bytes, to_coeffs};
 use p3_baby_bear::{BabyBear, Poseidon2BabyBear, default_babybear_poseidon2_16};
-use p3_field::{PrimeCharacteristicRing, PrimeField32};
-use p3_symmetric::{CryptographicHasher, PaddingFreeSponge};
+use p3_field::{Field, PrimeCharacteristicRing, PrimeField32};
+use p3_symmetric::{CryptographicHasher, PaddingFreeSponge, Permutation};
 use serde::{Deserialize, Serialize};
 
-use crate::constants::{POSEIDON2_OUT, POSEIDON2_RATE, POSEIDON2_WIDTH};
+use crate::constants::{
+    CHALLENGE_DIGEST_LEN, CONTEXT_DOMAIN_TAG, KEYGEN_DOMAIN_TAG, MESSAGE_FIRST_ORDER_TAG,
+    MULTI_MESSAGE_TAG, NONCE_DOMAIN_TAG, PK_SIZE, POSEIDON2_OUT, POSEIDON2_RATE, POSEIDON2_WIDTH,
+    RECOVERABLE_SCHEME_TAG, SIG_SIZE, XONLY_SCHEME_TAG,
+};
 use crate::errors::SchnorrError;
+use crate::keys::VerifyingKey;
+use crate::transcript::Transcript;
 
 /// A Schnorr signature consisting of a curve point and a scalar.
 ///
@@ -18,7 +24,7 @@ use crate::errors::SchnorrError;
 /// # Structure
 ///
 /// The signature satisfies the verification equation: `G * s == R + pk * e`
-/// where `e = H(R || pk || msg)` is the Fiat-Shamir challenge.
+/// where `e = H(R || pk || len(msg) || msg)` is the Fiat-Shamir challenge.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Signature {
     /// The commitment point R = G * k, where k is the signing nonce
@@ -27,63 +33,729 @@ pub struct Signature {
     pub s: ScalarField,
 }
 
+impl Signature {
+    /// Encodes this signature as `R || s`: the 64-byte point encoding used by
+    /// [`VerifyingKey::to_bytes`](crate::VerifyingKey::to_bytes), then the 32
+    /// canonical little-endian bytes of `s`.
+    pub fn to_bytes(&self) -> [u8; SIG_SIZE] {
+        let mut bytes = [0u8; SIG_SIZE];
+        bytes[..PK_SIZE].copy_from_slice(&point_to_bytes(&self.r));
+        bytes[PK_SIZE..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+
+    /// Decodes a signature produced by [`Signature::to_bytes`].
+    ///
+    /// # Returns
+    ///
+    /// - `Err(SchnorrError::NotOnCurve)` if `R` is not a point on the curve
+    ///   (the point at infinity has no encoding)
+    /// - `Err(SchnorrError::InvalidScalar)` if `s` is not below the scalar field
+    ///   order, which would otherwise give a second encoding of the same signature
+    pub fn from_bytes(bytes: &[u8; SIG_SIZE]) -> Result<Self, SchnorrError> {
+        let (r_bytes, s_bytes) = bytes.split_at(PK_SIZE);
+        let r = point_from_bytes(r_bytes.try_into().expect("point encoding"))?;
+        let s = ScalarField::from_bytes(s_bytes.try_into().expect("scalar encoding"))
+            .ok_or(SchnorrError::InvalidScalar)?;
+        Ok(Self { r, s })
+    }
+
+    /// [`Signature::to_bytes`] as a `Vec`.
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.to_bytes().to_vec()
+    }
+
+    /// Parity (`sgn0`) of the `y`-coordinate of `R`, as passed to [`Signature::recover`].
+    ///
+    /// Together with `R.x` this determines `R`; it is the flag bit of
+    /// [`Affine::to_compressed`].
+    pub fn r_parity(&self) -> bool {
+        self.r.to_compressed()[32] == 1
+    }
+
+    /// Recovers the verifying key from a [`SignatureScheme::Recoverable`] signature.
+    ///
+    /// `R` is rebuilt from its `x`-coordinate and `r_parity`, the challenge
+    /// `e = H(tag || R.x || len(msg) || msg)` is computed without the key, and
+    /// the candidate `pk = (G * s - R) * e^-1` is returned if the signature
+    /// verifies under it.
+    ///
+    /// Recovery only works because the recoverable challenge does not commit to
+    /// `pk`; under [`SignatureScheme::Legacy`] and [`SignatureScheme::XOnly`] the
+    /// key is an input to `e` and cannot be solved for. Every recoverable signature
+    /// is valid for exactly one key, so callers must still check that the
+    /// recovered key is the one they expect.
+    ///
+    /// Returns `None` if `r_parity` does not match `R`, `R` is the point at
+    /// infinity, `s` is not canonical, or the challenge is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use schnorr::{SignatureScheme, SigningKey};
+    /// use p3_baby_bear::BabyBear;
+    /// use p3_field::PrimeCharacteristicRing;
+    /// use rand::thread_rng;
+    ///
+    /// let mut rng = thread_rng();
+    /// let signing_key = SigningKey::random(&mut rng);
+    /// let message = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    /// let signature = signing_key
+    ///     .sign_with_scheme(&mut rng, &message, SignatureScheme::Recoverable)
+    ///     .expect("signing failed");
+    ///
+    /// let recovered = signature.recover(&message, signature.r_parity());
+    /// assert_eq!(recovered, Some(signing_key.verifying_key()));
+    /// ```
+    pub fn recover(&self, msg: &[BabyBear], r_parity: bool) -> Option<VerifyingKey> {
+        if self.r.is_infinity() || !self.s.is_canonical() {
+            return None;
+        }
+
+        let mut compressed = self.r.to_compressed();
+        compressed[32] = r_parity as u8;
+        let r = Affine::from_compressed(&compressed)?;
+
+        // The key is not absorbed under this scheme; `r` only fills the slot
+        let e = hash_challenge_with_prefix(SignatureScheme::Recoverable, &[], &r, &r, msg).ok()?;
+        let e_inv = e.try_inverse()?;
+        let point = (<Affine as Group>::mul_generator(&self.s) - r).scalar_mul(&e_inv);
+        let pk = VerifyingKey::from_affine(point).ok()?;
+
+        pk.verify_with_scheme(msg, self, SignatureScheme::Recoverable)
+            .ok()?
+            .then_some(pk)
+    }
+}
+
+impl TryFrom<&[u8]> for Signature {
+    type Error = SchnorrError;
+
+    /// Same as [`Signature::from_bytes`], but returns
+    /// `Err(SchnorrError::InvalidLength)` unless `bytes` has exactly `SIG_SIZE` bytes.
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_bytes(bytes.try_into().map_err(|_| SchnorrError::InvalidLength)?)
+    }
+}
+
 /// Computes the Fiat-Shamir challenge for the Schnorr signature scheme.
 ///
-/// The challenge is computed as `e = H(R || pk || msg)` using the Poseidon2
-/// hash function over the KoalaBear field.
+/// The challenge is `e = H(R || pk || len(msg) || msg)` where `H` is a Poseidon2
+/// sponge over the BabyBear field. This encoding is part of the public API: the
+/// circuit crate proves the sponge digest and its verifier recomputes `e` from
+/// it with the same packing, so any change is a breaking change.
 ///
 /// # Arguments
 ///
 /// * `r` - The commitment point R from the signature
 /// * `pk` - The public verifying key
-/// * `msg` - The message being signed/verified, encoded as KoalaBear field elements
+/// * `msg` - The message being signed/verified, encoded as BabyBear field elements
 ///
 /// # Returns
 ///
 /// Returns a `Result` containing:
 /// - `Ok(e)` where `e` is the challenge scalar
-/// - `Err(SchnorrError::InvalidPoint)` if either `r` or `pk` is the point at infinity
+/// - `Err(SchnorrError::PointAtInfinity)` if either `r` or `pk` is the point at infinity
+/// - `Err(SchnorrError::HashError)` if `msg` has `2^31 - 2^27 + 1` elements or more
 ///
-/// # Implementation Details
+/// # Encoding
 ///
-/// 1. Points are encoded as 16 KoalaBear field elements (8 for x-coordinate, 8 for y-coordinate)
-/// 2. The input is `R || pk || msg` concatenated
-/// 3. Poseidon2 with width 16, rate 8, and output 8 is used for hashing
-/// 4. The first 5 digest elements are packed into a scalar field element
+/// 1. Each point is encoded as 16 BabyBear elements: the 8 coefficients of `x`
+///    (`u^0` first) followed by the 8 coefficients of `y`. Each canonical
+///    KoalaBear coefficient `c` becomes `BabyBear::from_u32(c)`, i.e. it is
+///    reduced modulo the BabyBear prime `2^31 - 2^27 + 1`.
+/// 2. The sponge input is the 32 point elements of `R`, then of `pk`, then the
+///    message length `msg.len()` as one element, then `msg`. The length element
+///    keeps the split between the points and a variable-length message unambiguous.
+/// 3. The sponge is `PaddingFreeSponge<Poseidon2BabyBear<16>, 16, 8, 8>` with the
+///    default Plonky3 round constants (`default_babybear_poseidon2_16`).
+/// 4. The sponge is squeezed for [`CHALLENGE_DIGEST_LEN`] elements: the usual 8-element
+///    digest, then the first 8 elements of the state after one more permutation.
+/// 5. The 16 canonical elements `d0..d15` are read as the little-endian base-`p_BabyBear`
+///    integer `sum(d_i * p_BabyBear^i)` (~494 bits) and reduced modulo the scalar field
+///    order, so `e` covers the whole field with a bias of about `2^-246`.
+/// 6. If that gives zero, which would drop `pk` from the verification equation,
+///    the counter `1` is absorbed after the input and steps 4-5 are repeated,
+///    then `2`, and so on, so `e` is never zero. This happens with probability
+///    about `2^-248` per hash.
 pub fn hash_challenge(
     r: &Affine,
     pk: &Affine,
     msg: &[BabyBear],
+) -> Result<ScalarField, SchnorrError> {
+    hash_challenge_with_prefix(SignatureScheme::Legacy, &[], r, pk, msg)
+}
+
+/// A Fiat-Shamir hash mapping `(R, pk, msg)` to the challenge scalar.
+///
+/// [`SigningKey::sign_with_hash`](crate::SigningKey::sign_with_hash) and
+/// [`VerifyingKey::verify_with_hash`](crate::VerifyingKey::verify_with_hash)
+/// accept any implementation, which lets alternative sponges or test mocks be
+/// swapped in. [`Poseidon2Challenge`] is the default behind
+/// [`SigningKey::sign`](crate::SigningKey::sign) and
+/// [`VerifyingKey::verify`](crate::VerifyingKey::verify), and the only one the
+/// circuit can prove.
+pub trait ChallengeHash {
+    /// Computes the challenge for nonce commitment `r`, public key `pk` and `msg`.
+    fn hash(&self, r: &Affine, pk: &Affine, msg: &[BabyBear]) -> Result<ScalarField, SchnorrError>;
+}
+
+/// The Poseidon2 challenge of [`hash_challenge`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Poseidon2Challenge;
+
+impl ChallengeHash for Poseidon2Challenge {
+    fn hash(&self, r: &Affine, pk: &Affine, msg: &[BabyBear]) -> Result<ScalarField, SchnorrError> {
+        hash_challenge(r, pk, msg)
+    }
+}
+
+/// Order in which the challenge absorbs the nonce commitment and the message,
+/// for fitting into transcripts that commit to the message first.
+///
+/// The orderings are not cross-compatible: a signature made under one verifies
+/// only under the same one, because the message-first input starts with its own
+/// identifier element. Only [`ChallengeOrder::NonceFirst`] is what
+/// [`VerifyingKey::verify`](crate::VerifyingKey::verify) checks and what the
+/// circuit proves. Each ordering is a [`ChallengeHash`], so signing and
+/// verifying go through
+/// [`SigningKey::sign_with_hash`](crate::SigningKey::sign_with_hash) and
+/// [`VerifyingKey::verify_with_hash`](crate::VerifyingKey::verify_with_hash).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ChallengeOrder {
+    /// `R || pk || len(msg) || msg`, the challenge of [`hash_challenge`].
+    #[default]
+    NonceFirst,
+    /// `tag || len(msg) || msg || R || pk`, with a fixed ordering identifier
+    /// element as the tag.
+    MessageFirst,
+}
+
+impl ChallengeHash for ChallengeOrder {
+    fn hash(&self, r: &Affine, pk: &Affine, msg: &[BabyBear]) -> Result<ScalarField, SchnorrError> {
+        hash_challenge_ordered(*self, r, pk, msg)
+    }
+}
+
+/// Challenge over a list of messages, used by
+/// [`SigningKey::sign_many`](crate::SigningKey::sign_many) and
+/// [`VerifyingKey::verify_many`](crate::VerifyingKey::verify_many).
+///
+/// Absorbs `tag || count || R || pk`, then `len(m_i) || m_i` for each message, so
+/// moving an element across a message boundary changes the challenge. The `msg`
+/// argument of [`ChallengeHash::hash`] is ignored.
+pub(crate) struct MultiMessageChallenge<'a>(pub(crate) &'a [&'a [BabyBear]]);
+
+impl ChallengeHash for MultiMessageChallenge<'_> {
+    fn hash(
+        &self,
+        r: &Affine,
+        pk: &Affine,
+        _msg: &[BabyBear],
+    ) -> Result<ScalarField, SchnorrError> {
+        if r.is_infinity() || pk.is_infinity() {
+            return Err(SchnorrError::PointAtInfinity);
+        }
+        let mut transcript = Transcript::new();
+        transcript.absorb_elements(&[
+            BabyBear::from_u32(MULTI_MESSAGE_TAG),
+            length_element(self.0.len())?,
+        ]);
+        transcript.absorb_point(r);
+        transcript.absorb_point(pk);
+        for msg in self.0 {
+            transcript.absorb_message(msg)?;
+        }
+        Ok(transcript.challenge_scalar())
+    }
+}
+
+/// Computes the challenge with the inputs absorbed in `order`.
+///
+/// `ChallengeOrder::NonceFirst` gives [`hash_challenge`]. Points are encoded,
+/// and the digest is reduced, as described there; errors are the same.
+pub fn hash_challenge_ordered(
+    order: ChallengeOrder,
+    r: &Affine,
+    pk: &Affine,
+    msg: &[BabyBear],
+) -> Result<ScalarField, SchnorrError> {
+    match order {
+        ChallengeOrder::NonceFirst => hash_challenge(r, pk, msg),
+        ChallengeOrder::MessageFirst => {
+            if r.is_infinity() || pk.is_infinity() {
+                return Err(SchnorrError::PointAtInfinity);
+            }
+            let mut transcript = Transcript::new();
+            transcript.absorb_elements(&[BabyBear::from_u32(MESSAGE_FIRST_ORDER_TAG)]);
+            transcript.absorb_message(msg)?;
+            transcript.absorb_point(r);
+            transcript.absorb_point(pk);
+            Ok(transcript.challenge_scalar())
+        }
+    }
+}
+
+/// Which points the Fiat-Shamir challenge commits to.
+///
+/// Signatures are only valid under the scheme they were made with; the
+/// verifier must be told which one to use.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SignatureScheme {
+    /// `e = H(R || pk || len(msg) || msg)` over the full coordinates of both
+    /// points, as computed by [`hash_challenge`]. Used by
+    /// [`SigningKey::sign`](crate::SigningKey::sign) and
+    /// [`VerifyingKey::verify`](crate::VerifyingKey::verify).
+    #[default]
+    Legacy,
+    /// BIP340-style binding `e = H(tag || R.x || pk.x || len(msg) || msg)`: a
+    /// fixed scheme identifier element followed by only the 8 `x` coefficients
+    /// of each point.
+    XOnly,
+    /// Key-recoverable binding `e = H(tag || R.x || len(msg) || msg)`, which
+    /// leaves `pk` out of the challenge so that [`Signature::recover`] can solve
+    /// for it.
+    ///
+    /// Use only where the key is recovered or otherwise fixed by the protocol:
+    /// the signature does not commit to which key it was made for.
+    Recoverable,
+}
+
+impl SignatureScheme {
+    /// Computes the challenge for this scheme.
+    ///
+    /// Returns `Err(SchnorrError::PointAtInfinity)` if either `r` or `pk` is the
+    /// point at infinity.
+    pub fn hash_challenge(
+        self,
+        r: &Affine,
+        pk: &Affine,
+        msg: &[BabyBear],
+    ) -> Result<ScalarField, SchnorrError> {
+        hash_challenge_with_prefix(self, &[], r, pk, msg)
+    }
+}
+
+/// Computes the challenge `e = H(prefix || R || pk || len(msg) || msg)` with the
+/// points encoded as selected by `scheme`.
+///
+/// An empty prefix gives [`hash_challenge`]; a non-empty prefix comes from
+/// [`context_prefix`] and domain-separates the challenge.
+pub(crate) fn hash_challenge_with_prefix(
+    scheme: SignatureScheme,
+    prefix: &[BabyBear],
+    r: &Affine,
+    pk: &Affine,
+    msg: &[BabyBear],
 ) -> Result<ScalarField, SchnorrError> {
     if r.is_infinity() || pk.is_infinity() {
-        return Err(SchnorrError::InvalidPoint);
+        return Err(SchnorrError::PointAtInfinity);
     }
 
-    let mut input = Vec::with_capacity(msg.len() + 32);
-    input.extend_from_slice(&encode_point(r));
-    input.extend_from_slice(&encode_point(pk));
-    input.extend_from_slice(msg);
+    let mut transcript = Transcript::new();
+    transcript.absorb_elements(prefix);
+    match scheme {
+        SignatureScheme::Legacy => {
+            transcript.absorb_point(r);
+            transcript.absorb_point(pk);
+        }
+        SignatureScheme::XOnly => {
+            transcript.absorb_elements(&[BabyBear::from_u32(XONLY_SCHEME_TAG)]);
+            transcript.absorb_elements(&encode_point(r)[..8]);
+            transcript.absorb_elements(&encode_point(pk)[..8]);
+        }
+        SignatureScheme::Recoverable => {
+            transcript.absorb_elements(&[BabyBear::from_u32(RECOVERABLE_SCHEME_TAG)]);
+            transcript.absorb_elements(&encode_point(r)[..8]);
+        }
+    }
+    transcript.absorb_message(msg)?;
 
-    let perm = default_babybear_poseidon2_16();
-    let sponge = PaddingFreeSponge::<
-        Poseidon2BabyBear<POSEIDON2_WIDTH>,
-        POSEIDON2_WIDTH,
-        POSEIDON2_RATE,
-        POSEIDON2_OUT,
-    >::new(perm);
+    Ok(transcript.challenge_scalar())
+}
 
-    let digest = sponge.hash_iter(input);
-    let d0 = digest[0].as_canonical_u32() as u64;
-    let d1 = digest[1].as_canonical_u32() as u64;
-    let d2 = digest[2].as_canonical_u32() as u64;
-    let d3 = digest[3].as_canonical_u32() as u64;
-    let d4 = digest[4].as_canonical_u32() as u64;
+/// Incremental Fiat-Shamir challenge computation for messages that arrive in chunks.
+///
+/// Obtained from [`SigningKey::challenge_hasher`](crate::SigningKey::challenge_hasher)
+/// or [`VerifyingKey::challenge_hasher`](crate::VerifyingKey::challenge_hasher), which
+/// absorb `R || pk` and the declared message length up front. Message chunks are then
+/// absorbed with [`update`](Self::update) as they arrive, without buffering the whole
+/// message, and the result is identical to the one-shot [`hash_challenge`] over the
+/// concatenation.
+///
+/// # Example
+///
+/// ```
+/// use schnorr::SigningKey;
+/// use p3_baby_bear::BabyBear;
+/// use p3_field::PrimeCharacteristicRing;
+/// use rand::thread_rng;
+///
+/// let mut rng = thread_rng();
+/// let signing_key = SigningKey::random(&mut rng);
+/// let verifying_key = signing_key.verifying_key();
+/// let message: Vec<BabyBear> = (0..100).map(BabyBear::from_u32).collect();
+///
+/// let mut hasher = signing_key
+///     .challenge_hasher(&mut rng, message.len())
+///     .expect("nonce");
+/// for chunk in message.chunks(30) {
+///     hasher.update(chunk);
+/// }
+/// let signature = hasher.finalize_sign().expect("signing failed");
+///
+/// let mut hasher = verifying_key
+///     .challenge_hasher(&signature, message.len())
+///     .expect("valid points");
+/// hasher.update(&message);
+/// assert!(hasher.finalize_verify().expect("verification failed"));
+/// ```
+#[derive(Clone)]
+pub struct ChallengeHasher {
+    transcript: Transcript,
+    role: HasherRole,
+    /// Message length committed to in the challenge
+    msg_len: usize,
+    /// Message elements absorbed so far
+    absorbed: usize,
+}
+
+/// What [`ChallengeHasher`] does with the challenge once the message is absorbed.
+#[derive(Clone)]
+pub(crate) enum HasherRole {
+    /// Compute `s = k + e * sk` for the commitment `R = G * k`.
+    Sign {
+        r: Affine,
+        nonce: ScalarField,
+        secret: ScalarField,
+    },
+    /// Check `G * s == R + pk * e`.
+    Verify {
+        r: Affine,
+        pk: Affine,
+        s: ScalarField,
+    },
+}
 
-    let limb0 = d0 | (d1 << 31);
-    let limb1 = d2 | (d3 << 31);
-    let limb2 = d4;
+impl ChallengeHasher {
+    /// Starts a challenge over `R || pk || msg_len`.
+    ///
+    /// Returns `Err(SchnorrError::PointAtInfinity)` if either point is at infinity,
+    /// or `Err(SchnorrError::HashError)` if `msg_len` does not fit in one element.
+    pub(crate) fn new(
+        r: &Affine,
+        pk: &Affine,
+        msg_len: usize,
+        role: HasherRole,
+    ) -> Result<Self, SchnorrError> {
+        if r.is_infinity() || pk.is_infinity() {
+            return Err(SchnorrError::PointAtInfinity);
+        }
 
-    Ok(ScalarField::from_canonical_limbs([limb0, limb1, limb2, 0]))
+        let mut transcript = Transcript::new();
+        transcript.absorb_point(r);
+        transcript.absorb_point(pk);
+        transcript.absorb_elements(&[length_element(msg_len)?]);
+        Ok(Self {
+            transcript,
+            role,
+            msg_len,
+            absorbed: 0,
+        })
+    }
+
+    /// Absorbs the next chunk of the message.
+    pub fn update(&mut self, chunk: &[BabyBear]) {
+        self.transcript.absorb_elements(chunk);
+        self.absorbed += chunk.len();
+    }
+
+    /// The challenge, once exactly the declared number of elements was absorbed.
+    fn challenge(&self) -> Result<ScalarField, SchnorrError> {
+        if self.absorbed != self.msg_len {
+            return Err(SchnorrError::HashError);
+        }
+        Ok(self.transcript.challenge_scalar())
+    }
+
+    /// Finishes signing and returns the signature over all absorbed chunks.
+    ///
+    /// Returns `Err(SchnorrError::HashError)` if the chunks do not add up to the
+    /// message length given when the hasher was created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hasher was created by [`VerifyingKey::challenge_hasher`](crate::VerifyingKey::challenge_hasher).
+    pub fn finalize_sign(self) -> Result<Signature, SchnorrError> {
+        let HasherRole::Sign { r, nonce, secret } = self.role else {
+            panic!("finalize_sign called on a verifying ChallengeHasher");
+        };
+
+        let e = self.challenge()?;
+        Ok(Signature {
+            r,
+            s: nonce + e * secret,
+        })
+    }
+
+    /// Finishes verification and reports whether the signature is valid for
+    /// the absorbed message.
+    ///
+    /// Returns `Err(SchnorrError::HashError)` if the chunks do not add up to the
+    /// message length given when the hasher was created.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hasher was created by [`SigningKey::challenge_hasher`](crate::SigningKey::challenge_hasher).
+    pub fn finalize_verify(self) -> Result<bool, SchnorrError> {
+        let HasherRole::Verify { r, pk, s } = self.role else {
+            panic!("finalize_verify called on a signing ChallengeHasher");
+        };
+
+        let e = self.challenge()?;
+        if r.has_odd_y() {
+            return Ok(false);
+        }
+        let lhs = Affine::double_scalar_mul_basepoint(&s, &-e, &pk);
+        Ok(lhs == r)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ChallengeHasher {
+    fn drop(&mut self) {
+        if let HasherRole::Sign { nonce, secret, .. } = &mut self.role {
+            // SAFETY: `ScalarField` is plain limb data with no `Drop` impl, and the
+            // all-zero bit pattern is the valid element zero.
+            unsafe {
+                zeroize::zeroize_flat_type(nonce);
+                zeroize::zeroize_flat_type(secret);
+            }
+        }
+    }
+}
+
+impl core::fmt::Debug for ChallengeHasher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        // Never print the nonce or the secret scalar
+        f.debug_struct("ChallengeHasher").finish_non_exhaustive()
+    }
+}
+
+/// Encodes an application context string as a challenge prefix: a domain tag
+/// followed by the length-prefixed packing of the context bytes.
+pub(crate) fn context_prefix(context: &[u8]) -> Result<Vec<BabyBear>, SchnorrError> {
+    let mut prefix = vec![BabyBear::from_u32(CONTEXT_DOMAIN_TAG)];
+    prefix.extend(encode_bytes(context)?);
+    Ok(prefix)
+}
+
+/// Derives a deterministic signing nonce from the secret scalar and the message.
+///
+/// The nonce is `k = H(tag || sk || msg)` reduced with [`hash_to_scalar_wide`]. The
+/// secret scalar is absorbed as sixteen 16-bit chunks of its canonical byte encoding.
+pub(crate) fn derive_nonce(sk: &ScalarField, msg: &[BabyBear]) -> ScalarField {
+    let sk_bytes = sk.to_bytes();
+    let sk_elems = sk_bytes
+        .chunks_exact(2)
+        .map(|chunk| BabyBear::from_u16(u16::from_le_bytes([chunk[0], chunk[1]])));
+
+    hash_to_scalar_wide(NONCE_DOMAIN_TAG, sk_elems.chain(msg.iter().copied()))
+}
+
+/// Derives a secret scalar from seed bytes, `sk = H(tag || encode_bytes(seed))`.
+pub(crate) fn derive_secret_scalar(seed: &[u8]) -> ScalarField {
+    let seed = encode_bytes(seed).expect("seed too long to encode");
+    hash_to_scalar_wide(KEYGEN_DOMAIN_TAG, seed.into_iter())
+}
+
+/// Packs bytes densely into BabyBear elements: a length prefix followed by
+/// 3 bytes (little-endian) per element, the last element zero-padded.
+///
+/// The length prefix keeps the encoding injective, so inputs differing only in
+/// trailing zero bytes map to different element sequences. Returns
+/// `Err(SchnorrError::HashError)` if the length does not fit in one element.
+pub(crate) fn encode_bytes(bytes: &[u8]) -> Result<Vec<BabyBear>, SchnorrError> {
+    let mut out = Vec::with_capacity(1 + bytes.len().div_ceil(3));
+    out.push(length_element(bytes.len())?);
+    out.extend(bytes.chunks(3).map(|chunk| {
+        let mut word = [0u8; 4];
+        word[..chunk.len()].copy_from_slice(chunk);
+        BabyBear::from_u32(u32::from_le_bytes(word))
+    }));
+    Ok(out)
+}
+
+/// Encodes a length as a single BabyBear element.
+///
+/// Returns `Err(SchnorrError::HashError)` if `len` is not below the BabyBear prime.
+pub(crate) fn length_element(len: usize) -> Result<BabyBear, SchnorrError> {
+    if len >= BabyBear::ORDER_U32 as usize {
+        return Err(SchnorrError::HashError);
+    }
+    Ok(BabyBear::from_u32(len as u32))
+}
+
+/// Hashes `tag || data` into a statistically uniform scalar.
+///
+/// The input is hashed twice with a counter (`H(tag || 0 || data)`, `H(tag || 1 || data)`)
+/// and the two 8-element digests are read as a 16-digit base-`p_BabyBear` integer
+/// (~494 bits) before reducing modulo the scalar field order, leaving a bias of about
+/// `2^-246`.
+fn hash_to_scalar_wide<I>(tag: u32, data: I) -> ScalarField
+where
+    I: Iterator<Item = BabyBear> + Clone,
+{
+    let sponge = sponge();
+    let digests = [0u32, 1].map(|counter| {
+        let input = [BabyBear::from_u32(tag), BabyBear::from_u32(counter)]
+            .into_iter()
+            .chain(data.clone());
+        sponge.hash_iter(input)
+    });
+
+    let digits: Vec<BabyBear> = digests.iter().flatten().copied().collect();
+    pack_digest_to_scalar(&digits)
+}
+
+/// Reads `digest` as the little-endian base-`p_BabyBear` integer
+/// `sum(d_i * p_BabyBear^i)` and reduces it modulo the scalar field order.
+///
+/// This is the packing step of [`hash_challenge`], shared with the circuit so
+/// that the challenge it recomputes from a proven digest matches the signer's.
+/// Every canonical BabyBear element fits in 31 bits, which keeps the digits below
+/// the radix; this is checked in debug builds.
+pub fn pack_digest_to_scalar(digest: &[BabyBear]) -> ScalarField {
+    let radix = ScalarField::from_canonical_u64(BabyBear::ORDER_U32 as u64);
+    digest.iter().rev().fold(ScalarField::ZERO, |acc, d| {
+        let digit = d.as_canonical_u32();
+        debug_assert!(digit < 1 << 31, "digest element {digit} exceeds 31 bits");
+        acc * radix + ScalarField::from_canonical_u64(digit as u64)
+    })
+}
+
+/// Poseidon2 sponge in overwrite mode that absorbs input incrementally.
+///
+/// Absorbing a sequence in any chunking gives the same state as [`sponge`] over the
+/// whole sequence: each full rate block is written over `state[..RATE]` and permuted,
+/// and a short final block is permuted once when squeezing.
+#[derive(Clone)]
+pub(crate) struct StreamingSponge {
+    perm: Poseidon2BabyBear<POSEIDON2_WIDTH>,
+    state: [BabyBear; POSEIDON2_WIDTH],
+    pos: usize,
+}
+
+impl StreamingSponge {
+    pub(crate) fn new() -> Self {
+        Self {
+            perm: default_babybear_poseidon2_16(),
+            state: [BabyBear::ZERO; POSEIDON2_WIDTH],
+            pos: 0,
+        }
+    }
+
+    pub(crate) fn absorb(&mut self, input: &[BabyBear]) {
+        for &value in input {
+            self.state[self.pos] = value;
+            self.pos += 1;
+            if self.pos == POSEIDON2_RATE {
+                self.perm.permute_mut(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Squeezes [`CHALLENGE_DIGEST_LEN`] elements: the sponge digest followed by
+    /// the first `POSEIDON2_OUT` elements of one further permutation of the state.
+    pub(crate) fn squeeze_wide(&self) -> [BabyBear; CHALLENGE_DIGEST_LEN] {
+        let mut state = self.state;
+        if self.pos != 0 {
+            self.perm.permute_mut(&mut state);
+        }
+
+        let mut out = [BabyBear::ZERO; CHALLENGE_DIGEST_LEN];
+        out[..POSEIDON2_OUT].copy_from_slice(&state[..POSEIDON2_OUT]);
+        self.perm.permute_mut(&mut state);
+        out[POSEIDON2_OUT..].copy_from_slice(&state[..POSEIDON2_OUT]);
+        out
+    }
+}
+
+/// Poseidon2 sponge used for all hashing in the signature scheme.
+pub(crate) fn sponge() -> PaddingFreeSponge<
+    Poseidon2BabyBear<POSEIDON2_WIDTH>,
+    POSEIDON2_WIDTH,
+    POSEIDON2_RATE,
+    POSEIDON2_OUT,
+> {
+    PaddingFreeSponge::new(default_babybear_poseidon2_16())
+}
+
+/// Hashes `input` with the Poseidon2 sponge the signature scheme uses internally.
+///
+/// This is `PaddingFreeSponge<Poseidon2BabyBear<16>, 16, 8, 8>` with the default
+/// BabyBear round constants: rate-8 blocks overwrite the state, a short final
+/// block is not padded, and the digest is the first 8 state elements. Inputs are
+/// not length-prefixed, so callers hashing variable-length data should commit to
+/// the length themselves; in particular the empty input hashes to all zeros.
+///
+/// # Example
+///
+/// ```
+/// use p3_baby_bear::BabyBear;
+/// use p3_field::PrimeCharacteristicRing;
+/// use schnorr::poseidon2_hash;
+///
+/// let leaves = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+/// let digest = poseidon2_hash(&leaves);
+/// assert_eq!(digest, poseidon2_hash(&leaves));
+/// assert_ne!(digest, poseidon2_hash(&leaves[..1]));
+/// ```
+pub fn poseidon2_hash(input: &[BabyBear]) -> [BabyBear; POSEIDON2_OUT] {
+    sponge().hash_slice(input)
+}
+
+/// Encodes a point as `x || y`, each coordinate as 8 canonical little-endian
+/// `u32` coefficients.
+pub(crate) fn point_to_bytes(point: &Affine) -> [u8; PK_SIZE] {
+    let mut bytes = [0u8; PK_SIZE];
+    bytes[..32].copy_from_slice(&to_canonical_bytes(point.x));
+    bytes[32..].copy_from_slice(&to_canonical_bytes(point.y));
+    bytes
+}
+
+/// Decodes a point encoded by [`point_to_bytes`], rejecting non-canonical
+/// coefficients and coordinates that are not on the curve.
+pub(crate) fn point_from_bytes(bytes: &[u8; PK_SIZE]) -> Result<Affine, SchnorrError> {
+    let (x_bytes, y_bytes) = bytes.split_at(32);
+    let x = from_canonical_bytes(x_bytes.try_into().expect("32-byte coordinate"))
+        .ok_or(SchnorrError::NotOnCurve)?;
+    let y = from_canonical_bytes(y_bytes.try_into().expect("32-byte coordinate"))
+        .ok_or(SchnorrError::NotOnCurve)?;
+    Affine::from_coords_checked(x, y).ok_or(SchnorrError::NotOnCurve)
+}
+
+/// Returns `(k, k * G)` with the nonce negated if needed so the commitment has
+/// an even y, as [`VerifyingKey::verify`](crate::VerifyingKey::verify) requires.
+pub(crate) fn even_commitment(nonce: ScalarField) -> (ScalarField, Affine) {
+    let r = <Affine as Group>::mul_generator(&nonce);
+    if r.has_odd_y() {
+        (-nonce, -r)
+    } else {
+        (nonce, r)
+    }
+}
+
+/// Checks that `point` is a usable key or commitment: not the identity and on the curve.
+pub(crate) fn check_point(point: &Affine) -> Result<(), SchnorrError> {
+    if point.is_infinity() {
+        return Err(SchnorrError::PointAtInfinity);
+    }
+    if !point.is_on_curve() {
+        return Err(SchnorrError::NotOnCurve);
+    }
+    Ok(())
 }
 
 /// Encodes an elliptic curve point as an array of KoalaBear field elements.
@@ -101,9 +773,9 @@ pub fn hash_challenge(
 /// An array of 16 KoalaBear field elements:
 /// - Elements 0-7: x-coordinate coefficients
 /// - Elements 8-15: y-coordinate coefficients
-fn encode_point(point: &Affine) -> [BabyBear; 16] {
-    let x_coeffs: [KoalaBear; 8] = unsafe { core::mem::transmute(point.x) };
-    let y_coeffs: [KoalaBear; 8] = unsafe { core::mem::transmute(point.y) };
+pub(crate) fn encode_point(point: &Affine) -> [BabyBear; 16] {
+    let x_coeffs = to_coeffs(point.x);
+    let y_coeffs = to_coeffs(point.y);
 
     let mut out = [BabyBear::ZERO; 16];
     for (i, coeff) in x_coeffs.into_iter().enumerate() {
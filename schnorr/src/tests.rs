@@ -60,3 +60,1280 @@ fn test_verify_rejects_wrong_key() {
     let ok = wrong_pk.verify(&msg, &sig).expect("verify");
     assert!(!ok);
 }
+
+#[test]
+fn test_sign_deterministic() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(4), BabyBear::from_u32(5)];
+
+    let sig1 = sk.sign_deterministic(&msg).expect("sign");
+    let sig2 = sk.sign_deterministic(&msg).expect("sign");
+    assert_eq!(
+        bincode::serialize(&sig1).expect("serialize"),
+        bincode::serialize(&sig2).expect("serialize")
+    );
+    assert!(pk.verify(&msg, &sig1).expect("verify"));
+
+    let other = sk
+        .sign_deterministic(&[BabyBear::from_u32(4), BabyBear::from_u32(6)])
+        .expect("sign");
+    assert_ne!(sig1.r, other.r);
+
+    let other_key = SigningKey::random(&mut rng)
+        .sign_deterministic(&msg)
+        .expect("sign");
+    assert_ne!(sig1.r, other_key.r);
+}
+
+#[test]
+fn test_verify_batch() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let keys: Vec<SigningKey> = (0..8).map(|_| SigningKey::random(&mut rng)).collect();
+    let msgs: Vec<[BabyBear; 2]> = (0..8u32)
+        .map(|i| [BabyBear::from_u32(i), BabyBear::from_u32(i + 100)])
+        .collect();
+    let mut sigs: Vec<Signature> = keys
+        .iter()
+        .zip(&msgs)
+        .map(|(sk, msg)| sk.sign(&mut rng, msg).expect("sign"))
+        .collect();
+
+    let batch = |sigs: &[Signature]| -> Result<bool, SchnorrError> {
+        let items: Vec<(VerifyingKey, &[BabyBear], &Signature)> = keys
+            .iter()
+            .zip(&msgs)
+            .zip(sigs)
+            .map(|((sk, msg), sig)| (sk.verifying_key(), &msg[..], sig))
+            .collect();
+        VerifyingKey::verify_batch(&items)
+    };
+
+    assert!(batch(&sigs).expect("verify"));
+    assert!(VerifyingKey::verify_batch(&[]).expect("verify"));
+
+    sigs[5].s += curve::ScalarField::ONE;
+    assert!(!batch(&sigs).expect("verify"));
+}
+
+#[test]
+fn test_from_seed() {
+    let seed = [7u8; 32];
+    let pk1 = SigningKey::from_seed(&seed).verifying_key();
+    let pk2 = SigningKey::from_seed(&seed).verifying_key();
+    assert_eq!(pk1, pk2);
+
+    let mut other_seed = seed;
+    other_seed[31] ^= 1;
+    assert_ne!(SigningKey::from_seed(&other_seed).verifying_key(), pk1);
+
+    // Trailing zero bytes are not ignored
+    assert_ne!(SigningKey::from_seed(&[1]), SigningKey::from_seed(&[1, 0]));
+}
+
+#[test]
+fn test_context_separation() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+
+    let sig = sk
+        .sign_with_context(&mut rng, b"context-a", &msg)
+        .expect("sign");
+    assert!(
+        pk.verify_with_context(b"context-a", &msg, &sig)
+            .expect("verify")
+    );
+    assert!(
+        !pk.verify_with_context(b"context-b", &msg, &sig)
+            .expect("verify")
+    );
+    assert!(!pk.verify_with_context(b"", &msg, &sig).expect("verify"));
+    assert!(!pk.verify(&msg, &sig).expect("verify"));
+
+    let plain = sk.sign(&mut rng, &msg).expect("sign");
+    assert!(!pk.verify_with_context(b"", &msg, &plain).expect("verify"));
+    assert!(
+        !pk.verify_with_context(b"context-a", &msg, &plain)
+            .expect("verify")
+    );
+}
+
+#[test]
+fn test_sign_verify_bytes() {
+    let mut rng = StdRng::seed_from_u64(42);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+
+    let sig = sk.sign_bytes(&mut rng, b"hello schnorr").expect("sign");
+    assert!(pk.verify_bytes(b"hello schnorr", &sig).expect("verify"));
+    assert!(!pk.verify_bytes(b"hello schnorr!", &sig).expect("verify"));
+
+    let sig = sk.sign_bytes(&mut rng, &[0x01]).expect("sign");
+    assert!(pk.verify_bytes(&[0x01], &sig).expect("verify"));
+    assert!(!pk.verify_bytes(&[0x01, 0x00], &sig).expect("verify"));
+}
+
+#[test]
+fn test_encode_bytes_is_unambiguous() {
+    use crate::signatures::encode_bytes;
+
+    let a = encode_bytes(&[0x01]).unwrap();
+    let b = encode_bytes(&[0x01, 0x00]).unwrap();
+    assert_ne!(a, b);
+    assert_eq!(encode_bytes(&[]), Ok(vec![BabyBear::ZERO]));
+    assert_eq!(encode_bytes(&[0u8; 7]).unwrap().len(), 4);
+
+    let r = <curve::Affine as curve::Group>::generator();
+    let pk = r.double();
+    assert_ne!(
+        hash_challenge(&r, &pk, &a).expect("hash"),
+        hash_challenge(&r, &pk, &b).expect("hash")
+    );
+}
+
+#[test]
+fn test_hash_challenge_encoding() {
+    use crate::signatures::encode_point;
+    use curve::ScalarField;
+    use p3_baby_bear::default_babybear_poseidon2_16;
+    use p3_field::PrimeField32;
+    use p3_symmetric::{CryptographicHasher, PaddingFreeSponge, Permutation};
+
+    let r = <curve::Affine as curve::Group>::generator();
+    let pk = r.double();
+    let msg = [
+        BabyBear::from_u32(1),
+        BabyBear::from_u32(2),
+        BabyBear::from_u32(3),
+    ];
+
+    // The documented point encoding, written out for G and 2G. Coefficients at or
+    // above the BabyBear prime appear reduced: x_2 of G is 2115217807 and y_7 is
+    // 2077084094.
+    const R_ELEMENTS: [u32; 16] = [
+        1813646457, 1763905369, 101951886, 1299273209, 1825476283, 438909494, 1368232771,
+        1195559694, 376996212, 840779000, 1365273355, 655051022, 1286889583, 125328769, 434578416,
+        63818173,
+    ];
+    const PK_ELEMENTS: [u32; 16] = [
+        191121194, 33896407, 715261316, 1712628817, 20799199, 342297662, 1264830206, 1431654644,
+        476416146, 1986041272, 1176643965, 1030604535, 1308880522, 1564994060, 1963196707,
+        251517406,
+    ];
+    assert_eq!(encode_point(&r), R_ELEMENTS.map(BabyBear::from_u32));
+    assert_eq!(encode_point(&pk), PK_ELEMENTS.map(BabyBear::from_u32));
+
+    // R || pk || len(msg) || msg
+    let input: Vec<BabyBear> = [&R_ELEMENTS[..], &PK_ELEMENTS[..], &[3, 1, 2, 3]]
+        .concat()
+        .into_iter()
+        .map(BabyBear::from_u32)
+        .collect();
+
+    // Overwrite-mode absorption, then one extra permutation to squeeze a second block.
+    let perm = default_babybear_poseidon2_16();
+    let mut state = [BabyBear::ZERO; 16];
+    for chunk in input.chunks(8) {
+        state[..chunk.len()].copy_from_slice(chunk);
+        perm.permute_mut(&mut state);
+    }
+    let mut digits = state[..8].to_vec();
+    perm.permute_mut(&mut state);
+    digits.extend_from_slice(&state[..8]);
+
+    // The first block is the plain sponge digest.
+    let sponge = PaddingFreeSponge::<_, 16, 8, 8>::new(default_babybear_poseidon2_16());
+    assert_eq!(sponge.hash_iter(input.clone())[..], digits[..8]);
+
+    let radix = ScalarField::from_canonical_u64(BabyBear::ORDER_U32 as u64);
+    let mut expected = ScalarField::ZERO;
+    for d in digits.iter().rev() {
+        expected = expected * radix + ScalarField::from_canonical_u64(d.as_canonical_u32() as u64);
+    }
+
+    let e = hash_challenge(&r, &pk, &msg).expect("hash");
+    assert_eq!(e, expected);
+
+    // Pinned, so a change to the sponge, the constants or the packing fails even
+    // when the rebuild above changes with it. On a deliberate change, paste the
+    // `left` value from the failure here.
+    const EXPECTED_CHALLENGE: &str = "";
+    assert_eq!(e.to_string(), EXPECTED_CHALLENGE);
+}
+
+#[test]
+fn test_challenge_spans_full_range() {
+    let mut rng = StdRng::seed_from_u64(28);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+
+    // A challenge below 2^200 has probability about 2^-48 per message.
+    for i in 0..64u32 {
+        let msg = [BabyBear::from_u32(i)];
+        let sig = sk.sign(&mut rng, &msg).expect("sign");
+        let e = hash_challenge(&sig.r, &pk.as_affine(), &msg).expect("hash");
+        let limbs = e.to_canonical_u64_vec();
+        assert_ne!(limbs[3] >> 8, 0, "challenge below 2^200: {limbs:x?}");
+    }
+}
+
+#[test]
+fn test_challenge_hasher_matches_one_shot() {
+    let sk = SigningKey::random(&mut StdRng::seed_from_u64(29));
+    let pk = sk.verifying_key();
+    let msg: Vec<BabyBear> = (0..10_000).map(BabyBear::from_u32).collect();
+
+    let one_shot = sk.sign(&mut StdRng::seed_from_u64(1), &msg).expect("sign");
+
+    // Uneven chunk boundaries that do not align with the sponge rate.
+    let mut hasher = sk
+        .challenge_hasher(&mut StdRng::seed_from_u64(1), msg.len())
+        .expect("hasher");
+    hasher.update(&msg[..3]);
+    hasher.update(&msg[3..6_001]);
+    hasher.update(&msg[6_001..]);
+    let streamed = hasher.finalize_sign().expect("sign");
+    assert_eq!(streamed, one_shot);
+
+    let mut hasher = pk.challenge_hasher(&one_shot, msg.len()).expect("hasher");
+    for chunk in msg.chunks(777) {
+        hasher.update(chunk);
+    }
+    assert!(hasher.finalize_verify().expect("verify"));
+
+    let mut hasher = pk
+        .challenge_hasher(&one_shot, msg.len() - 1)
+        .expect("hasher");
+    hasher.update(&msg[1..]);
+    assert!(!hasher.finalize_verify().expect("verify"));
+
+    // The absorbed chunks must add up to the declared length
+    let mut hasher = pk.challenge_hasher(&one_shot, msg.len()).expect("hasher");
+    hasher.update(&msg[1..]);
+    assert_eq!(hasher.finalize_verify(), Err(SchnorrError::HashError));
+}
+
+#[test]
+fn test_signature_bytes_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(31);
+    let sk = SigningKey::random(&mut rng);
+    let sig = sk.sign(&mut rng, &[BabyBear::ONE]).expect("sign");
+
+    let bytes = sig.to_bytes();
+    assert_eq!(bytes.len(), SIG_SIZE);
+    assert_eq!(Signature::from_bytes(&bytes), Ok(sig));
+}
+
+#[test]
+fn test_signature_from_bytes_rejects_malformed() {
+    let mut rng = StdRng::seed_from_u64(31);
+    let sk = SigningKey::random(&mut rng);
+    let bytes = sk
+        .sign(&mut rng, &[BabyBear::ONE])
+        .expect("sign")
+        .to_bytes();
+
+    // s = p, the scalar field order
+    const ORDER: [u64; 4] = [
+        0xf2154ff8a2e94d81,
+        0xf85ccc2efc3068fa,
+        0x40f5f26a5ae1748f,
+        0x00f06e44682c2aa4,
+    ];
+    let mut s_is_order = bytes;
+    for (chunk, limb) in s_is_order[PK_SIZE..].chunks_exact_mut(8).zip(ORDER) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    assert_eq!(
+        Signature::from_bytes(&s_is_order),
+        Err(SchnorrError::InvalidScalar)
+    );
+
+    // R off the curve
+    let mut off_curve = bytes;
+    off_curve[PK_SIZE - 32] ^= 1;
+    assert_eq!(
+        Signature::from_bytes(&off_curve),
+        Err(SchnorrError::NotOnCurve)
+    );
+}
+
+#[test]
+fn test_error_variants() {
+    let mut rng = StdRng::seed_from_u64(33);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(5)];
+    let sig = sk.sign(&mut rng, &msg).expect("sign");
+    let g = <curve::Affine as curve::Group>::generator();
+
+    // Point at infinity: in the challenge hash, as R, and as a key
+    assert_eq!(
+        hash_challenge(&curve::Affine::INFINITY, &g, &msg),
+        Err(SchnorrError::PointAtInfinity)
+    );
+    let infinite_r = Signature {
+        r: curve::Affine::INFINITY,
+        s: sig.s,
+    };
+    assert_eq!(
+        pk.verify(&msg, &infinite_r),
+        Err(SchnorrError::PointAtInfinity)
+    );
+    assert_eq!(
+        VerifyingKey::from_affine(curve::Affine::INFINITY),
+        Err(SchnorrError::PointAtInfinity)
+    );
+
+    // Off-curve R
+    let off_curve = Signature {
+        r: curve::Affine::new(sig.r.x, sig.r.y + curve::BaseField::ONE),
+        s: sig.s,
+    };
+    assert_eq!(pk.verify(&msg, &off_curve), Err(SchnorrError::NotOnCurve));
+
+    // Non-canonical s in an encoding
+    let mut bytes = sig.to_bytes();
+    bytes[PK_SIZE..].fill(0xff);
+    assert_eq!(
+        Signature::from_bytes(&bytes),
+        Err(SchnorrError::InvalidScalar)
+    );
+
+    // The deprecated catch-all still names the infinity case
+    #[allow(deprecated)]
+    let legacy = SchnorrError::InvalidPoint;
+    assert_eq!(legacy, SchnorrError::PointAtInfinity);
+    assert_eq!(
+        SchnorrError::NotOnCurve.to_string(),
+        "point is not on the curve"
+    );
+    let _: &dyn std::error::Error = &SchnorrError::HashError;
+}
+
+#[test]
+fn test_xonly_scheme() {
+    let sk = SigningKey::random(&mut StdRng::seed_from_u64(34));
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+
+    // Same nonce stream gives the same signature: the scheme is deterministic
+    let sign_xonly = |seed| {
+        sk.sign_with_scheme(
+            &mut StdRng::seed_from_u64(seed),
+            &msg,
+            SignatureScheme::XOnly,
+        )
+        .expect("sign")
+    };
+    let xonly = sign_xonly(7);
+    assert_eq!(xonly, sign_xonly(7));
+    assert!(
+        pk.verify_with_scheme(&msg, &xonly, SignatureScheme::XOnly)
+            .expect("verify")
+    );
+    assert!(!pk.verify(&msg, &xonly).expect("verify"));
+
+    // Legacy signatures still verify under the legacy scheme only
+    let legacy = sk.sign(&mut StdRng::seed_from_u64(7), &msg).expect("sign");
+    assert_eq!(legacy.r, xonly.r);
+    assert_eq!(
+        legacy,
+        sk.sign_with_scheme(&mut StdRng::seed_from_u64(7), &msg, SignatureScheme::Legacy)
+            .expect("sign")
+    );
+    assert!(
+        pk.verify_with_scheme(&msg, &legacy, SignatureScheme::Legacy)
+            .expect("verify")
+    );
+    assert!(
+        !pk.verify_with_scheme(&msg, &legacy, SignatureScheme::XOnly)
+            .expect("verify")
+    );
+
+    // The x-only challenge ignores y and differs from the legacy one
+    let r = legacy.r;
+    let pk_point = pk.as_affine();
+    let e = SignatureScheme::XOnly
+        .hash_challenge(&r, &pk_point, &msg)
+        .expect("hash");
+    assert_eq!(
+        SignatureScheme::XOnly
+            .hash_challenge(&-r, &-pk_point, &msg)
+            .expect("hash"),
+        e
+    );
+    assert_ne!(hash_challenge(&r, &pk_point, &msg).expect("hash"), e);
+    assert_eq!(
+        SignatureScheme::Legacy
+            .hash_challenge(&r, &pk_point, &msg)
+            .expect("hash"),
+        hash_challenge(&r, &pk_point, &msg).expect("hash")
+    );
+}
+
+#[test]
+fn test_xonly_scheme_vectors() {
+    use curve::{Affine, Group};
+
+    let sk = SigningKey::from_bytes(&{
+        let mut bytes = [0u8; 32];
+        bytes[0] = 2;
+        bytes
+    })
+    .expect("canonical key");
+    let pk = sk.verifying_key();
+    assert_eq!(
+        pk.to_string(),
+        "2a47640bd837057a8405a22a51a41466e05e3d793e0c6714fec6634bf4505555\
+         9288651cb89560767d292246f7c66d3d8aee034e0cea475d23010475ded9fd0e"
+    );
+
+    // tag || R.x || pk.x || len(msg) || msg for R = 3G, pk = 2G, msg = [1, 2]
+    const XONLY_INPUT: [u32; 20] = [
+        0x584f4e4c, 59619026, 1228323174, 120668176, 1257928744, 1977748670, 1978998154,
+        1472701608, 547349579, 191121194, 33896407, 715261316, 1712628817, 20799199, 342297662,
+        1264830206, 1431654644, 2, 1, 2,
+    ];
+    let r = Affine::generator().mul_u64(3);
+    let msg = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+    let mut transcript = Transcript::new();
+    transcript.absorb_elements(&XONLY_INPUT.map(BabyBear::from_u32));
+    assert_eq!(
+        SignatureScheme::XOnly
+            .hash_challenge(&r, &pk.as_affine(), &msg)
+            .expect("hash"),
+        transcript.challenge_scalar()
+    );
+
+    // `R || s` for the nonce drawn from `StdRng::seed_from_u64(7)`. On a
+    // deliberate change, paste the `left` value from the failure here.
+    const EXPECTED_SIG: &str = "";
+    let sig = sk
+        .sign_with_scheme(&mut StdRng::seed_from_u64(7), &msg, SignatureScheme::XOnly)
+        .expect("sign");
+    let hex: String = sig.to_bytes().iter().map(|b| format!("{b:02x}")).collect();
+    assert_eq!(hex, EXPECTED_SIG);
+    assert!(
+        pk.verify_with_scheme(&msg, &sig, SignatureScheme::XOnly)
+            .expect("verify")
+    );
+}
+
+#[test]
+fn test_recover_then_verify() {
+    let mut rng = StdRng::seed_from_u64(35);
+    let msg = [BabyBear::from_u32(3), BabyBear::from_u32(5)];
+
+    for _ in 0..5 {
+        let sk = SigningKey::random(&mut rng);
+        let sig = sk
+            .sign_with_scheme(&mut rng, &msg, SignatureScheme::Recoverable)
+            .expect("sign");
+
+        let pk = sig.recover(&msg, sig.r_parity()).expect("recover");
+        assert_eq!(pk, sk.verifying_key());
+        assert!(
+            pk.verify_with_scheme(&msg, &sig, SignatureScheme::Recoverable)
+                .expect("verify")
+        );
+
+        // The wrong parity selects -R, which does not reproduce the challenge
+        assert_eq!(sig.recover(&msg, !sig.r_parity()), None);
+
+        // A different message recovers a different key, never the signer's
+        let other = [BabyBear::from_u32(4)];
+        assert_ne!(sig.recover(&other, sig.r_parity()), Some(pk));
+    }
+
+    // Keys cannot be recovered from signatures under a key-binding scheme
+    let sk = SigningKey::random(&mut rng);
+    let sig = sk.sign(&mut rng, &msg).expect("sign");
+    assert_ne!(sig.recover(&msg, sig.r_parity()), Some(sk.verifying_key()));
+}
+
+#[test]
+fn test_vrf_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(45);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let input = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+
+    let (output, proof) = sk.vrf_prove(&mut rng, &input);
+    assert!(pk.vrf_verify(&input, &output, &proof).expect("verify"));
+
+    // The output is unique: a fresh nonce changes the proof but not the output
+    let (output2, proof2) = sk.vrf_prove(&mut rng, &input);
+    assert_eq!(output, output2);
+    assert_eq!(proof.gamma, proof2.gamma);
+    assert_ne!(proof.c, proof2.c);
+
+    let (other_output, _) = sk.vrf_prove(&mut rng, &[BabyBear::from_u32(3)]);
+    assert_ne!(output, other_output);
+}
+
+#[test]
+fn test_vrf_rejects_wrong_output() {
+    let mut rng = StdRng::seed_from_u64(46);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let input = [BabyBear::from_u32(7)];
+    let (output, proof) = sk.vrf_prove(&mut rng, &input);
+
+    let mut wrong = output;
+    wrong.0[0] += BabyBear::ONE;
+    assert!(!pk.vrf_verify(&input, &wrong, &proof).expect("verify"));
+
+    // Proof bound to a different input, key, or gamma
+    assert!(
+        !pk.vrf_verify(&[BabyBear::from_u32(8)], &output, &proof)
+            .expect("verify")
+    );
+    let other_pk = SigningKey::random(&mut rng).verifying_key();
+    assert!(
+        !other_pk
+            .vrf_verify(&input, &output, &proof)
+            .expect("verify")
+    );
+    let mut tampered = proof;
+    tampered.gamma = tampered.gamma + <curve::Affine as curve::Group>::generator();
+    assert!(!pk.vrf_verify(&input, &output, &tampered).expect("verify"));
+}
+
+#[test]
+fn test_challenge_commits_to_message_length() {
+    use crate::signatures::{StreamingSponge, encode_point, pack_digest_to_scalar};
+
+    let mut rng = StdRng::seed_from_u64(50);
+    let r = SigningKey::random(&mut rng).verifying_key().as_affine();
+    let pk = SigningKey::random(&mut rng).verifying_key().as_affine();
+    let msg = [BabyBear::from_u32(1), BabyBear::from_u32(2)];
+
+    // The framed challenge differs from the former unframed R || pk || msg hash,
+    // so old signatures no longer verify.
+    let mut unframed = StreamingSponge::new();
+    unframed.absorb(&encode_point(&r));
+    unframed.absorb(&encode_point(&pk));
+    unframed.absorb(&msg);
+    let unframed = pack_digest_to_scalar(&unframed.squeeze_wide());
+    assert_ne!(hash_challenge(&r, &pk, &msg).expect("hash"), unframed);
+
+    // Framings that absorb overlapping element sequences, such as a message
+    // led by what looks like its own length, or a zero-extended message, all
+    // yield distinct challenges.
+    let len = BabyBear::from_u32(msg.len() as u32);
+    let candidates: [&[BabyBear]; 5] = [
+        &msg,
+        &[len, msg[0], msg[1]],
+        &[msg[0], msg[1], BabyBear::ZERO],
+        &msg[..1],
+        &[],
+    ];
+    let mut seen = std::collections::HashSet::new();
+    for candidate in candidates {
+        for scheme in [
+            SignatureScheme::Legacy,
+            SignatureScheme::XOnly,
+            SignatureScheme::Recoverable,
+        ] {
+            let e = scheme.hash_challenge(&r, &pk, candidate).expect("hash");
+            assert!(seen.insert(e.to_bytes()), "challenge collision");
+        }
+    }
+}
+
+#[test]
+fn test_try_from_slices() {
+    let mut rng = StdRng::seed_from_u64(55);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let sig = sk.sign(&mut rng, &[BabyBear::ONE]).expect("sign");
+
+    let sk_bytes = sk.to_vec();
+    let pk_bytes = pk.to_vec();
+    let sig_bytes = sig.to_vec();
+    assert_eq!(sk_bytes.len(), SK_SIZE);
+    assert_eq!(pk_bytes.len(), PK_SIZE);
+    assert_eq!(sig_bytes.len(), SIG_SIZE);
+
+    assert_eq!(SigningKey::try_from(&sk_bytes[..]), Ok(sk.clone()));
+    assert_eq!(VerifyingKey::try_from(&pk_bytes[..]), Ok(pk));
+    assert_eq!(Signature::try_from(&sig_bytes[..]), Ok(sig));
+
+    // Short and long slices are rejected before parsing
+    for bytes in [&sk_bytes, &pk_bytes, &sig_bytes] {
+        let mut long = bytes.clone();
+        long.push(0);
+        let short = &bytes[..bytes.len() - 1];
+        for slice in [short, &long[..], &[]] {
+            assert_eq!(
+                SigningKey::try_from(slice).err(),
+                Some(SchnorrError::InvalidLength)
+            );
+            assert_eq!(
+                VerifyingKey::try_from(slice).map(|_| ()),
+                Err(SchnorrError::InvalidLength)
+            );
+            assert_eq!(
+                Signature::try_from(slice).map(|_| ()),
+                Err(SchnorrError::InvalidLength)
+            );
+        }
+    }
+
+    // Right length, invalid content
+    assert_eq!(
+        SigningKey::try_from(&[0xff; SK_SIZE][..]),
+        Err(SchnorrError::InvalidScalar)
+    );
+    assert_eq!(
+        VerifyingKey::try_from(&[0; PK_SIZE][..]),
+        Err(SchnorrError::NotOnCurve)
+    );
+}
+
+#[test]
+fn test_verifying_key_hex_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(56);
+    for _ in 0..4 {
+        let pk = SigningKey::random(&mut rng).verifying_key();
+        let hex = pk.to_string();
+        assert_eq!(hex.len(), 2 * PK_SIZE);
+        assert!(
+            hex.bytes()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+        );
+        assert_eq!(VerifyingKey::from_hex(&hex), Ok(pk));
+        assert_eq!(VerifyingKey::from_hex(&hex.to_uppercase()), Ok(pk));
+    }
+
+    let hex = SigningKey::random(&mut rng).verifying_key().to_string();
+    // Odd and otherwise wrong lengths
+    assert_eq!(
+        VerifyingKey::from_hex(&hex[1..]),
+        Err(SchnorrError::InvalidLength)
+    );
+    assert_eq!(
+        VerifyingKey::from_hex(&format!("{hex}00")),
+        Err(SchnorrError::InvalidLength)
+    );
+    assert_eq!(VerifyingKey::from_hex(""), Err(SchnorrError::InvalidLength));
+    assert_eq!(
+        VerifyingKey::from_hex(&format!("0x{}", &hex[2..])),
+        Err(SchnorrError::InvalidHex)
+    );
+    // Non-hex characters at the right length
+    let mut bad = hex.clone();
+    bad.replace_range(10..11, "g");
+    assert_eq!(VerifyingKey::from_hex(&bad), Err(SchnorrError::InvalidHex));
+    // Valid hex that is not a point
+    assert_eq!(
+        VerifyingKey::from_hex(&"0".repeat(2 * PK_SIZE)),
+        Err(SchnorrError::NotOnCurve)
+    );
+}
+
+fn musig_sign(
+    rng: &mut StdRng,
+    signers: &[SigningKey],
+    keys: &[VerifyingKey],
+    msg: &[BabyBear],
+) -> Vec<musig::PartialSignature> {
+    let (nonces, commitments): (Vec<_>, Vec<_>) =
+        signers.iter().map(|_| musig::commit_nonce(rng)).unzip();
+    let public_nonces: Vec<_> = nonces.iter().map(|n| n.public_nonce()).collect();
+    let r = musig::aggregate_nonces(&commitments, &public_nonces).expect("nonces");
+
+    signers
+        .iter()
+        .zip(nonces)
+        .map(|(signer, nonce)| {
+            signer
+                .musig_partial_sign(nonce, keys, &r, msg)
+                .expect("partial sign")
+        })
+        .collect()
+}
+
+#[test]
+fn test_musig_three_party_roundtrip() {
+    let mut rng = StdRng::seed_from_u64(58);
+    let signers: Vec<_> = (0..3).map(|_| SigningKey::random(&mut rng)).collect();
+    let keys: Vec<_> = signers.iter().map(SigningKey::verifying_key).collect();
+    let msg = [BabyBear::from_u32(7), BabyBear::from_u32(8)];
+
+    let partials = musig_sign(&mut rng, &signers, &keys, &msg);
+    let sig = musig::aggregate_signatures(&keys, &msg, &partials).expect("aggregate");
+    let aggregated = musig::aggregate_keys(&keys).expect("aggregate keys");
+    assert!(aggregated.verify(&msg, &sig).expect("verify"));
+    assert!(
+        !aggregated
+            .verify(&[BabyBear::from_u32(9)], &sig)
+            .expect("verify")
+    );
+
+    // The coefficients make the aggregated key depend on the whole key list
+    let plain_sum = keys[0].as_affine() + keys[1].as_affine() + keys[2].as_affine();
+    assert_ne!(aggregated.as_affine(), plain_sum);
+    let reordered = [keys[1], keys[0], keys[2]];
+    assert_ne!(musig::aggregate_keys(&reordered).unwrap(), aggregated);
+    assert_eq!(
+        musig::aggregate_keys(&[]),
+        Err(SchnorrError::PointAtInfinity)
+    );
+}
+
+#[test]
+fn test_musig_rejects_missing_or_bad_partial() {
+    let mut rng = StdRng::seed_from_u64(59);
+    let signers: Vec<_> = (0..3).map(|_| SigningKey::random(&mut rng)).collect();
+    let keys: Vec<_> = signers.iter().map(SigningKey::verifying_key).collect();
+    let msg = [BabyBear::from_u32(1)];
+    let partials = musig_sign(&mut rng, &signers, &keys, &msg);
+
+    assert_eq!(
+        musig::aggregate_signatures(&keys, &msg, &partials[..2]),
+        Err(SchnorrError::InvalidPartialSignature)
+    );
+
+    let mut tampered = partials.clone();
+    tampered[1].s += curve::ScalarField::ONE;
+    assert_eq!(
+        musig::aggregate_signatures(&keys, &msg, &tampered),
+        Err(SchnorrError::InvalidPartialSignature)
+    );
+
+    let mut swapped = partials.clone();
+    swapped.swap(0, 1);
+    assert_eq!(
+        musig::aggregate_signatures(&keys, &msg, &swapped),
+        Err(SchnorrError::InvalidPartialSignature)
+    );
+
+    let (nonce, _) = musig::commit_nonce(&mut rng);
+    let outsider = SigningKey::random(&mut rng);
+    assert_eq!(
+        outsider.musig_partial_sign(nonce, &keys, &partials[0].r, &msg),
+        Err(SchnorrError::InvalidPartialSignature)
+    );
+}
+
+#[test]
+fn test_musig_nonce_reveal_must_match_commitment() {
+    let mut rng = StdRng::seed_from_u64(60);
+    let (first, first_commitment) = musig::commit_nonce(&mut rng);
+    let (second, second_commitment) = musig::commit_nonce(&mut rng);
+    let commitments = [first_commitment, second_commitment];
+    assert_eq!(first.commitment(), first_commitment);
+
+    let revealed = [first.public_nonce(), second.public_nonce()];
+    assert_eq!(
+        musig::aggregate_nonces(&commitments, &revealed),
+        Ok(revealed[0] + revealed[1])
+    );
+    assert_eq!(
+        musig::aggregate_nonces(&commitments, &[revealed[1], revealed[0]]),
+        Err(SchnorrError::NonceCommitmentMismatch)
+    );
+    assert_eq!(
+        musig::aggregate_nonces(&commitments, &revealed[..1]),
+        Err(SchnorrError::NonceCommitmentMismatch)
+    );
+}
+
+#[test]
+fn test_prehashed_matches_hashed_sign_and_verify() {
+    let mut rng = StdRng::seed_from_u64(73);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(5), BabyBear::from_u32(6)];
+
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+    let e = hash_challenge(&sig.r, &pk.as_affine(), &msg).unwrap();
+    assert!(pk.verify_prehashed(e, &sig).unwrap());
+    assert!(
+        !pk.verify_prehashed(e + curve::ScalarField::ONE, &sig)
+            .unwrap()
+    );
+
+    let mut tampered = sig;
+    tampered.s += curve::ScalarField::ONE;
+    assert_eq!(
+        pk.verify_prehashed(e, &tampered).unwrap(),
+        pk.verify(&msg, &tampered).unwrap()
+    );
+
+    let mut nonce = curve::ScalarField::from_canonical_u64(0x5eed);
+    let mut r = <curve::Affine as curve::Group>::mul_generator(&nonce);
+    if r.has_odd_y() {
+        (nonce, r) = (-nonce, -r);
+    }
+    let e = hash_challenge(&r, &pk.as_affine(), &msg).unwrap();
+    let prehashed = sk.sign_prehashed(nonce, e).unwrap();
+    assert_eq!(prehashed.r, r);
+    assert!(pk.verify(&msg, &prehashed).unwrap());
+    assert_eq!(
+        sk.sign_prehashed(curve::ScalarField::ZERO, e),
+        Err(SchnorrError::PointAtInfinity)
+    );
+    assert_eq!(
+        sk.sign_prehashed(-nonce, e),
+        Err(SchnorrError::OddCommitment)
+    );
+}
+
+/// Mock challenge: the message elements and `R.x[0]` summed into a scalar.
+struct SumHash;
+
+impl ChallengeHash for SumHash {
+    fn hash(
+        &self,
+        r: &curve::Affine,
+        _pk: &curve::Affine,
+        msg: &[BabyBear],
+    ) -> Result<curve::ScalarField, SchnorrError> {
+        use p3_field::PrimeField32;
+        let r_x = curve::to_coeffs(r.x)[0].as_canonical_u32() as u64;
+        let sum = msg.iter().map(|m| m.as_canonical_u32() as u64).sum::<u64>();
+        Ok(curve::ScalarField::from_canonical_u64(r_x + sum + 1))
+    }
+}
+
+#[test]
+fn test_sign_verify_with_custom_challenge_hash() {
+    let mut rng = StdRng::seed_from_u64(74);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(3), BabyBear::from_u32(4)];
+
+    let sig = sk.sign_with_hash(&SumHash, &mut rng, &msg).unwrap();
+    assert!(pk.verify_with_hash(&SumHash, &msg, &sig).unwrap());
+    assert!(
+        !pk.verify_with_hash(&SumHash, &[BabyBear::ONE], &sig)
+            .unwrap()
+    );
+    // The default Poseidon2 challenge differs, so the signature does not carry over
+    assert!(!pk.verify(&msg, &sig).unwrap());
+
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+    assert!(
+        pk.verify_with_hash(&Poseidon2Challenge, &msg, &sig)
+            .unwrap()
+    );
+    assert!(!pk.verify_with_hash(&SumHash, &msg, &sig).unwrap());
+}
+
+#[test]
+fn test_challenge_orders_are_self_consistent_and_distinct() {
+    let mut rng = StdRng::seed_from_u64(75);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [
+        BabyBear::from_u32(9),
+        BabyBear::from_u32(8),
+        BabyBear::from_u32(7),
+    ];
+    let orders = [ChallengeOrder::NonceFirst, ChallengeOrder::MessageFirst];
+
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+    assert_eq!(
+        hash_challenge_ordered(ChallengeOrder::default(), &sig.r, &pk.as_affine(), &msg),
+        hash_challenge(&sig.r, &pk.as_affine(), &msg)
+    );
+
+    for sign_order in orders {
+        let sig = sk.sign_with_hash(&sign_order, &mut rng, &msg).unwrap();
+        for verify_order in orders {
+            assert_eq!(
+                pk.verify_with_hash(&verify_order, &msg, &sig).unwrap(),
+                sign_order == verify_order
+            );
+        }
+    }
+}
+
+#[test]
+fn test_transcript_matches_hash_challenge() {
+    let mut rng = StdRng::seed_from_u64(79);
+    let r = SigningKey::random(&mut rng).verifying_key().as_affine();
+    let pk = SigningKey::random(&mut rng).verifying_key().as_affine();
+    let msg: Vec<BabyBear> = (0..20).map(BabyBear::from_u32).collect();
+
+    let mut transcript = Transcript::new();
+    transcript.absorb_point(&r);
+    transcript.absorb_point(&pk);
+    transcript.absorb_message(&msg).unwrap();
+    let e = transcript.challenge_scalar();
+    assert_eq!(e, hash_challenge(&r, &pk, &msg).unwrap());
+
+    // Squeezing does not consume the transcript.
+    assert_eq!(transcript.challenge_scalar(), e);
+
+    // Absorbing a scalar changes the challenge.
+    transcript.absorb_scalar(&curve::ScalarField::ONE);
+    assert_ne!(transcript.challenge_scalar(), e);
+}
+
+#[test]
+fn test_signatures_have_even_commitment() {
+    let mut rng = StdRng::seed_from_u64(80);
+    let sk = SigningKey::random(&mut rng);
+    let msg = [BabyBear::from_u32(3), BabyBear::from_u32(1)];
+
+    for i in 0..32u32 {
+        let msg = [msg[0], BabyBear::from_u32(i)];
+        let sig = sk.sign(&mut rng, &msg).unwrap();
+        assert!(!sig.r.has_odd_y());
+        assert!(!sk.sign_deterministic(&msg).unwrap().r.has_odd_y());
+        assert!(
+            !sk.sign_with_context(&mut rng, b"ctx", &msg)
+                .unwrap()
+                .r
+                .has_odd_y()
+        );
+
+        let mut hasher = sk.challenge_hasher(&mut rng, msg.len()).unwrap();
+        hasher.update(&msg);
+        assert!(!hasher.finalize_sign().unwrap().r.has_odd_y());
+    }
+}
+
+#[test]
+fn test_rejects_odd_commitment() {
+    let mut rng = StdRng::seed_from_u64(81);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(4)];
+
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+    let e = hash_challenge(&sig.r, &pk.as_affine(), &msg).unwrap();
+    assert!(pk.verify_prehashed(e, &sig).unwrap());
+
+    // (-R, -s) satisfies the verification equation for -e, so only the parity
+    // check rejects it.
+    let flipped = Signature {
+        r: -sig.r,
+        s: -sig.s,
+    };
+    assert!(flipped.r.has_odd_y());
+    assert_eq!(
+        <curve::Affine as curve::Group>::mul_generator(&flipped.s),
+        flipped.r + <curve::Affine as curve::Group>::scalar_mul(&pk.as_affine(), &-e)
+    );
+    assert!(!pk.verify_prehashed(-e, &flipped).unwrap());
+    assert!(!pk.verify(&msg, &flipped).unwrap());
+    assert!(!VerifyingKey::verify_batch(&[(pk, &msg[..], &flipped)]).unwrap());
+
+    let mut hasher = pk.challenge_hasher(&flipped, msg.len()).unwrap();
+    hasher.update(&msg);
+    assert!(!hasher.finalize_verify().unwrap());
+}
+
+/// Mock challenge that counts how often it is asked for a challenge.
+#[derive(Default)]
+struct CountingHash(core::cell::Cell<usize>);
+
+impl ChallengeHash for CountingHash {
+    fn hash(
+        &self,
+        r: &curve::Affine,
+        pk: &curve::Affine,
+        msg: &[BabyBear],
+    ) -> Result<curve::ScalarField, SchnorrError> {
+        self.0.set(self.0.get() + 1);
+        Poseidon2Challenge.hash(r, pk, msg)
+    }
+}
+
+#[test]
+fn test_verify_rejects_malformed_r_before_hashing() {
+    let mut rng = StdRng::seed_from_u64(82);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg = [BabyBear::from_u32(2)];
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+
+    let hasher = CountingHash::default();
+    assert!(pk.verify_with_hash(&hasher, &msg, &sig).unwrap());
+    assert_eq!(hasher.0.get(), 1);
+
+    let hasher = CountingHash::default();
+    let off_curve = Signature {
+        r: curve::Affine::new(sig.r.x, sig.r.y + curve::BaseField::ONE),
+        s: sig.s,
+    };
+    assert_eq!(
+        pk.verify_with_hash(&hasher, &msg, &off_curve),
+        Err(SchnorrError::NotOnCurve)
+    );
+    let infinite_r = Signature {
+        r: curve::Affine::INFINITY,
+        s: sig.s,
+    };
+    assert_eq!(
+        pk.verify_with_hash(&hasher, &msg, &infinite_r),
+        Err(SchnorrError::PointAtInfinity)
+    );
+    let odd_r = Signature {
+        r: -sig.r,
+        s: sig.s,
+    };
+    assert!(!pk.verify_with_hash(&hasher, &msg, &odd_r).unwrap());
+    assert_eq!(hasher.0.get(), 0);
+}
+
+#[test]
+fn test_poseidon2_hash_matches_internal_sponge() {
+    use crate::signatures::StreamingSponge;
+    use p3_baby_bear::default_babybear_poseidon2_16;
+    use p3_field::PrimeField32;
+    use p3_symmetric::Permutation;
+
+    let input: Vec<BabyBear> = (1..=11).map(BabyBear::from_u32).collect();
+    let digest = poseidon2_hash(&input);
+
+    // Pinned, so a change to the permutation or its constants fails even though
+    // the rebuilds below change with it. On a deliberate change, paste the
+    // `left` value from the failure here.
+    const EXPECTED_DIGEST: &str = "";
+    let canonical = digest.map(|x| x.as_canonical_u32());
+    assert_eq!(format!("{canonical:?}"), EXPECTED_DIGEST);
+
+    // Overwrite-mode absorption of the rate-8 blocks, short last block unpadded.
+    let perm = default_babybear_poseidon2_16();
+    let mut state = [BabyBear::ZERO; 16];
+    for chunk in input.chunks(8) {
+        state[..chunk.len()].copy_from_slice(chunk);
+        perm.permute_mut(&mut state);
+    }
+    assert_eq!(digest[..], state[..8]);
+
+    let mut streaming = StreamingSponge::new();
+    streaming.absorb(&input);
+    assert_eq!(digest[..], streaming.squeeze_wide()[..8]);
+
+    let empty = poseidon2_hash(&[]);
+    assert_eq!(empty, [BabyBear::ZERO; 8]);
+    assert_ne!(poseidon2_hash(&input[..10]), digest);
+}
+
+#[test]
+fn test_sign_many_separates_messages() {
+    let mut rng = StdRng::seed_from_u64(84);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let a = BabyBear::from_u32(10);
+    let b = BabyBear::from_u32(20);
+
+    let sig = sk.sign_many(&mut rng, &[&[a], &[b]]).unwrap();
+    assert!(pk.verify_many(&[&[a], &[b]], &sig).unwrap());
+    assert!(!pk.verify_many(&[&[a, b], &[]], &sig).unwrap());
+    assert!(!pk.verify_many(&[&[b], &[a]], &sig).unwrap());
+    assert!(!pk.verify_many(&[&[a], &[b], &[]], &sig).unwrap());
+    assert!(!pk.verify(&[a, b], &sig).unwrap());
+
+    let merged = sk.sign_many(&mut rng, &[&[a, b], &[]]).unwrap();
+    assert!(pk.verify_many(&[&[a, b], &[]], &merged).unwrap());
+    assert!(!pk.verify_many(&[&[a], &[b]], &merged).unwrap());
+
+    let r = sig.r;
+    let challenge = |messages: &[&[BabyBear]]| {
+        crate::signatures::MultiMessageChallenge(messages)
+            .hash(&r, &pk.as_affine(), &[])
+            .unwrap()
+    };
+    assert_ne!(challenge(&[&[a], &[b]]), challenge(&[&[a, b], &[]]));
+    assert_ne!(challenge(&[&[a], &[b]]), challenge(&[&[b], &[a]]));
+    assert_ne!(challenge(&[&[a, b]]), challenge(&[&[a, b], &[]]));
+
+    assert!(sk.sign_many(&mut rng, &[]).is_ok());
+}
+
+#[test]
+fn test_signing_key_from_bytes() {
+    let mut rng = StdRng::seed_from_u64(85);
+    let sk = SigningKey::random(&mut rng);
+    let msg = [BabyBear::from_u32(1)];
+
+    let imported = SigningKey::from_bytes(&sk.to_bytes()).unwrap();
+    assert_eq!(imported, sk);
+    assert_eq!(SigningKey::from_bytes_mod_order(&sk.to_bytes()), sk);
+    let sig = imported.sign(&mut rng, &msg).unwrap();
+    assert!(sk.verifying_key().verify(&msg, &sig).unwrap());
+
+    // 2^256 - 1 is above the order: rejected strictly, reduced otherwise.
+    let oversized = [0xff; SK_SIZE];
+    assert_eq!(
+        SigningKey::from_bytes(&oversized),
+        Err(SchnorrError::InvalidScalar)
+    );
+    let reduced = SigningKey::from_bytes_mod_order(&oversized);
+    assert_eq!(
+        reduced.to_bytes(),
+        curve::ScalarField::from_bytes_mod_order(&oversized).to_bytes()
+    );
+    assert_eq!(
+        SigningKey::from_bytes(&reduced.to_bytes()).as_ref(),
+        Ok(&reduced)
+    );
+    let sig = reduced.sign(&mut rng, &msg).unwrap();
+    assert!(reduced.verifying_key().verify(&msg, &sig).unwrap());
+}
+
+#[test]
+fn test_compressed_verifying_key_serde() {
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct Compact {
+        #[serde(with = "crate::compressed_key")]
+        key: VerifyingKey,
+    }
+
+    let mut rng = StdRng::seed_from_u64(87);
+    let sk = SigningKey::random(&mut rng);
+    let msg = [BabyBear::from_u32(7)];
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+
+    let compact = bincode::serialize(&Compact {
+        key: sk.verifying_key(),
+    })
+    .unwrap();
+    let full = bincode::serialize(&sk.verifying_key()).unwrap();
+    assert_eq!(compact.len(), curve::compressed::COMPRESSED_SIZE);
+    assert!(compact.len() < full.len());
+
+    let decoded: Compact = bincode::deserialize(&compact).unwrap();
+    assert_eq!(decoded.key, sk.verifying_key());
+    assert!(decoded.key.verify(&msg, &sig).unwrap());
+
+    // The identity is not a valid key, and a truncated encoding does not parse.
+    let infinity = bincode::serialize(&curve::CompressedAffine(curve::Affine::INFINITY)).unwrap();
+    assert!(bincode::deserialize::<Compact>(&infinity).is_err());
+    assert!(bincode::deserialize::<Compact>(&compact[..32]).is_err());
+
+    let point = curve::CompressedAffine(sig.r);
+    let bytes = bincode::serialize(&point).unwrap();
+    assert_eq!(bytes[..], sig.r.to_compressed()[..]);
+    assert_eq!(
+        bincode::deserialize::<curve::CompressedAffine>(&bytes).unwrap(),
+        point
+    );
+}
+
+#[test]
+fn test_zero_challenge_is_rederived_with_counter() {
+    use crate::transcript::nonzero_challenge;
+    use curve::ScalarField;
+
+    // A mock digest that packs to zero until the counter reaches 2.
+    let mut attempts = Vec::new();
+    let e = nonzero_challenge(|counter| {
+        attempts.push(counter);
+        if counter < 2 {
+            ScalarField::ZERO
+        } else {
+            ScalarField::from_canonical_u64(counter as u64)
+        }
+    });
+    assert_eq!(e, ScalarField::from_canonical_u64(2));
+    assert_eq!(attempts, [0, 1, 2]);
+
+    // A nonzero first attempt is used as is, so real challenges are unchanged.
+    let e = nonzero_challenge(|counter| ScalarField::from_canonical_u64(counter as u64 + 7));
+    assert_eq!(e, ScalarField::from_canonical_u64(7));
+
+    let g = <curve::Affine as curve::Group>::generator();
+    assert!(!hash_challenge(&g, &g, &[]).unwrap().is_zero());
+}
+
+#[test]
+fn test_verify_test_vector_rejects_malformed_hex() {
+    let mut rng = StdRng::seed_from_u64(90);
+    let sk = SigningKey::random(&mut rng);
+    let msg = [BabyBear::from_u32(1)];
+    let sig = sk.sign(&mut rng, &msg).unwrap();
+    let pk_hex = sk.verifying_key().to_string();
+    let sig_hex: String = sig.to_bytes().iter().map(|b| format!("{b:02X}")).collect();
+
+    assert_eq!(verify_test_vector(&pk_hex, &msg, &sig_hex), Ok(true));
+    assert_eq!(
+        verify_test_vector(&pk_hex, &msg, &sig_hex[2..]),
+        Err(SchnorrError::InvalidLength)
+    );
+    let bad_digit = format!("zz{}", &sig_hex[2..]);
+    assert_eq!(
+        verify_test_vector(&pk_hex, &msg, &bad_digit),
+        Err(SchnorrError::InvalidHex)
+    );
+}
+
+#[test]
+fn test_empty_message_round_trip() {
+    let mut rng = StdRng::seed_from_u64(91);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+
+    for sig in [
+        sk.sign(&mut rng, &[]).unwrap(),
+        sk.sign_deterministic(&[]).unwrap(),
+    ] {
+        assert!(pk.verify(&[], &sig).unwrap());
+        // The length element separates the empty message from a single zero.
+        assert!(!pk.verify(&[BabyBear::ZERO], &sig).unwrap());
+        assert!(!pk.verify(&[BabyBear::ONE], &sig).unwrap());
+    }
+
+    let sig = sk.sign(&mut rng, &[BabyBear::ZERO]).unwrap();
+    assert!(!pk.verify(&[], &sig).unwrap());
+
+    let g = <curve::Affine as curve::Group>::generator();
+    assert_ne!(
+        hash_challenge(&g, &pk.as_affine(), &[]),
+        hash_challenge(&g, &pk.as_affine(), &[BabyBear::ZERO])
+    );
+}
+
+#[test]
+fn test_nonce_reuse_detection_and_recovery() {
+    let mut rng = StdRng::seed_from_u64(96);
+    let sk = SigningKey::random(&mut rng);
+    let pk = sk.verifying_key();
+    let msg1 = [BabyBear::from_u32(1)];
+    let msg2 = [BabyBear::from_u32(2), BabyBear::from_u32(3)];
+
+    // Force the same nonce into two signatures on different messages
+    let (nonce, r) = crate::signatures::even_commitment(curve::ScalarField::from_canonical_u64(96));
+    let sign_with_nonce = |msg: &[BabyBear]| {
+        let e = hash_challenge(&r, &pk.as_affine(), msg).unwrap();
+        sk.sign_prehashed(nonce, e).unwrap()
+    };
+    let sig1 = sign_with_nonce(&msg1);
+    let sig2 = sign_with_nonce(&msg2);
+    assert!(pk.verify(&msg1, &sig1).unwrap());
+    assert!(pk.verify(&msg2, &sig2).unwrap());
+
+    let fresh = sk.sign(&mut rng, &msg1).unwrap();
+    assert_eq!(detect_nonce_reuse(&[sig1, fresh]), None);
+    assert_eq!(detect_nonce_reuse(&[sig1, fresh, sig2]), Some((0, 2)));
+    assert_eq!(detect_nonce_reuse(&[]), None);
+
+    let recovered = recover_key_from_reuse(&sig1, &msg1, &sig2, &msg2, &pk).unwrap();
+    assert_eq!(recovered, Some(sk));
+
+    // Nothing to recover without a shared nonce or from one message signed twice
+    assert_eq!(
+        recover_key_from_reuse(&sig1, &msg1, &fresh, &msg1, &pk).unwrap(),
+        None
+    );
+    assert_eq!(
+        recover_key_from_reuse(&sig1, &msg1, &sig1, &msg1, &pk).unwrap(),
+        None
+    );
+    let other = SigningKey::random(&mut rng).verifying_key();
+    assert_eq!(
+        recover_key_from_reuse(&sig1, &msg1, &sig2, &msg2, &other).unwrap(),
+        None
+    );
+}
@@ -0,0 +1,128 @@
+//! Incremental Fiat-Shamir transcript over the Poseidon2 sponge.
+//!
+//! [`hash_challenge`](crate::hash_challenge) is a transcript that absorbs `R`,
+//! then `pk`, then the message, and the circuit's Poseidon2 hash AIR proves the
+//! same sponge over the same elements, so this type is the single definition of
+//! how points, scalars and messages become sponge input and how the squeezed
+//! digest becomes a scalar.
+
+use curve::{Affine, ScalarField};
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeCharacteristicRing;
+
+use crate::errors::SchnorrError;
+use crate::signatures::{StreamingSponge, encode_point, length_element, pack_digest_to_scalar};
+
+/// Bits per limb when absorbing a scalar, matching the circuit's scalar limbs.
+const SCALAR_LIMB_BITS: usize = 28;
+
+/// Number of limbs a canonical scalar is absorbed as.
+const SCALAR_LIMBS: usize = 9;
+
+/// A Poseidon2 transcript that absorbs points, scalars and messages in the
+/// order they are given and squeezes a challenge scalar.
+///
+/// # Example
+///
+/// ```
+/// use curve::{Affine, Group};
+/// use p3_baby_bear::BabyBear;
+/// use p3_field::PrimeCharacteristicRing;
+/// use schnorr::{Transcript, hash_challenge};
+///
+/// let r = Affine::generator().double();
+/// let pk = Affine::generator();
+/// let msg = [BabyBear::from_u32(1)];
+///
+/// let mut transcript = Transcript::new();
+/// transcript.absorb_point(&r);
+/// transcript.absorb_point(&pk);
+/// transcript.absorb_message(&msg).unwrap();
+/// assert_eq!(transcript.challenge_scalar(), hash_challenge(&r, &pk, &msg).unwrap());
+/// ```
+#[derive(Clone)]
+pub struct Transcript {
+    sponge: StreamingSponge,
+}
+
+impl Transcript {
+    /// Starts an empty transcript.
+    pub fn new() -> Self {
+        Self {
+            sponge: StreamingSponge::new(),
+        }
+    }
+
+    /// Absorbs the 8 `x` then 8 `y` coefficients of `point`, each reduced into
+    /// BabyBear. The point at infinity absorbs as all zeros.
+    pub fn absorb_point(&mut self, point: &Affine) {
+        self.sponge.absorb(&encode_point(point));
+    }
+
+    /// Absorbs the canonical value of `scalar` as nine little-endian 28-bit limbs.
+    pub fn absorb_scalar(&mut self, scalar: &ScalarField) {
+        let limbs = scalar.to_canonical_u64_vec();
+        let digits: [BabyBear; SCALAR_LIMBS] = core::array::from_fn(|j| {
+            let bit = j * SCALAR_LIMB_BITS;
+            let (word, shift) = (bit / 64, bit % 64);
+            let mut value = limbs[word] >> shift;
+            if shift + SCALAR_LIMB_BITS > 64 && word + 1 < limbs.len() {
+                value |= limbs[word + 1] << (64 - shift);
+            }
+            BabyBear::from_u32((value & ((1 << SCALAR_LIMB_BITS) - 1)) as u32)
+        });
+        self.sponge.absorb(&digits);
+    }
+
+    /// Absorbs `msg.len()` as one element, then `msg`.
+    ///
+    /// Returns `Err(SchnorrError::HashError)` if `msg` has `2^31 - 2^27 + 1`
+    /// elements or more.
+    pub fn absorb_message(&mut self, msg: &[BabyBear]) -> Result<(), SchnorrError> {
+        self.sponge.absorb(&[length_element(msg.len())?]);
+        self.sponge.absorb(msg);
+        Ok(())
+    }
+
+    /// Absorbs raw elements, for prefixes and domain tags.
+    pub(crate) fn absorb_elements(&mut self, elements: &[BabyBear]) {
+        self.sponge.absorb(elements);
+    }
+
+    /// Squeezes the wide digest and packs it into a scalar with
+    /// [`pack_digest_to_scalar`](crate::pack_digest_to_scalar).
+    ///
+    /// Never returns zero: if the packed digest is zero, the counter `1`, `2`, ...
+    /// is absorbed into a copy of the transcript and the digest re-squeezed until
+    /// it is not.
+    pub fn challenge_scalar(&self) -> ScalarField {
+        nonzero_challenge(|counter| {
+            if counter == 0 {
+                return pack_digest_to_scalar(&self.sponge.squeeze_wide());
+            }
+            let mut sponge = self.sponge.clone();
+            sponge.absorb(&[BabyBear::from_u32(counter)]);
+            pack_digest_to_scalar(&sponge.squeeze_wide())
+        })
+    }
+}
+
+/// Returns the first nonzero `derive(counter)` for `counter = 0, 1, 2, ...`.
+pub(crate) fn nonzero_challenge(mut derive: impl FnMut(u32) -> ScalarField) -> ScalarField {
+    (0..=u32::MAX)
+        .map(&mut derive)
+        .find(|e| !e.is_zero())
+        .expect("a nonzero challenge within 2^32 attempts")
+}
+
+impl Default for Transcript {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Transcript").finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,111 @@
+//! Verifiable random function built on the signature curve.
+//!
+//! For input `alpha`, let `H = hash_to_curve(alpha)`. The prover publishes
+//! `Gamma = sk * H` and the output `Poseidon2(tag || Gamma)`, together with a
+//! Chaum-Pedersen proof that `Gamma` and `pk` have the same discrete log with
+//! respect to `H` and `G`:
+//!
+//! - commit `U = k * G` and `V = k * H` for a random nonce `k`,
+//! - challenge `c = H(tag || H || pk || Gamma || U || V)`,
+//! - response `s = k + c * sk`.
+//!
+//! The verifier recomputes `U = s * G - c * pk` and `V = s * H - c * Gamma` and
+//! checks the challenge. Since `Gamma` is fixed by `sk` and the input, the output
+//! is unique for each key and input.
+
+use curve::{Affine, Group, ScalarField, hash_to_curve};
+use p3_baby_bear::BabyBear;
+use p3_field::{PrimeCharacteristicRing, PrimeField32};
+use p3_symmetric::CryptographicHasher;
+use serde::{Deserialize, Serialize};
+
+use crate::constants::{POSEIDON2_OUT, VRF_CHALLENGE_TAG, VRF_HASH_DOMAIN, VRF_OUTPUT_TAG};
+use crate::errors::SchnorrError;
+use crate::signatures::{
+    StreamingSponge, check_point, encode_point, pack_digest_to_scalar, sponge,
+};
+
+/// Pseudorandom output of the VRF, `Poseidon2(tag || Gamma)`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct VrfOutput(pub [BabyBear; POSEIDON2_OUT]);
+
+/// Proof that a [`VrfOutput`] was computed with the secret key for a given
+/// verifying key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VrfProof {
+    /// `Gamma = sk * H`, where `H` is the input hashed to the curve
+    pub gamma: Affine,
+    /// The DLEQ challenge `c`
+    pub c: ScalarField,
+    /// The DLEQ response `s = k + c * sk`
+    pub s: ScalarField,
+}
+
+/// Hashes a VRF input to the curve point `H`.
+///
+/// Each element is absorbed as its 4 canonical little-endian bytes.
+fn input_point(input: &[BabyBear]) -> Affine {
+    let bytes: Vec<u8> = input
+        .iter()
+        .flat_map(|elem| elem.as_canonical_u32().to_le_bytes())
+        .collect();
+    hash_to_curve(VRF_HASH_DOMAIN, &bytes)
+}
+
+fn output_from_gamma(gamma: &Affine) -> VrfOutput {
+    let input = [BabyBear::from_u32(VRF_OUTPUT_TAG)]
+        .into_iter()
+        .chain(encode_point(gamma));
+    VrfOutput(sponge().hash_iter(input))
+}
+
+fn dleq_challenge(points: [&Affine; 5]) -> ScalarField {
+    let mut sponge = StreamingSponge::new();
+    sponge.absorb(&[BabyBear::from_u32(VRF_CHALLENGE_TAG)]);
+    for point in points {
+        sponge.absorb(&encode_point(point));
+    }
+    pack_digest_to_scalar(&sponge.squeeze_wide())
+}
+
+/// Evaluates the VRF on `input` with secret `sk` and nonce `k`.
+pub(crate) fn prove(
+    sk: &ScalarField,
+    pk: &Affine,
+    k: &ScalarField,
+    input: &[BabyBear],
+) -> (VrfOutput, VrfProof) {
+    let h = input_point(input);
+    // `sk` and `k` are secret, so the variable-base products use the
+    // constant-time ladder.
+    let gamma = h.scalar_mul_ct(sk);
+    let u = <Affine as Group>::mul_generator(k);
+    let v = h.scalar_mul_ct(k);
+
+    let c = dleq_challenge([&h, pk, &gamma, &u, &v]);
+    let s = *k + c * *sk;
+    (output_from_gamma(&gamma), VrfProof { gamma, c, s })
+}
+
+/// Checks a VRF output and proof against the verifying key `pk`.
+pub(crate) fn verify(
+    pk: &Affine,
+    input: &[BabyBear],
+    output: &VrfOutput,
+    proof: &VrfProof,
+) -> Result<bool, SchnorrError> {
+    if !proof.c.is_canonical() || !proof.s.is_canonical() {
+        return Err(SchnorrError::InvalidScalar);
+    }
+    check_point(pk)?;
+    check_point(&proof.gamma)?;
+
+    let h = input_point(input);
+    let u = Affine::double_scalar_mul_basepoint(&proof.s, &-proof.c, pk);
+    let v = <Affine as Group>::multi_scalar_mul(&[h, proof.gamma], &[proof.s, -proof.c]);
+    if dleq_challenge([&h, pk, &proof.gamma, &u, &v]) != proof.c {
+        return Ok(false);
+    }
+
+    Ok(output_from_gamma(&proof.gamma) == *output)
+}
@@ -0,0 +1,104 @@
+//! Fixed signature test vectors.
+//!
+//! Each vector is generated by the procedure below, which uses no randomness:
+//!
+//! 1. The signing key is `SigningKey::from_bytes(sk)` for the listed
+//!    little-endian key bytes.
+//! 2. The message is the listed `u32` values as BabyBear elements.
+//! 3. The signature is `SigningKey::sign_deterministic(msg)`, whose nonce is
+//!    derived from the key and message with Poseidon2.
+//! 4. `pk` is the `VerifyingKey` `Display` hex and `sig` is
+//!    `Signature::to_bytes` as lowercase hex.
+//!
+//! `EXPECTED` must hold one `(pk, sig)` entry per input, and `test_vectors_verify`
+//! fails until it does. Once filled in, a change to the wire format, the nonce
+//! derivation or the `hash_challenge` packing fails this test. Generate or
+//! regenerate it after a deliberate format change with
+//! `cargo test -p schnorr --test vectors -- --ignored --nocapture` and paste
+//! the printed table.
+
+use p3_baby_bear::BabyBear;
+use p3_field::PrimeCharacteristicRing;
+use schnorr::{SigningKey, verify_test_vector};
+
+/// `(sk, msg)` inputs: a small key with an empty message, the same key with a
+/// three-element message, and a large key with a multi-element message that
+/// spans more than one sponge block.
+const INPUTS: [(&str, &[u32]); 3] = [
+    (
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        &[],
+    ),
+    (
+        "0100000000000000000000000000000000000000000000000000000000000000",
+        &[1, 2, 3],
+    ),
+    (
+        "efcdab8967452301efcdab8967452301efcdab8967452301efcdab8967452300",
+        &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 2013265920],
+    ),
+];
+
+/// `(pk, sig)` hex produced from `INPUTS` by the procedure above, in order.
+const EXPECTED: &[(&str, &str)] = &[];
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+    assert_eq!(hex.len(), 2 * N);
+    core::array::from_fn(|i| u8::from_str_radix(&hex[2 * i..2 * i + 2], 16).unwrap())
+}
+
+/// The `(pk, sig)` hex for each input.
+fn generate() -> Vec<(String, String)> {
+    INPUTS
+        .iter()
+        .map(|(sk, msg)| {
+            let sk = SigningKey::from_bytes(&from_hex(sk)).expect("canonical key");
+            let msg: Vec<BabyBear> = msg.iter().copied().map(BabyBear::from_u32).collect();
+            let sig = sk.sign_deterministic(&msg).expect("signing failed");
+            (sk.verifying_key().to_string(), to_hex(&sig.to_bytes()))
+        })
+        .collect()
+}
+
+#[test]
+fn test_vectors_verify() {
+    let generated = generate();
+    assert_eq!(
+        EXPECTED.len(),
+        INPUTS.len(),
+        "EXPECTED is out of date; regenerate it with `print_vectors`"
+    );
+
+    for (i, ((_, msg), (pk, sig))) in INPUTS.iter().zip(&generated).enumerate() {
+        let msg: Vec<BabyBear> = msg.iter().copied().map(BabyBear::from_u32).collect();
+        assert_eq!(verify_test_vector(pk, &msg, sig), Ok(true), "vector {i}");
+
+        let mut tampered = msg.clone();
+        tampered.push(BabyBear::ZERO);
+        assert_eq!(
+            verify_test_vector(pk, &tampered, sig),
+            Ok(false),
+            "vector {i}"
+        );
+
+        let (expected_pk, expected_sig) = EXPECTED[i];
+        assert_eq!(pk, expected_pk, "vector {i} public key");
+        assert_eq!(sig, expected_sig, "vector {i} signature");
+    }
+
+    assert_eq!(generated, generate(), "vectors must be deterministic");
+}
+
+#[test]
+#[ignore = "prints the EXPECTED table"]
+fn print_vectors() {
+    println!("const EXPECTED: &[(&str, &str)] = &[");
+    for (pk, sig) in generate() {
+        println!("    (\n        \"{pk}\",\n        \"{sig}\",\n    ),");
+    }
+    println!("];");
+}